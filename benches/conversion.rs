@@ -0,0 +1,62 @@
+//! Cost of the conversions checked for constant math by the `codegen` package
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use embedded_time::{duration::*, fraction::Fraction, Clock, Instant};
+use std::convert::TryFrom;
+
+#[derive(Debug)]
+struct SysTick;
+
+impl Clock for SysTick {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+fn unit_conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Unit conversions");
+
+    group.bench_function("Milliseconds<u32> -> Microseconds<u32>", |b| {
+        b.iter(|| Microseconds::<u32>::try_from(black_box(Milliseconds(1_234_u32))))
+    });
+
+    group.bench_function("Microseconds<u32> -> Milliseconds<u32>", |b| {
+        b.iter(|| Milliseconds::<u32>::from(black_box(Microseconds(1_234_567_u32))))
+    });
+
+    group.bench_function("Milliseconds<u64> -> Microseconds<u64>", |b| {
+        b.iter(|| Microseconds::<u64>::try_from(black_box(Milliseconds(1_234_u64))))
+    });
+
+    group.bench_function("Generic<u32> (1/32_768 s) -> Microseconds<u32>", |b| {
+        b.iter(|| {
+            Microseconds::<u32>::try_from(black_box(Generic::new(
+                1_234_u32,
+                Fraction::new(1, 32_768),
+            )))
+        })
+    });
+
+    group.finish();
+}
+
+fn instant_subtraction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Instant subtraction");
+
+    group.bench_function("Instant - Instant -> Milliseconds<u32>", |b| {
+        b.iter(|| {
+            let later = black_box(Instant::<SysTick>::new(5_000));
+            let earlier = black_box(Instant::<SysTick>::new(2_000));
+            later
+                .checked_duration_since(&earlier)
+                .and_then(|elapsed| Milliseconds::<u32>::try_from(elapsed).ok())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, unit_conversions, instant_subtraction);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Conversions whose generated code is checked by `tests/asm.rs`
+//!
+//! Each function is `#[no_mangle]` so that it's emitted (and can be found) as is. Conversions
+//! between units with constant _scaling factors_ are expected to compile down to a constant
+//! multiply (or a multiply by the reciprocal and a shift), without calls or divide instructions.
+#![no_std]
+
+use core::convert::TryFrom;
+use embedded_time::{duration::*, fraction::Fraction, Clock, ConversionError, Instant};
+
+/// A 1 kHz clock
+#[derive(Debug)]
+pub struct SysTick;
+
+impl Clock for SysTick {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        Ok(Instant::new(0))
+    }
+}
+
+#[no_mangle]
+pub fn milliseconds_to_microseconds(
+    duration: Milliseconds<u32>,
+) -> Result<Microseconds<u32>, ConversionError> {
+    Microseconds::try_from(duration)
+}
+
+#[no_mangle]
+pub fn microseconds_to_milliseconds(duration: Microseconds<u32>) -> Milliseconds<u32> {
+    Milliseconds::from(duration)
+}
+
+#[no_mangle]
+pub fn milliseconds_u64_to_microseconds(
+    duration: Milliseconds<u64>,
+) -> Result<Microseconds<u64>, ConversionError> {
+    Microseconds::try_from(duration)
+}
+
+#[no_mangle]
+pub fn instant_elapsed_milliseconds(
+    later: Instant<SysTick>,
+    earlier: Instant<SysTick>,
+) -> Option<Milliseconds<u32>> {
+    Milliseconds::try_from(later.checked_duration_since(&earlier)?).ok()
+}
+
+#[no_mangle]
+pub fn rtc_ticks_to_microseconds(
+    ticks: Ticks<u32, 1, 32_768>,
+) -> Result<Microseconds<u32>, ConversionError> {
+    Microseconds::try_from(ticks)
+}
+
+#[no_mangle]
+pub fn rtc_ticks_u64_to_microseconds(
+    ticks: Ticks<u64, 1, 32_768>,
+) -> Result<Microseconds<u64>, ConversionError> {
+    Microseconds::try_from(ticks)
+}
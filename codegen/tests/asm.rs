@@ -0,0 +1,115 @@
+//! Checks the thumbv7em code generated for the functions in `src/lib.rs`
+//!
+//! Requires the `thumbv7em-none-eabihf` target (`rustup target add thumbv7em-none-eabihf`); the
+//! checks are skipped without it.
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+const TARGET: &str = "thumbv7em-none-eabihf";
+
+/// Mnemonics that mustn't appear in a conversion: calls (eg. to `__aeabi_uldivmod`) and divides
+const FORBIDDEN: &[&str] = &["bl", "blx", "udiv", "sdiv"];
+
+/// Builds the crate for the target and returns the emitted assembly (or `None` if the target isn't
+/// installed)
+fn assembly() -> Option<String> {
+    let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(&[
+            "rustc",
+            "--release",
+            "--lib",
+            "--target",
+            TARGET,
+            "--target-dir",
+        ])
+        .arg(&target_dir)
+        .args(&["--", "--emit", "asm"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        if stderr.contains("target may not be installed") {
+            eprintln!("skipped: the `{}` target isn't installed", TARGET);
+            return None;
+        }
+        panic!("build failed:\n{}", stderr);
+    }
+
+    let deps = target_dir.join(TARGET).join("release").join("deps");
+    let listing = fs::read_dir(deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |extension| extension == "s"))
+        .max_by_key(|path| fs::metadata(path).unwrap().modified().unwrap())
+        .unwrap();
+
+    Some(fs::read_to_string(listing).unwrap())
+}
+
+/// Returns the instructions of a function (labels and directives removed)
+fn instructions<'a>(assembly: &'a str, function: &str) -> Vec<&'a str> {
+    let label = format!("{}:", function);
+
+    assembly
+        .lines()
+        .skip_while(|line| *line != label)
+        .skip(1)
+        .take_while(|line| !line.starts_with(".Lfunc_end"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('.') && !line.starts_with('@'))
+        .filter(|line| !line.ends_with(':'))
+        .collect()
+}
+
+fn assert_constant_math(assembly: &str, function: &str, expected: &str, max_len: usize) {
+    let instructions = instructions(assembly, function);
+    let listing = instructions.join("\n");
+    assert!(!instructions.is_empty(), "`{}` not found", function);
+
+    for instruction in &instructions {
+        let mnemonic = instruction.split_whitespace().next().unwrap();
+        assert!(
+            !FORBIDDEN.contains(&mnemonic) && !instruction.contains("__"),
+            "`{}` calls or divides:\n{}",
+            function,
+            listing
+        );
+    }
+    assert!(
+        instructions
+            .iter()
+            .any(|instruction| instruction.starts_with(expected)),
+        "`{}` has no `{}`:\n{}",
+        function,
+        expected,
+        listing
+    );
+    assert!(
+        instructions.len() <= max_len,
+        "`{}` is longer than {} instructions:\n{}",
+        function,
+        max_len,
+        listing
+    );
+}
+
+#[test]
+fn conversions_are_constant_math() {
+    let assembly = match assembly() {
+        Some(assembly) => assembly,
+        None => return,
+    };
+
+    // a multiply (after an overflow check against a constant)
+    assert_constant_math(&assembly, "milliseconds_to_microseconds", "mul", 20);
+    assert_constant_math(&assembly, "milliseconds_u64_to_microseconds", "umull", 24);
+    // a multiply by the reciprocal and a shift
+    assert_constant_math(&assembly, "microseconds_to_milliseconds", "umull", 10);
+    // a multiply and a shift (for the power-of-two period)
+    assert_constant_math(&assembly, "rtc_ticks_to_microseconds", "lsr", 20);
+    assert_constant_math(&assembly, "rtc_ticks_u64_to_microseconds", "lsr", 32);
+    // a subtraction (the ticks already are milliseconds)
+    assert_constant_math(&assembly, "instant_elapsed_milliseconds", "subs", 10);
+}
@@ -0,0 +1,104 @@
+//! Frequency tolerances
+//!
+//! Oscillators, clock sources, and communication links (eg. UARTs) are specified to a tolerance in
+//! parts-per-million ([`Ppm`]). Durations and rates can be compared within such a tolerance
+//! ([`Duration::within()`](crate::duration::Duration::within),
+//! [`Rate::within()`](crate::rate::Rate::within)), and a [`Clock`](crate::Clock) can declare its
+//! own ([`ClockAccuracy`]).
+
+#[cfg(any(feature = "duration", feature = "rate"))]
+use crate::fraction::Fraction;
+#[cfg(any(feature = "duration", feature = "rate"))]
+use num::integer::gcd;
+
+/// A tolerance in parts-per-million (eg. `Ppm(20)` for a ±20 ppm crystal)
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{accuracy::Ppm, duration::*};
+///
+/// assert!(Microseconds(1_000_020_u32).within(Seconds(1_u32), Ppm(20)));
+/// assert!(!Microseconds(1_000_021_u32).within(Seconds(1_u32), Ppm(20)));
+///
+/// assert_eq!(Ppm::from_percent(2), Ppm(20_000));
+/// assert_eq!(Ppm(20).combined(Ppm(30)), Ppm(50));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Ppm(pub u32);
+
+impl Ppm {
+    /// Construct from a percentage
+    pub const fn from_percent(percent: u32) -> Self {
+        Self(percent.saturating_mul(10_000))
+    }
+
+    /// Returns the worst-case tolerance of two sources deviating in opposite directions (eg. the
+    /// two ends of a UART link)
+    pub const fn combined(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+}
+
+/// A [`Clock`](crate::Clock) with a known accuracy
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{accuracy::*, duration::*, Instant};
+///
+/// # #[derive(Debug)]
+/// struct Rtc;
+/// impl embedded_time::Clock for Rtc {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// impl ClockAccuracy for Rtc {
+///     const ACCURACY: Ppm = Ppm(20);
+/// }
+///
+/// // a measured one second is at least 999,980 µs long
+/// assert!(Microseconds(999_980_u32).within(Seconds(1_u32), Rtc::ACCURACY));
+/// ```
+#[cfg(feature = "clock")]
+pub trait ClockAccuracy: crate::Clock {
+    /// The worst-case frequency error of the clock
+    const ACCURACY: Ppm;
+}
+
+/// Returns `true` if `value` deviates from `expected` by no more than `tolerance` of `expected`
+///
+/// Returns `false` if the comparison overflows (only possible with extreme _scaling factors_).
+#[cfg(any(feature = "duration", feature = "rate"))]
+pub(crate) fn within(
+    (value, value_scaling_factor): (u128, Fraction),
+    (expected, expected_scaling_factor): (u128, Fraction),
+    tolerance: Ppm,
+) -> bool {
+    // bring both to the common scaling factor value denominator × expected denominator
+    let value_factor = u128::from(*value_scaling_factor.numerator())
+        * u128::from(*expected_scaling_factor.denominator());
+    let expected_factor = u128::from(*expected_scaling_factor.numerator())
+        * u128::from(*value_scaling_factor.denominator());
+    let divisor = gcd(value_factor, expected_factor).max(1);
+
+    let (value, expected) = match (
+        value.checked_mul(value_factor / divisor),
+        expected.checked_mul(expected_factor / divisor),
+    ) {
+        (Option::Some(value), Option::Some(expected)) => (value, expected),
+        _ => return false,
+    };
+    let deviation = value.max(expected) - value.min(expected);
+
+    match (
+        deviation.checked_mul(1_000_000),
+        expected.checked_mul(u128::from(tolerance.0)),
+    ) {
+        (Option::Some(deviation), Option::Some(allowed)) => deviation <= allowed,
+        _ => false,
+    }
+}
@@ -0,0 +1,79 @@
+//! Compare-match alarms
+//!
+//! Many hardware timers can raise an interrupt when the counter reaches a compare value. The
+//! [`Alarm`] trait exposes this on top of [`Clock`](crate::Clock) so that waiting can be
+//! interrupt-driven rather than polled (see `asynch::AlarmClock` with the `async` feature).
+
+use crate::{clock, Instant};
+
+/// A [`Clock`](crate::Clock) with a compare-match alarm
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{alarm::Alarm, clock, duration::*, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Timer {
+///     ticks: Cell<u32>,
+///     compare: Cell<Option<u32>>,
+///     // ...
+/// }
+///
+/// impl embedded_time::Clock for Timer {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, clock::Error> {Ok(Instant::new(self.ticks.get()))}
+/// }
+///
+/// impl Alarm for Timer {
+///     fn set_alarm(&self, at: Instant<Self>) -> Result<(), clock::Error> {
+///         // write the compare register and enable the compare interrupt
+///         self.compare.set(Some(*at.duration_since_epoch().integer()));
+///         Ok(())
+///     }
+///
+///     fn clear_alarm(&self) {
+///         self.compare.set(None);
+///     }
+///
+///     fn alarm_fired(&self) -> bool {
+///         // read and clear the compare flag
+/// #       let fired = matches!(self.compare.get(), Some(at) if self.ticks.get() >= at);
+/// #       if fired { self.compare.set(None) }
+/// #       fired
+///         // ...
+///     }
+/// }
+///
+/// let timer = Timer { ticks: Cell::new(0), compare: Cell::new(None) };
+/// timer.set_alarm(Instant::new(100)).unwrap();
+/// assert!(!timer.alarm_fired());
+///
+/// timer.ticks.set(100);
+/// assert!(timer.alarm_fired());
+/// assert!(!timer.alarm_fired());
+/// ```
+pub trait Alarm: crate::Clock {
+    /// Arm the alarm to fire once the clock reaches the given instant, replacing any armed alarm
+    ///
+    /// If the instant has already passed (eg. it was set too close to the current count), the
+    /// alarm must fire as soon as possible rather than after the counter wraps.
+    ///
+    /// # Errors
+    ///
+    /// - [`clock::Error::NotRunning`]
+    /// - [`clock::Error::ReadFailure`]: The alarm could not be programmed (eg. a bus error writing
+    ///   to an external RTC)
+    /// - [`clock::Error::Unspecified`]
+    fn set_alarm(&self, at: Instant<Self>) -> Result<(), clock::Error>;
+
+    /// Disarm the alarm, discarding a fired but unacknowledged one
+    fn clear_alarm(&self);
+
+    /// Returns `true` once the armed alarm has fired, acknowledging it (clearing the flag)
+    ///
+    /// Typically called from the compare-match interrupt.
+    fn alarm_fired(&self) -> bool;
+}
@@ -0,0 +1,311 @@
+//! Async delays and periodic ticks
+//!
+//! The futures are executor-agnostic: instead of spinning, a pending future asks the clock to
+//! schedule a wake-up at its expiration ([`AsyncClock::schedule_wake()`]). The implementation
+//! typically arms a hardware alarm (compare match) and wakes the stored [`Waker`] from its
+//! interrupt, eg. through an [`AlarmWaker`]. Clocks with a compare-match [`Alarm`] can be adapted
+//! with an [`AlarmClock`].
+
+use crate::{alarm::Alarm, clock, duration::Duration, fixed_point::FixedPoint, Instant, TimeError};
+use core::{
+    cell::Cell,
+    convert::TryFrom,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+/// A [`Clock`](crate::Clock) able to wake async tasks at a given instant
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{asynch::*, duration::*, Instant};
+/// # use core::task::Waker;
+/// # #[derive(Debug)]
+/// struct Clock {
+///     alarm: AlarmWaker,
+///     // ...
+/// }
+///
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// impl AsyncClock for Clock {
+///     fn schedule_wake(&self, at: Instant<Self>, waker: &Waker) {
+///         self.alarm.register(waker);
+///         // set the compare register to `at`, the alarm interrupt calls `self.alarm.wake()`
+///     }
+/// }
+/// ```
+pub trait AsyncClock: crate::Clock {
+    /// Arrange for the waker to be woken once the clock reaches the given instant
+    ///
+    /// Waking early is allowed (the future is polled again and re-schedules), waking late delays
+    /// the future. If the instant has already passed, the waker should be woken immediately.
+    fn schedule_wake(&self, at: Instant<Self>, waker: &Waker);
+
+    /// Returns a future completing once the duration has elapsed from now
+    ///
+    /// # Errors
+    ///
+    /// The future completes with an error if:
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - [`TimeError::Overflow`]: The duration is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`TimeError::ConversionFailure`], [`TimeError::Unspecified`]: The duration could not be
+    ///   converted to clock ticks
+    fn delay<Dur>(&self, duration: Dur) -> Delay<'_, Self>
+    where
+        Dur: Duration + FixedPoint,
+        Self::T: TryFrom<Dur::T>,
+    {
+        let expiration = Instant::<Self>::window_ticks(duration)
+            .map_err(TimeError::from)
+            .and_then(|ticks| Ok(self.try_now()?.wrapping_add_ticks(ticks)));
+
+        match expiration {
+            Ok(expiration) => Delay::until(self, expiration),
+            Err(error) => Delay {
+                clock: self,
+                expiration: Option::None,
+                error: Option::Some(error),
+            },
+        }
+    }
+}
+
+/// A [`Future`] completing once an [`AsyncClock`] reaches an instant
+///
+/// See [`AsyncClock::delay()`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Delay<'a, Clock: AsyncClock> {
+    clock: &'a Clock,
+    expiration: Option<Instant<Clock>>,
+    error: Option<TimeError>,
+}
+
+impl<'a, Clock: AsyncClock> Delay<'a, Clock> {
+    /// Returns a future completing once the clock reaches the given instant
+    pub fn until(clock: &'a Clock, expiration: Instant<Clock>) -> Self {
+        Self {
+            clock,
+            expiration: Option::Some(expiration),
+            error: Option::None,
+        }
+    }
+}
+
+// no pinned projections
+impl<Clock: AsyncClock> Unpin for Delay<'_, Clock> {}
+
+impl<Clock: AsyncClock> Future for Delay<'_, Clock> {
+    type Output = Result<(), TimeError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Option::Some(error) = this.error.take() {
+            return Poll::Ready(Err(error));
+        }
+
+        let expiration = match this.expiration {
+            Option::Some(expiration) => expiration,
+            Option::None => return Poll::Ready(Ok(())),
+        };
+        match this.clock.try_now() {
+            Ok(now) if now >= expiration => {
+                this.expiration = Option::None;
+                Poll::Ready(Ok(()))
+            }
+            Ok(_) => {
+                this.clock.schedule_wake(expiration, cx.waker());
+                Poll::Pending
+            }
+            Err(error) => Poll::Ready(Err(error.into())),
+        }
+    }
+}
+
+/// Periodic async ticks, scheduled without drift
+///
+/// Each tick is due one period after the previous one was due (rather than after it completed),
+/// so ticks that complete late are caught up.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use embedded_time::{asynch::*, duration::*, Instant, TimeError};
+/// # use core::task::Waker;
+/// # #[derive(Debug)]
+/// # struct Clock;
+/// # impl embedded_time::Clock for Clock {
+/// #     type T = u32;
+/// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+/// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// # }
+/// # impl AsyncClock for Clock {
+/// #     fn schedule_wake(&self, at: Instant<Self>, waker: &Waker) {unimplemented!()}
+/// # }
+/// async fn blink(clock: &Clock) -> Result<(), TimeError> {
+///     let mut ticker = Ticker::new(clock, Milliseconds(500_u32))?;
+///     loop {
+///         ticker.tick().await?;
+///         // toggle the LED
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Ticker<'a, Clock: AsyncClock> {
+    clock: &'a Clock,
+    period: Clock::T,
+    next: Instant<Clock>,
+}
+
+impl<'a, Clock: AsyncClock> Ticker<'a, Clock> {
+    /// Start ticking from now, the first tick being due one period from now
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - [`TimeError::Overflow`]: The period is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`TimeError::ConversionFailure`], [`TimeError::Unspecified`]: The period could not be
+    ///   converted to clock ticks
+    pub fn new<Dur>(clock: &'a Clock, period: Dur) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let period = Instant::<Clock>::window_ticks(period)?;
+        let next = clock.try_now()?.wrapping_add_ticks(period);
+
+        Ok(Self {
+            clock,
+            period,
+            next,
+        })
+    }
+
+    /// Returns a future completing when the next tick is due
+    pub fn tick(&mut self) -> Delay<'a, Clock> {
+        let due = self.next;
+        self.next = due.wrapping_add_ticks(self.period);
+
+        Delay::until(self.clock, due)
+    }
+}
+
+/// Storage for the [`Waker`] of the task awaiting an alarm
+///
+/// Registered from [`AsyncClock::schedule_wake()`] and woken from the alarm interrupt. To share it
+/// with an interrupt handler, it must be wrapped in a critical-section-based mutex (eg.
+/// `cortex_m::interrupt::Mutex`).
+#[derive(Default)]
+pub struct AlarmWaker {
+    waker: Cell<Option<Waker>>,
+}
+
+impl fmt::Debug for AlarmWaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let waker = self.waker.take();
+        let registered = waker.is_some();
+        self.waker.set(waker);
+
+        f.debug_struct("AlarmWaker")
+            .field("registered", &registered)
+            .finish()
+    }
+}
+
+impl AlarmWaker {
+    /// Construct without a registered waker
+    pub const fn new() -> Self {
+        Self {
+            waker: Cell::new(Option::None),
+        }
+    }
+
+    /// Register the waker to wake, replacing any previously registered one
+    pub fn register(&self, waker: &Waker) {
+        let registered = match self.waker.take() {
+            Option::Some(registered) if registered.will_wake(waker) => registered,
+            _ => waker.clone(),
+        };
+        self.waker.set(Option::Some(registered));
+    }
+
+    /// Wake the registered waker (if any), removing it
+    pub fn wake(&self) {
+        if let Option::Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// An [`AsyncClock`] waking async tasks from the compare-match interrupt of an [`Alarm`]
+///
+/// [`on_interrupt()`](AlarmClock::on_interrupt) must be called from the alarm's interrupt
+/// handler. To share the clock with it, it must be wrapped in a critical-section-based mutex (eg.
+/// `cortex_m::interrupt::Mutex`).
+///
+/// Only one task at a time can wait on the alarm (the most recently scheduled wake-up replaces the
+/// previous one).
+#[derive(Debug)]
+pub struct AlarmClock<Hardware: Alarm> {
+    alarm: Hardware,
+    waker: AlarmWaker,
+}
+
+impl<Hardware: Alarm> AlarmClock<Hardware> {
+    /// Construct from the clock with the alarm
+    pub fn new(alarm: Hardware) -> Self {
+        Self {
+            alarm,
+            waker: AlarmWaker::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped clock
+    pub fn alarm(&self) -> &Hardware {
+        &self.alarm
+    }
+
+    /// Wake the waiting task if the alarm fired
+    ///
+    /// To be called from the alarm's interrupt handler.
+    pub fn on_interrupt(&self) {
+        if self.alarm.alarm_fired() {
+            self.waker.wake();
+        }
+    }
+}
+
+impl<Hardware: Alarm> crate::Clock for AlarmClock<Hardware> {
+    type T = Hardware::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Hardware::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Hardware::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(
+            *self.alarm.try_now()?.duration_since_epoch().integer(),
+        ))
+    }
+}
+
+impl<Hardware: Alarm> AsyncClock for AlarmClock<Hardware> {
+    fn schedule_wake(&self, at: Instant<Self>, waker: &Waker) {
+        self.waker.register(waker);
+        let at = Instant::new(*at.duration_since_epoch().integer());
+        if self.alarm.set_alarm(at).is_err() {
+            // let the future read (and report) the clock error
+            self.waker.wake();
+        }
+    }
+}
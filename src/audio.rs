@@ -0,0 +1,271 @@
+//! Sample and block timing for audio/DSP pipelines
+//!
+//! Conversions between sample counts, block counts, and durations use exact rational arithmetic
+//! (results are truncated only once, at the end). A [`SampleCounter`] tracks the samples due on
+//! every tick of a clock whose period is not a whole number of samples (eg. 44.1 kHz against a
+//! 1 ms tick), carrying the fractional sample over so that no drift accumulates.
+
+use crate::{
+    duration::Duration,
+    fixed_point::{self, FixedPoint},
+    rate::{self, Hertz},
+    time_int::TimeInt,
+    ConversionError,
+};
+use core::convert::TryFrom;
+use num::integer::gcd;
+
+/// The sample rate and block size of a block-based pipeline
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{audio::BlockTiming, duration::*, rate::*};
+///
+/// let timing = BlockTiming::new(Kilohertz(48_u32), 64).unwrap();
+///
+/// assert_eq!(timing.block_duration(), Ok(Microseconds(1_333_u32)));
+/// assert_eq!(timing.duration_to_samples(Milliseconds(10_u32)), Ok(480));
+/// assert_eq!(timing.duration_to_blocks(Seconds(1_u32)), Ok(750));
+/// assert_eq!(timing.blocks_to_duration(750), Ok(Seconds(1_u32)));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BlockTiming {
+    /// The sample rate in hertz as a reduced (numerator, denominator)
+    sample_rate: (u64, u64),
+    block_size: u32,
+}
+
+impl BlockTiming {
+    /// Construct from a sample rate and a block size in samples
+    ///
+    /// The sample rate keeps its _scaling factor_, so fractional hertz are not lost.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The sample rate or the block size is `0`
+    /// - [`ConversionError::ConversionFailure`]: The sample rate doesn't fit in a `Hertz<u32>`
+    pub fn new<Rate>(sample_rate: Rate, block_size: u32) -> Result<Self, ConversionError>
+    where
+        Rate: rate::Rate + FixedPoint,
+    {
+        let numerator = sample_rate
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*Rate::SCALING_FACTOR.numerator()))
+            .ok_or(ConversionError::ConversionFailure)?;
+        let denominator = u128::from(*Rate::SCALING_FACTOR.denominator());
+        if numerator == 0 || denominator == 0 || block_size == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        if numerator / denominator > u128::from(u32::MAX) {
+            return Err(ConversionError::ConversionFailure);
+        }
+
+        // below (`u32::MAX` + 1) × a `u32` denominator, so within a `u64`
+        let (numerator, denominator) = (numerator as u64, denominator as u64);
+        let divisor = gcd(numerator, denominator);
+
+        Ok(Self {
+            sample_rate: (numerator / divisor, denominator / divisor),
+            block_size,
+        })
+    }
+
+    /// Returns the sample rate (truncated to whole hertz)
+    pub fn sample_rate(&self) -> Hertz<u32> {
+        // checked on construction
+        Hertz((self.sample_rate.0 / self.sample_rate.1) as u32)
+    }
+
+    /// Returns the number of samples per block
+    pub fn block_size(&self) -> u32 {
+        self.block_size
+    }
+
+    /// Returns the duration of one block (truncated)
+    ///
+    /// # Errors
+    ///
+    /// See [`BlockTiming::samples_to_duration()`]
+    pub fn block_duration<Dur: Duration + FixedPoint>(&self) -> Result<Dur, ConversionError> {
+        self.blocks_to_duration(1)
+    }
+
+    /// Returns the duration of the given number of samples (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: An intermediate product doesn't fit in a `u128`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn samples_to_duration<Dur: Duration + FixedPoint>(
+        &self,
+        samples: u64,
+    ) -> Result<Dur, ConversionError> {
+        let (rate_numerator, rate_denominator) = self.sample_rate;
+
+        // samples / (sample rate × scaling factor)
+        let numerator = u128::from(samples)
+            .checked_mul(u128::from(rate_denominator))
+            .and_then(|product| product.checked_mul(u128::from(*Dur::SCALING_FACTOR.denominator())))
+            .ok_or(ConversionError::Overflow)?;
+        let denominator = u128::from(rate_numerator) * u128::from(*Dur::SCALING_FACTOR.numerator());
+
+        fixed_point::from_ratio(numerator, denominator)
+    }
+
+    /// Returns the duration of the given number of blocks (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The number of samples doesn't fit in a `u64` (or see
+    ///   [`BlockTiming::samples_to_duration()`])
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn blocks_to_duration<Dur: Duration + FixedPoint>(
+        &self,
+        blocks: u64,
+    ) -> Result<Dur, ConversionError> {
+        let samples = blocks
+            .checked_mul(u64::from(self.block_size))
+            .ok_or(ConversionError::Overflow)?;
+        self.samples_to_duration(samples)
+    }
+
+    /// Returns the number of whole samples within the duration
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The number of samples doesn't fit in a `u64`
+    pub fn duration_to_samples<Dur>(&self, duration: Dur) -> Result<u64, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: From<Dur::T>,
+    {
+        let (rate_numerator, rate_denominator) = self.sample_rate;
+
+        // duration × scaling factor × sample rate
+        let samples = u128::from(u64::from(*duration.integer()))
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()))
+            .and_then(|product| product.checked_mul(u128::from(rate_numerator)))
+            .ok_or(ConversionError::Overflow)?
+            / (u128::from(*Dur::SCALING_FACTOR.denominator()) * u128::from(rate_denominator));
+
+        u64::try_from(samples).map_err(|_| ConversionError::Overflow)
+    }
+
+    /// Returns the number of whole blocks within the duration
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The number of samples doesn't fit in a `u64`
+    pub fn duration_to_blocks<Dur>(&self, duration: Dur) -> Result<u64, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: From<Dur::T>,
+    {
+        Ok(self.duration_to_samples(duration)? / self.block_size as u64)
+    }
+
+    /// Construct a [`SampleCounter`] driven by a tick of the given period
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The period is `0`
+    /// - [`ConversionError::Overflow`]: The samples per tick (as a fraction) don't fit in `u64`s
+    pub fn counter<Dur>(&self, tick_period: Dur) -> Result<SampleCounter, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: From<Dur::T>,
+    {
+        let (rate_numerator, rate_denominator) = self.sample_rate;
+
+        // samples per tick = period × scaling factor × sample rate
+        let numerator = u64::from(*tick_period.integer())
+            .checked_mul(*Dur::SCALING_FACTOR.numerator() as u64)
+            .and_then(|numerator| numerator.checked_mul(rate_numerator))
+            .ok_or(ConversionError::Overflow)?;
+        if numerator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        let denominator = (*Dur::SCALING_FACTOR.denominator() as u64)
+            .checked_mul(rate_denominator)
+            .ok_or(ConversionError::Overflow)?;
+        let divisor = gcd(numerator, denominator);
+
+        Ok(SampleCounter {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+            remainder: 0,
+            samples: 0,
+            block_size: self.block_size,
+        })
+    }
+}
+
+/// Counts the samples due on each tick of a clock, carrying fractional samples over between ticks
+///
+/// Constructed with [`BlockTiming::counter()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{audio::BlockTiming, duration::*, rate::*};
+///
+/// let timing = BlockTiming::new(Hertz(44_100_u32), 32).unwrap();
+/// let mut counter = timing.counter(Milliseconds(1_u32)).unwrap();
+///
+/// // 44.1 samples per tick
+/// assert_eq!(counter.tick(), 44);
+/// assert_eq!((0..9).map(|_| counter.tick()).sum::<u64>(), 397);
+/// assert_eq!(counter.samples(), 441);
+/// assert_eq!(counter.blocks(), 13);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SampleCounter {
+    numerator: u64,
+    denominator: u64,
+    remainder: u64,
+    samples: u64,
+    block_size: u32,
+}
+
+impl SampleCounter {
+    /// Advance by one tick, returning the number of samples that became due
+    pub fn tick(&mut self) -> u64 {
+        self.advance(1)
+    }
+
+    /// Advance by the given number of ticks, returning the number of samples that became due
+    pub fn advance(&mut self, ticks: u32) -> u64 {
+        let accumulated =
+            u128::from(self.remainder) + u128::from(ticks) * u128::from(self.numerator);
+        let denominator = u128::from(self.denominator);
+
+        // the remainder is less than the denominator, itself a `u64`
+        self.remainder = (accumulated % denominator) as u64;
+        let due = u64::try_from(accumulated / denominator).unwrap_or(u64::MAX);
+        self.samples = self.samples.wrapping_add(due);
+
+        due
+    }
+
+    /// Returns the total number of samples due so far (wrapping at `u64::MAX`)
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Returns the number of complete blocks due so far
+    pub fn blocks(&self) -> u64 {
+        self.samples / u64::from(self.block_size)
+    }
+
+    /// Returns the samples due in the current (incomplete) block
+    pub fn partial_block(&self) -> u32 {
+        (self.samples % u64::from(self.block_size)) as u32
+    }
+
+    /// Returns the fractional sample carried over to the next tick as (numerator, denominator)
+    pub fn drift(&self) -> (u64, u64) {
+        (self.remainder, self.denominator)
+    }
+}
@@ -0,0 +1,115 @@
+//! Exponential backoff of retries
+
+use crate::{duration::Duration, fixed_point::FixedPoint, fraction::Fraction, time_int::TimeInt};
+
+/// An (endless) iterator of the delays between retries, growing exponentially up to a maximum
+///
+/// Each delay is the previous one times the multiplier (rounded up, so any multiplier greater than
+/// `1` grows even the shortest delays), capped at the maximum. A jitter hook (eg. randomizing each
+/// delay to keep devices retrying in sync from colliding) may be applied to the delays yielded.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{backoff::Backoff, duration::*};
+///
+/// let mut backoff = Backoff::new(Milliseconds(100_u32), Fraction::new(3, 2), Milliseconds(1_000));
+///
+/// assert_eq!(backoff.next(), Some(Milliseconds(100)));
+/// assert_eq!(backoff.next(), Some(Milliseconds(150)));
+/// assert_eq!(backoff.next(), Some(Milliseconds(225)));
+/// assert_eq!(backoff.nth(3), Some(Milliseconds(1_000)));
+///
+/// // after a success
+/// backoff.reset();
+/// assert_eq!(backoff.next(), Some(Milliseconds(100)));
+///
+/// // a ½-1× jitter from some source of randomness
+/// # let random = || 0_u32;
+/// let mut backoff = Backoff::new(Milliseconds(100_u32), Fraction::new(2, 1), Milliseconds(1_000))
+///     .with_jitter(|delay| delay / 2 + delay / 2 * random() / u32::MAX);
+/// assert_eq!(backoff.next(), Some(Milliseconds(50)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Backoff<Dur: Duration, Jitter = fn(Dur) -> Dur> {
+    initial: Dur,
+    multiplier: Fraction,
+    max: Dur,
+    /// The next delay (before the jitter)
+    delay: Dur,
+    jitter: Jitter,
+}
+
+impl<Dur: Duration + FixedPoint> Backoff<Dur> {
+    /// Construct from the initial delay, the multiplier, and the maximum delay
+    ///
+    /// An initial delay longer than the maximum is capped at the maximum.
+    pub fn new(initial: Dur, multiplier: Fraction, max: Dur) -> Self {
+        let initial = if initial.integer() > max.integer() {
+            max
+        } else {
+            initial
+        };
+
+        Self {
+            initial,
+            multiplier,
+            max,
+            delay: initial,
+            jitter: core::convert::identity,
+        }
+    }
+}
+
+impl<Dur: Duration + FixedPoint, Jitter: FnMut(Dur) -> Dur> Backoff<Dur, Jitter> {
+    /// Apply a function to each delay yielded (eg. to randomize it)
+    pub fn with_jitter<J: FnMut(Dur) -> Dur>(self, jitter: J) -> Backoff<Dur, J> {
+        Backoff {
+            initial: self.initial,
+            multiplier: self.multiplier,
+            max: self.max,
+            delay: self.delay,
+            jitter,
+        }
+    }
+
+    /// Restart from the initial delay (eg. after a successful attempt)
+    pub fn reset(&mut self) {
+        self.delay = self.initial;
+    }
+
+    /// Returns the next delay (before the jitter) without advancing
+    pub fn peek(&self) -> Dur {
+        self.delay
+    }
+}
+
+impl<Dur: Duration + FixedPoint, Jitter: FnMut(Dur) -> Dur> Iterator for Backoff<Dur, Jitter> {
+    type Item = Dur;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.delay;
+
+        // delay × multiplier, rounded up (capped)
+        let numerator = u128::from(*self.multiplier.numerator());
+        let denominator = u128::from(*self.multiplier.denominator());
+        self.delay = delay
+            .integer()
+            .into_u128()
+            .checked_mul(numerator)
+            .and_then(|product| {
+                // a `0` denominator caps the delay
+                let quotient = product.checked_div(denominator)?;
+                Option::Some(quotient + u128::from(product % denominator != 0))
+            })
+            .and_then(Dur::T::try_from_u128)
+            .filter(|integer| integer < self.max.integer())
+            .map_or(self.max, Dur::new);
+
+        Option::Some((self.jitter)(delay))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, Option::None)
+    }
+}
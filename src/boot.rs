@@ -0,0 +1,154 @@
+//! Boot-session tagging of instants
+//!
+//! An [`Instant`] is only meaningful relative to the [`Clock`](crate::Clock) it was read from
+//! _during the same boot session_. A timestamp that has been persisted or transmitted may have been
+//! read before the most recent reset, in which case comparing it against the current time gives a
+//! meaningless result. A [`TaggedInstant`] carries the [`BootId`] of the session it was read in so
+//! that such stale timestamps can be recognized.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use embedded_time::{boot::*, fraction::Fraction, Instant};
+//!
+//! # #[derive(Debug)]
+//! struct Clock {
+//!     boot_id: BootId,
+//! }
+//!
+//! impl embedded_time::Clock for Clock {
+//!     type T = u32;
+//!     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+//!     // ...
+//! # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(5))}
+//! }
+//!
+//! impl BootSession for Clock {
+//!     fn boot_id(&self) -> BootId {
+//!         self.boot_id
+//!     }
+//! }
+//!
+//! let clock = Clock { boot_id: BootId(7) };
+//! let timestamp = clock.try_now_tagged().unwrap();
+//!
+//! assert!(timestamp.is_current(&clock));
+//! assert_eq!(timestamp.instant_if_current(&clock), Some(Instant::new(5)));
+//!
+//! // after a reset, the timestamp is recognized as belonging to the previous session
+//! let clock = Clock { boot_id: BootId(7).next() };
+//! assert!(!timestamp.is_current(&clock));
+//! assert_eq!(timestamp.instant_if_current(&clock), None);
+//! ```
+
+use crate::{clock, duration, Instant};
+use core::hash::{Hash, Hasher};
+
+/// Identifies a boot session
+///
+/// The id is typically captured when the [`Clock`](crate::Clock) is initialized, either from a
+/// random source or from a counter kept in persistent storage and incremented on every boot.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct BootId(pub u32);
+
+impl BootId {
+    /// Returns the id of the following session (for counter-based ids)
+    pub const fn next(self) -> Self {
+        Self(self.0.wrapping_add(1))
+    }
+}
+
+/// A [`Clock`](crate::Clock) that knows the [`BootId`] of the current boot session
+pub trait BootSession: crate::Clock {
+    /// Returns the id of the current boot session
+    fn boot_id(&self) -> BootId;
+
+    /// Get the current [`Instant`] tagged with the current [`BootId`]
+    ///
+    /// # Errors
+    ///
+    /// See [`Clock::try_now()`](crate::Clock::try_now)
+    fn try_now_tagged(&self) -> Result<TaggedInstant<Self>, clock::Error> {
+        Ok(TaggedInstant::new(self.boot_id(), self.try_now()?))
+    }
+}
+
+/// An [`Instant`] tagged with the [`BootId`] of the session in which it was read
+#[derive(Debug)]
+pub struct TaggedInstant<Clock: crate::Clock> {
+    boot_id: BootId,
+    instant: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> TaggedInstant<Clock> {
+    /// Construct a new `TaggedInstant`
+    pub fn new(boot_id: BootId, instant: Instant<Clock>) -> Self {
+        Self { boot_id, instant }
+    }
+
+    /// Returns the [`BootId`] of the session in which the instant was read
+    pub fn boot_id(&self) -> BootId {
+        self.boot_id
+    }
+
+    /// Returns the inner [`Instant`] regardless of the session
+    ///
+    /// Comparing it against instants from another session gives a meaningless result.
+    pub fn instant_unchecked(&self) -> Instant<Clock> {
+        self.instant
+    }
+
+    /// Returns `true` if the instant was read during the clock's current session
+    pub fn is_current(&self, clock: &Clock) -> bool
+    where
+        Clock: BootSession,
+    {
+        self.boot_id == clock.boot_id()
+    }
+
+    /// Returns the inner [`Instant`] if it was read during the clock's current session, otherwise
+    /// [`None`]
+    pub fn instant_if_current(&self, clock: &Clock) -> Option<Instant<Clock>>
+    where
+        Clock: BootSession,
+    {
+        if self.is_current(clock) {
+            Some(self.instant)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the amount of time elapsed from another tagged instant to this one or [`None`] if
+    /// the instants belong to different sessions or the other instant is later than this one
+    pub fn checked_duration_since(&self, other: &Self) -> Option<duration::Generic<Clock::T>> {
+        if self.boot_id == other.boot_id {
+            self.instant.checked_duration_since(&other.instant)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Clock: crate::Clock> Copy for TaggedInstant<Clock> {}
+
+impl<Clock: crate::Clock> Clone for TaggedInstant<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for TaggedInstant<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.boot_id == other.boot_id && self.instant == other.instant
+    }
+}
+
+impl<Clock: crate::Clock> Eq for TaggedInstant<Clock> {}
+
+impl<Clock: crate::Clock> Hash for TaggedInstant<Clock> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.boot_id.hash(state);
+        self.instant.hash(state);
+    }
+}
@@ -0,0 +1,240 @@
+//! Frequency trimming and temperature compensation of clocks
+//!
+//! A [`CalibratedClock`] wraps a clock whose oscillator is known to run fast or slow and corrects
+//! its readings by a _trim_ expressed in parts-per-billion (ppb). The trim consists of:
+//!
+//! - a static calibration (eg. measured at the factory), set with
+//!   [`CalibratedClock::set_calibration()`]
+//! - a temperature compensation, derived from periodic temperature readings
+//!   ([`CalibratedClock::update_temperature()`]) and a [`CrystalModel`]
+//!
+//! The 32.768 kHz tuning-fork crystals used for most RTCs follow a parabolic curve
+//! ([`ParabolicCurve`]), losing ~0.034 ppm/°C² away from a turnover temperature of ~25 °C.
+
+use crate::{clock, Instant};
+use core::{cell::Cell, convert::TryFrom};
+
+const PPB: i128 = 1_000_000_000;
+
+/// The largest trim magnitude (ppb) that will be applied
+///
+/// A trim of ±100% or more would stop or reverse the clock.
+pub const MAX_TRIM_PPB: i32 = 999_999_999;
+
+/// Describes the frequency error of an oscillator as a function of temperature
+///
+/// The `()` implementation models an ideal oscillator (no temperature dependence).
+pub trait CrystalModel {
+    /// Returns the frequency error in ppb (positive: running fast) at the given temperature in
+    /// milli-degrees Celsius
+    fn frequency_error_ppb(&self, temperature_millicelsius: i32) -> i32;
+}
+
+impl CrystalModel for () {
+    fn frequency_error_ppb(&self, _temperature_millicelsius: i32) -> i32 {
+        0
+    }
+}
+
+/// A parabolic crystal curve: `error = curvature × (temperature - turnover)²`
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::calibration::*;
+/// let curve = ParabolicCurve::TUNING_FORK;
+///
+/// assert_eq!(curve.frequency_error_ppb(25_000), 0);
+/// // 0.034 ppm/°C² × (10 °C)² = 3.4 ppm slow
+/// assert_eq!(curve.frequency_error_ppb(35_000), -3_400);
+/// assert_eq!(curve.frequency_error_ppb(-5_000), -30_600);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParabolicCurve {
+    turnover_millicelsius: i32,
+    curvature_ppb: i32,
+}
+
+impl ParabolicCurve {
+    /// A typical 32.768 kHz tuning-fork crystal (turnover at 25 °C, -34 ppb/°C²)
+    pub const TUNING_FORK: Self = Self::new(25_000, -34);
+
+    /// Construct a curve from its turnover temperature (milli-degrees Celsius) and curvature
+    /// (ppb/°C², negative for crystals that slow down away from the turnover)
+    pub const fn new(turnover_millicelsius: i32, curvature_ppb: i32) -> Self {
+        Self {
+            turnover_millicelsius,
+            curvature_ppb,
+        }
+    }
+}
+
+impl CrystalModel for ParabolicCurve {
+    fn frequency_error_ppb(&self, temperature_millicelsius: i32) -> i32 {
+        let offset = i64::from(temperature_millicelsius) - i64::from(self.turnover_millicelsius);
+        let error =
+            i128::from(self.curvature_ppb) * i128::from(offset) * i128::from(offset) / 1_000_000;
+
+        error.clamp(i128::from(i32::MIN), i128::from(i32::MAX)) as i32
+    }
+}
+
+/// A [`Clock`](crate::Clock) correcting the readings of another clock by a runtime-adjustable trim
+///
+/// Corrections are applied to the ticks elapsed between consecutive readings (sub-tick remainders
+/// are carried over), so changing the trim never makes the clock jump or run backwards. For this
+/// to work, the clock must be read at least once per [wrap horizon](crate::Clock::wrap_horizon) of
+/// the wrapped clock.
+///
+/// The first reading is passed through unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{calibration::*, duration::*, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Rtc(Cell<u32>);
+/// impl embedded_time::Clock for Rtc {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// let clock = CalibratedClock::with_model(Rtc(Cell::new(0)), ParabolicCurve::TUNING_FORK);
+/// clock.try_now().unwrap();
+///
+/// // at -5 °C, the crystal runs 30.6 ppm slow
+/// clock.update_temperature(-5_000);
+/// assert_eq!(clock.trim_ppb(), 30_600);
+///
+/// clock.inner().0.set(32_768_000);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(32_769_002)));
+/// ```
+#[derive(Debug)]
+pub struct CalibratedClock<Clock: crate::Clock, Model = ()> {
+    clock: Clock,
+    model: Model,
+    calibration_ppb: Cell<i32>,
+    compensation_ppb: Cell<i32>,
+    /// The last raw and corrected readings
+    last: Cell<Option<(Clock::T, Clock::T)>>,
+    /// Uncorrected error carried over from previous readings (ticks × 10⁻⁹)
+    residue: Cell<i64>,
+}
+
+impl<Clock: crate::Clock> CalibratedClock<Clock, ()> {
+    /// Wrap a clock without temperature compensation
+    pub fn new(clock: Clock) -> Self {
+        Self::with_model(clock, ())
+    }
+}
+
+impl<Clock: crate::Clock, Model: CrystalModel> CalibratedClock<Clock, Model> {
+    /// Wrap a clock, compensating for temperature using the provided crystal model
+    pub fn with_model(clock: Clock, model: Model) -> Self {
+        Self {
+            clock,
+            model,
+            calibration_ppb: Cell::new(0),
+            compensation_ppb: Cell::new(0),
+            last: Cell::new(Option::None),
+            residue: Cell::new(0),
+        }
+    }
+
+    /// Set the static calibration in ppb (positive: speed up the clock)
+    pub fn set_calibration(&self, ppb: i32) {
+        self.calibration_ppb.set(ppb);
+    }
+
+    /// Update the temperature compensation from a temperature reading in milli-degrees Celsius
+    pub fn update_temperature(&self, temperature_millicelsius: i32) {
+        self.compensation_ppb.set(
+            self.model
+                .frequency_error_ppb(temperature_millicelsius)
+                .saturating_neg(),
+        );
+    }
+
+    /// Returns the trim currently applied (calibration + temperature compensation) in ppb, limited
+    /// to ±[`MAX_TRIM_PPB`]
+    pub fn trim_ppb(&self) -> i32 {
+        self.calibration_ppb
+            .get()
+            .saturating_add(self.compensation_ppb.get())
+            .clamp(-MAX_TRIM_PPB, MAX_TRIM_PPB)
+    }
+
+    /// Returns a reference to the wrapped clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns a reference to the crystal model
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+}
+
+impl<Clock: crate::Clock, Model: CrystalModel> crate::Clock for CalibratedClock<Clock, Model>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let raw = self.clock.try_now()?;
+
+        let corrected = match self.last.get() {
+            Option::None => Instant::new(*raw.duration_since_epoch().integer()),
+            Option::Some((last_raw, last_corrected)) => apply_ppb(
+                Instant::<Self>::new(last_corrected),
+                raw.wrapping_ticks_since(&Instant::new(last_raw)),
+                self.trim_ppb(),
+                &self.residue,
+            ),
+        };
+        self.last.set(Option::Some((
+            *raw.duration_since_epoch().integer(),
+            *corrected.duration_since_epoch().integer(),
+        )));
+
+        Ok(corrected)
+    }
+}
+
+/// Advance a corrected reading by the raw ticks `elapsed` since, sped up by `rate_ppb` (which must
+/// be within ±[`MAX_TRIM_PPB`]), carrying the sub-tick remainder over in `residue` (ticks × 10⁻⁹)
+///
+/// The rate correction of both the [`CalibratedClock`] and the
+/// [`DisciplinedClock`](crate::discipline::DisciplinedClock).
+pub(crate) fn apply_ppb<Clock: crate::Clock>(
+    last_corrected: Instant<Clock>,
+    elapsed: Clock::T,
+    rate_ppb: i32,
+    residue: &Cell<i64>,
+) -> Instant<Clock>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    let error = i128::from(u64::from(elapsed)) * i128::from(rate_ppb) + i128::from(residue.get());
+    let correction = error / PPB;
+    residue.set((error % PPB) as i64);
+
+    // the rate is limited to ±100%, so the correction is no larger than `elapsed`
+    let magnitude = Clock::T::try_from(correction.unsigned_abs() as u64)
+        .ok()
+        .unwrap();
+    let corrected = last_corrected.wrapping_add_ticks(elapsed);
+    if correction < 0 {
+        corrected.wrapping_sub_ticks(magnitude)
+    } else {
+        corrected.wrapping_add_ticks(magnitude)
+    }
+}
@@ -0,0 +1,394 @@
+//! Conversion of hardware input-capture values into durations and rates
+//!
+//! An input-capture driver latches the value of a free-running counter on each edge of a signal.
+//! The time between two edges is the difference of the captured values plus one counter period
+//! per overflow in between, scaled by the counter's tick rate (timer clock ÷ prescaler).
+//!
+//! Drivers capturing edges as [`Instant`](crate::Instant)s can measure the period, frequency,
+//! and duty cycle of the signal with a [`PeriodMeasurement`] (`clock` feature).
+
+use crate::{
+    duration::Duration,
+    fixed_point::{self, FixedPoint},
+    fraction::Fraction,
+    rate::{self, Rate},
+    ConversionError,
+};
+#[cfg(feature = "clock")]
+use crate::{duty::DutyCycle, time_int::TimeInt, Instant};
+use core::convert::TryFrom;
+
+/// Converts raw capture-register values into typed durations and rates
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{capture::CaptureConverter, duration::*, rate::*};
+///
+/// // 16-bit counter at 16 MHz / 8 = 2 MHz
+/// let mut converter = CaptureConverter::new(Megahertz(16_u32), 8, 16).unwrap();
+///
+/// assert_eq!(converter.capture(65_000, 0), Ok(None));
+/// // the counter overflowed once
+/// let ticks = converter.capture(1_464, 1).unwrap().unwrap();
+/// assert_eq!(ticks, 2_000);
+///
+/// assert_eq!(converter.ticks_to_duration(ticks), Ok(Microseconds(1_000_u32)));
+/// assert_eq!(converter.ticks_to_rate(ticks), Ok(Hertz(1_000_u32)));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CaptureConverter {
+    timer_clock: u32,
+    prescaler: u32,
+    counter_bits: u32,
+    previous: Option<u32>,
+}
+
+impl CaptureConverter {
+    /// Construct from the timer clock (truncated to whole hertz), prescaler, and counter width in
+    /// bits
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The timer clock or the prescaler is `0`
+    /// - [`ConversionError::Overflow`]: The counter width is `0` or more than 32 bits
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The timer clock
+    ///   doesn't fit in a `Hertz<u32>`
+    pub fn new<Clock>(
+        timer_clock: Clock,
+        prescaler: u32,
+        counter_bits: u32,
+    ) -> Result<Self, ConversionError>
+    where
+        Clock: Rate + FixedPoint,
+        u32: TryFrom<Clock::T>,
+    {
+        let timer_clock = timer_clock.into_ticks::<u32>(Fraction::new(1, 1))?;
+        if timer_clock == 0 || prescaler == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        if counter_bits == 0 || counter_bits > 32 {
+            return Err(ConversionError::Overflow);
+        }
+
+        Ok(Self {
+            timer_clock,
+            prescaler,
+            counter_bits,
+            previous: Option::None,
+        })
+    }
+
+    /// Returns the counter's tick rate (timer clock ÷ prescaler)
+    pub fn tick_rate(&self) -> rate::Generic<u32> {
+        rate::Generic::new(self.timer_clock, Fraction::new(1, self.prescaler))
+    }
+
+    /// Returns the number of counter ticks between two captures with `overflows` counter
+    /// overflows in between
+    ///
+    /// Bits of the captured values beyond the counter width are ignored.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::NegDuration`]: The `current` capture precedes the `previous` one (too
+    /// few overflows)
+    pub fn elapsed_ticks(
+        &self,
+        previous: u32,
+        current: u32,
+        overflows: u32,
+    ) -> Result<u64, ConversionError> {
+        let mask = (1_u64 << self.counter_bits) - 1;
+        let previous = u64::from(previous) & mask;
+        let current = u64::from(current) & mask;
+
+        (u64::from(overflows) << self.counter_bits)
+            .checked_add(current)
+            .and_then(|ticks| ticks.checked_sub(previous))
+            .ok_or(ConversionError::NegDuration)
+    }
+
+    /// Record a capture, returning the ticks elapsed since the previous one
+    ///
+    /// `overflows` is the number of counter overflows since the previous capture. Returns
+    /// `Ok(None)` for the first capture (after construction or [`reset()`](Self::reset)).
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::NegDuration`]: The capture precedes the previous one (too few overflows).
+    /// The capture is recorded regardless.
+    pub fn capture(&mut self, raw: u32, overflows: u32) -> Result<Option<u64>, ConversionError> {
+        let previous = self.previous.replace(raw);
+        match previous {
+            Option::Some(previous) => self.elapsed_ticks(previous, raw, overflows).map(Some),
+            Option::None => Ok(Option::None),
+        }
+    }
+
+    /// Forget the previous capture
+    pub fn reset(&mut self) {
+        self.previous = Option::None;
+    }
+
+    /// Convert a number of counter ticks into a duration (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: An intermediate product doesn't fit in a `u128`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn ticks_to_duration<Dur: Duration + FixedPoint>(
+        &self,
+        ticks: u64,
+    ) -> Result<Dur, ConversionError> {
+        // ticks × prescaler / (timer clock × scaling factor)
+        let numerator = (u128::from(ticks) * u128::from(self.prescaler))
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .ok_or(ConversionError::Overflow)?;
+        let denominator =
+            u128::from(self.timer_clock) * u128::from(*Dur::SCALING_FACTOR.numerator());
+
+        fixed_point::from_ratio(numerator, denominator)
+    }
+
+    /// Convert a number of counter ticks (per period) into a rate (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: `ticks` is `0`
+    /// - [`ConversionError::Overflow`]: An intermediate product doesn't fit in a `u128`
+    /// - [`ConversionError::ConversionFailure`]: The rate doesn't fit in the destination type
+    pub fn ticks_to_rate<R: Rate + FixedPoint>(&self, ticks: u64) -> Result<R, ConversionError> {
+        // timer clock / (ticks × prescaler × scaling factor)
+        let numerator = u128::from(self.timer_clock) * u128::from(*R::SCALING_FACTOR.denominator());
+        let denominator = (u128::from(ticks) * u128::from(self.prescaler))
+            .checked_mul(u128::from(*R::SCALING_FACTOR.numerator()))
+            .ok_or(ConversionError::Overflow)?;
+
+        fixed_point::from_ratio(numerator, denominator)
+    }
+}
+
+/// Measures a periodic signal (eg. a PWM input or a tachometer) from the instants of its edges,
+/// averaged over a number of periods
+///
+/// Every `samples`-th rising edge completes a [`Measurement`] of the periods since the previous
+/// one. Falling edges are optional: they're only needed for the duty cycle. The periods of a
+/// measurement must add up to less than the clock's
+/// [valid comparison window](Instant::valid_comparison_window).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{capture::PeriodMeasurement, duration::*, duty::DutyCycle, rate::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // averaged over 2 periods
+/// let mut measurement = PeriodMeasurement::<Clock>::new(2).unwrap();
+///
+/// assert_eq!(measurement.rising(Instant::new(0)), None);
+/// measurement.falling(Instant::new(250));
+/// assert_eq!(measurement.rising(Instant::new(1_010)), None);
+/// measurement.falling(Instant::new(1_260));
+/// let measured = measurement.rising(Instant::new(2_000)).unwrap();
+///
+/// assert_eq!(measured.period(), Ok(Microseconds(1_000_u32)));
+/// assert_eq!(measured.frequency(), Ok(Hertz(1_000_u32)));
+/// assert_eq!(measured.duty_cycle(), Ok(Some(DutyCycle::from_percent(25).unwrap())));
+/// ```
+#[cfg(feature = "clock")]
+#[derive(Debug)]
+pub struct PeriodMeasurement<Clock: crate::Clock> {
+    samples: u32,
+    /// The rising edge starting the current measurement
+    start: Option<Instant<Clock>>,
+    /// The last rising edge
+    rising: Option<Instant<Clock>>,
+    /// The periods completed since `start`
+    periods: u32,
+    /// The high ticks since `start` (if any falling edges were seen)
+    high: Option<u128>,
+    last: Option<Measurement>,
+}
+
+#[cfg(feature = "clock")]
+impl<Clock: crate::Clock> PeriodMeasurement<Clock> {
+    /// Construct from the number of periods to average over
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::DivByZero`]: `samples` is `0`
+    pub fn new(samples: u32) -> Result<Self, ConversionError> {
+        if samples == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+
+        Ok(Self {
+            samples,
+            start: Option::None,
+            rising: Option::None,
+            periods: 0,
+            high: Option::None,
+            last: Option::None,
+        })
+    }
+
+    /// Record a rising edge, returning the completed measurement (if any)
+    pub fn rising(&mut self, edge: Instant<Clock>) -> Option<Measurement> {
+        self.rising = Option::Some(edge);
+        let start = match self.start {
+            Option::Some(start) => start,
+            Option::None => {
+                self.start = Option::Some(edge);
+                return Option::None;
+            }
+        };
+
+        self.periods += 1;
+        if self.periods < self.samples {
+            return Option::None;
+        }
+
+        let measurement = Measurement {
+            ticks: edge.wrapping_ticks_since(&start).into_u128(),
+            high: self.high,
+            samples: self.samples,
+            scaling_factor: Clock::SCALING_FACTOR,
+        };
+        self.start = Option::Some(edge);
+        self.periods = 0;
+        self.high = Option::None;
+        self.last = Option::Some(measurement);
+
+        Option::Some(measurement)
+    }
+
+    /// Record a falling edge
+    ///
+    /// Falling edges before the first rising edge are ignored.
+    pub fn falling(&mut self, edge: Instant<Clock>) {
+        if let Option::Some(rising) = self.rising {
+            let high = edge.wrapping_ticks_since(&rising).into_u128();
+            self.high = Option::Some(self.high.unwrap_or(0).saturating_add(high));
+        }
+    }
+
+    /// Returns the last completed measurement
+    pub fn last(&self) -> Option<Measurement> {
+        self.last
+    }
+
+    /// Forget the recorded edges and measurement (eg. after the signal stopped)
+    pub fn reset(&mut self) {
+        self.start = Option::None;
+        self.rising = Option::None;
+        self.periods = 0;
+        self.high = Option::None;
+        self.last = Option::None;
+    }
+}
+
+/// The average period (and duty cycle) of a signal over a number of periods
+///
+/// See [`PeriodMeasurement`].
+#[cfg(feature = "clock")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Measurement {
+    /// The clock ticks of all the periods
+    ticks: u128,
+    /// The clock ticks the signal was high for (if any falling edges were seen)
+    high: Option<u128>,
+    samples: u32,
+    /// The clock's scaling factor
+    scaling_factor: Fraction,
+}
+
+#[cfg(feature = "clock")]
+impl Measurement {
+    /// Returns the number of periods averaged over
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Returns the average period (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: A _scaling factor_ is `0`
+    /// - [`ConversionError::ConversionFailure`]: The period doesn't fit in the destination type
+    pub fn period<Dur>(&self) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        // ticks × clock scaling factor / (samples × scaling factor)
+        let numerator = self
+            .ticks
+            .checked_mul(u128::from(*self.scaling_factor.numerator()))
+            .and_then(|ticks| ticks.checked_mul(u128::from(*Dur::SCALING_FACTOR.denominator())))
+            .ok_or(ConversionError::ConversionFailure)?;
+        let denominator = u128::from(self.samples)
+            * u128::from(*self.scaling_factor.denominator())
+            * u128::from(*Dur::SCALING_FACTOR.numerator());
+
+        numerator
+            .checked_div(denominator)
+            .ok_or(ConversionError::DivByZero)
+            .and_then(|integer| {
+                Dur::T::try_from_u128(integer)
+                    .map(Dur::new)
+                    .ok_or(ConversionError::ConversionFailure)
+            })
+    }
+
+    /// Returns the average frequency (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The periods took no ticks (or a _scaling factor_ is `0`)
+    /// - [`ConversionError::ConversionFailure`]: The frequency doesn't fit in the destination type
+    pub fn frequency<R>(&self) -> Result<R, ConversionError>
+    where
+        R: Rate + FixedPoint,
+    {
+        // samples / (ticks × clock scaling factor × scaling factor)
+        let numerator = u128::from(self.samples)
+            * u128::from(*self.scaling_factor.denominator())
+            * u128::from(*R::SCALING_FACTOR.denominator());
+        let denominator = self
+            .ticks
+            .checked_mul(u128::from(*self.scaling_factor.numerator()))
+            .and_then(|ticks| ticks.checked_mul(u128::from(*R::SCALING_FACTOR.numerator())));
+
+        match denominator {
+            // the frequency rounds to `0`
+            Option::None => Ok(R::new(R::T::from(0))),
+            Option::Some(denominator) => numerator
+                .checked_div(denominator)
+                .ok_or(ConversionError::DivByZero)
+                .and_then(|integer| {
+                    R::T::try_from_u128(integer)
+                        .map(R::new)
+                        .ok_or(ConversionError::ConversionFailure)
+                }),
+        }
+    }
+
+    /// Returns the duty cycle (or `Ok(None)` without falling edges)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The periods took no ticks
+    /// - [`ConversionError::Overflow`]: The (reduced) ratio doesn't fit in a [`Fraction`]
+    pub fn duty_cycle(&self) -> Result<Option<DutyCycle>, ConversionError> {
+        self.high
+            .map(|high| DutyCycle::reduce(high, self.ticks))
+            .transpose()
+    }
+}
@@ -1,19 +1,31 @@
 //! Abstraction for hardware timers/clocks
 
+use crate::{duration, fraction::Fraction, instant::Instant, time_int::TimeInt};
+#[cfg(feature = "timers")]
 use crate::{
-    duration::Duration, fixed_point::FixedPoint, fraction::Fraction, instant::Instant,
-    time_int::TimeInt, timer::param, timer::Timer,
+    duration::Duration,
+    fixed_point::FixedPoint,
+    timer::{param, Timer, TimerBuilder},
+    TimeError,
 };
+#[cfg(feature = "timers")]
+use core::convert::TryFrom;
 use core::hash::Hash;
 
 /// Potential `Clock` errors
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     /// Exact cause of failure is unknown
     Unspecified,
     /// The clock has either stopped or never started
     NotRunning,
+    /// The clock could not be read (eg. a bus error reading an external RTC)
+    ///
+    /// The code is defined by the driver (eg. its bus error as an integer), so that the cause of
+    /// the failure reaches the code handling it without making the error type generic.
+    ReadFailure(u32),
 }
 
 impl Default for Error {
@@ -22,6 +34,51 @@ impl Default for Error {
     }
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Unspecified => f.write_str("clock failed"),
+            Error::NotRunning => f.write_str("clock not running"),
+            Error::ReadFailure(code) => write!(f, "clock read failed (code {})", code),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// [`Clock::timeout()`] errors
+#[cfg(feature = "timers")]
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TimeoutError<E> {
+    /// Exact cause of failure is unknown
+    Unspecified,
+    /// The operation didn't complete within the timeout
+    TimedOut,
+    /// The operation failed
+    Operation(E),
+    /// The clock could not be read or the timeout could not be converted to clock ticks
+    Time(TimeError),
+}
+
+// not derived, `#[derive(Default)]` would require `E: Default`
+#[cfg(feature = "timers")]
+#[allow(clippy::derivable_impls)]
+impl<E> Default for TimeoutError<E> {
+    fn default() -> Self {
+        Self::Unspecified
+    }
+}
+
+#[cfg(feature = "timers")]
+impl<E> From<TimeError> for TimeoutError<E> {
+    fn from(error: TimeError) -> Self {
+        TimeoutError::Time(error)
+    }
+}
+
 /// The `Clock` trait provides an abstraction for hardware-specific timer peripherals, external
 /// timer devices, RTCs, etc.
 ///
@@ -39,15 +96,193 @@ pub trait Clock: Sized {
     /// The duration of one clock tick in seconds, AKA the clock precision.
     const SCALING_FACTOR: Fraction;
 
+    /// The width (in bits) of the hardware counter, after which it wraps around to `0`
+    ///
+    /// Defaults to the width of [`Clock::T`]. Narrower counters (eg. 24-bit SysTick, 16-bit
+    /// timers) must declare their width so that [`Instant`] comparisons and differences are correct
+    /// across a rollover.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Clock as _, Instant};
+    /// # #[derive(Debug)]
+    /// struct SysTick;
+    /// impl embedded_time::Clock for SysTick {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 16_000_000);
+    ///     const COUNTER_BITS: u32 = 24;
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let earlier = Instant::<SysTick>::new(0xFF_FFF0);
+    /// let later = Instant::<SysTick>::new(0x10);
+    ///
+    /// assert!(later > earlier);
+    /// assert_eq!(later - earlier, Generic::new(0x20_u32, SysTick::SCALING_FACTOR));
+    /// ```
+    const COUNTER_BITS: u32 = (core::mem::size_of::<Self::T>() * 8) as u32;
+
     /// Get the current Instant
     ///
+    /// Reads that can fail (eg. of an RTC over I²C) return an error rather than panicking. Users of
+    /// the clock ([`Timer`]s, delays, etc.) propagate it as [`TimeError::Clock`](crate::TimeError).
+    ///
     /// # Errors
     ///
     /// - [`Error::NotRunning`]
+    /// - [`Error::ReadFailure`]
     /// - [`Error::Unspecified`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{clock, duration::*, Clock as _, Instant};
+    /// # #[derive(Debug)]
+    /// # struct I2c;
+    /// # impl I2c {
+    /// #     fn read_seconds(&self) -> Result<u32, BusError> { Err(BusError::Nack) }
+    /// # }
+    /// #[derive(Debug)]
+    /// enum BusError {
+    ///     Nack = 1,
+    ///     ArbitrationLoss = 2,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Rtc {
+    ///     i2c: I2c,
+    /// }
+    ///
+    /// impl embedded_time::Clock for Rtc {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1);
+    ///
+    ///     fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+    ///         let seconds = self
+    ///             .i2c
+    ///             .read_seconds()
+    ///             .map_err(|error| clock::Error::ReadFailure(error as u32))?;
+    ///         Ok(Instant::new(seconds))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Rtc { i2c: I2c }.try_now(),
+    ///     Err(clock::Error::ReadFailure(BusError::Nack as u32))
+    /// );
+    /// ```
     fn try_now(&self) -> Result<Instant<Self>, Error>;
 
+    /// Returns the time it takes the clock to count through its entire range (2^[`COUNTER_BITS`] -
+    /// 1 ticks), after which a wrapping clock wraps around
+    ///
+    /// [`COUNTER_BITS`]: Clock::COUNTER_BITS
+    ///
+    /// Comparisons of [`Instant`]s are only valid within half of this horizon (see
+    /// [`Instant::valid_comparison_window()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Clock as _, Instant};
+    /// # use core::convert::TryFrom;
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// // a 32-bit, 1 MHz clock wraps after a little more than an hour
+    /// assert_eq!(Seconds::<u32>::try_from(Clock::wrap_horizon()), Ok(Seconds(4_294_u32)));
+    /// ```
+    fn wrap_horizon() -> duration::Generic<Self::T> {
+        let max_ticks = if Self::COUNTER_BITS < 128 {
+            Self::T::try_from_u128((1_u128 << Self::COUNTER_BITS) - 1)
+        } else {
+            Option::None
+        };
+
+        duration::Generic::new(
+            max_ticks.unwrap_or_else(<Self::T as num::Bounded>::max_value),
+            Self::SCALING_FACTOR,
+        )
+    }
+
+    /// Convert a number of ticks of this clock (eg. a raw counter delta) to a duration of any unit
+    /// (truncated)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError`](crate::ConversionError): The duration doesn't fit in `Dur`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Clock as _, Instant};
+    /// # #[derive(Debug)]
+    /// struct Rtc;
+    /// impl embedded_time::Clock for Rtc {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(Rtc::ticks_to_duration(49_152), Ok(Milliseconds(1_500_u32)));
+    /// assert_eq!(Rtc::ticks_to_duration(49_152), Ok(Microseconds(1_500_000_u64)));
+    /// ```
+    fn ticks_to_duration<Dur>(ticks: Self::T) -> Result<Dur, crate::ConversionError>
+    where
+        Dur: duration::Duration + crate::fixed_point::FixedPoint,
+        Dur::T: core::convert::TryFrom<Self::T>,
+    {
+        Dur::from_ticks(ticks, Self::SCALING_FACTOR)
+    }
+
+    /// Returns the tick frequency of the clock (the reciprocal of its [_scaling
+    /// factor_](Clock::SCALING_FACTOR)) in any rate unit (truncated)
+    ///
+    /// The resolutions of two clocks can also be compared directly through their _scaling
+    /// factors_ (eg. `A::SCALING_FACTOR < B::SCALING_FACTOR` if `A` ticks faster).
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError`](crate::ConversionError): The frequency doesn't fit in `R`
+    ///
+    /// # Panics
+    ///
+    /// The _scaling factor_ is `0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, rate::*, Clock as _, Instant};
+    /// # #[derive(Debug)]
+    /// struct Rtc;
+    /// impl embedded_time::Clock for Rtc {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(Rtc::frequency(), Ok(Hertz(32_768_u32)));
+    /// assert_eq!(Rtc::frequency(), Ok(Kilohertz(32_u32)));
+    /// ```
+    #[cfg(feature = "rate")]
+    fn frequency<R>() -> Result<R, crate::ConversionError>
+    where
+        R: crate::rate::Rate + crate::fixed_point::FixedPoint,
+        R::T: core::convert::TryFrom<u32>,
+    {
+        R::from_ticks(1_u32, Self::SCALING_FACTOR.recip())
+    }
+
     /// Spawn a new, `OneShot` [`Timer`] from this clock
+    #[cfg(feature = "timers")]
     fn new_timer<Dur: Duration>(
         &self,
         duration: Dur,
@@ -57,4 +292,70 @@ pub trait Clock: Sized {
     {
         Timer::<param::None, param::None, Self, Dur>::new(&self, duration)
     }
+
+    /// Repeatedly poll a non-blocking operation until it completes or the timeout elapses
+    ///
+    /// The operation is polled at least once, even if the timeout is `0`.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::TimedOut`]: The operation was still blocking when the timeout elapsed
+    /// - [`TimeoutError::Operation`]: The operation failed
+    /// - [`TimeoutError::Time`]: The clock could not be read or the timeout could not be converted
+    ///   to clock ticks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{clock::TimeoutError, duration::*, Clock as _, Instant};
+    /// # use core::sync::atomic::{AtomicU32, Ordering};
+    /// # static TICKS: AtomicU32 = AtomicU32::new(0);
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(TICKS.fetch_add(1, Ordering::SeqCst)))}
+    /// }
+    ///
+    /// // wait for a status flag that never gets set
+    /// let ready = || -> nb::Result<(), ()> { Err(nb::Error::WouldBlock) };
+    ///
+    /// assert_eq!(
+    ///     Clock.timeout(Milliseconds(10_u32), ready),
+    ///     Err(TimeoutError::TimedOut)
+    /// );
+    /// ```
+    #[cfg(feature = "timers")]
+    fn timeout<Dur, T, E, Operation>(
+        &self,
+        duration: Dur,
+        mut operation: Operation,
+    ) -> Result<T, TimeoutError<E>>
+    where
+        Dur: Duration + FixedPoint,
+        Self::T: TryFrom<Dur::T>,
+        Operation: FnMut() -> nb::Result<T, E>,
+    {
+        let timer = self.new_timer(duration).start()?;
+
+        loop {
+            match operation() {
+                Ok(value) => return Ok(value),
+                Err(nb::Error::Other(error)) => return Err(TimeoutError::Operation(error)),
+                Err(nb::Error::WouldBlock) => {
+                    if timer.is_expired()? {
+                        return Err(TimeoutError::TimedOut);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Configure a new [`Timer`] from this clock using a [`TimerBuilder`]
+    #[cfg(feature = "timers")]
+    fn timer_builder(&self) -> TimerBuilder<'_, param::None, Self, ()> {
+        TimerBuilder::new(self)
+    }
 }
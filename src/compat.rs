@@ -0,0 +1,149 @@
+//! Migration aids for `core::time::Duration` users and renamed items
+//!
+//! - [`CoreDurationExt`] and [`ToCoreDuration`] convert between `core::time::Duration` and any
+//!   _named_ duration type (not only those with a dedicated `TryFrom` implementation).
+//! - [`DurationCompat`] and [`RateCompat`] provide the methods of previous releases as deprecated
+//!   aliases of their replacements so that existing code keeps compiling (with a deprecation
+//!   warning pointing to the replacement) and can be migrated incrementally.
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! use embedded_time::{compat::*, duration::*};
+//! use core::time::Duration as CoreDuration;
+//!
+//! assert_eq!(CoreDuration::from_millis(2_500).to_duration(), Ok(Milliseconds(2_500_u32)));
+//! assert_eq!(Minutes(2_u32).to_core(), Ok(CoreDuration::from_secs(120)));
+//!
+//! // deprecated: use `to_generic()`
+//! assert_eq!(
+//!     Seconds(2_u32).try_into_generic(Fraction::new(1, 1_000)),
+//!     Ok(Generic::new(2_000_u32, Fraction::new(1, 1_000)))
+//! );
+//! ```
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::{self, FixedPoint},
+    fraction::Fraction,
+    time_int::TimeInt,
+    ConversionError,
+};
+use core::convert::TryFrom;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// The former name of [`Fraction`]
+#[deprecated(since = "0.8.0", note = "Renamed to `Fraction`")]
+pub type Period = Fraction;
+
+/// Conversion of a `core::time::Duration` into any _named_ duration type
+pub trait CoreDurationExt {
+    /// Convert to a _named_ duration (truncating toward `0`)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`]: The value does not fit in the destination type
+    fn to_duration<Dur: Duration + FixedPoint>(&self) -> Result<Dur, ConversionError>;
+}
+
+impl CoreDurationExt for core::time::Duration {
+    fn to_duration<Dur: Duration + FixedPoint>(&self) -> Result<Dur, ConversionError> {
+        // at most ~2⁶⁴ s × 10⁹ ns/s × 2³² (< 2¹²⁸)
+        fixed_point::from_ratio(
+            self.as_nanos() * u128::from(*Dur::SCALING_FACTOR.denominator()),
+            u128::from(*Dur::SCALING_FACTOR.numerator()) * NANOS_PER_SECOND,
+        )
+    }
+}
+
+/// Conversion of any _named_ duration into a `core::time::Duration`
+///
+/// Implemented for all _named_ duration types.
+pub trait ToCoreDuration {
+    /// Convert to a `core::time::Duration` (truncating toward `0`)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The duration is longer than `u64::MAX` seconds
+    fn to_core(&self) -> Result<core::time::Duration, ConversionError>;
+}
+
+impl<Dur> ToCoreDuration for Dur
+where
+    Dur: Duration + FixedPoint,
+    u64: From<Dur::T>,
+{
+    fn to_core(&self) -> Result<core::time::Duration, ConversionError> {
+        let numerator =
+            u128::from(u64::from(*self.integer())) * u128::from(*Dur::SCALING_FACTOR.numerator());
+        let denominator = u128::from(*Dur::SCALING_FACTOR.denominator());
+
+        let seconds =
+            u64::try_from(numerator / denominator).map_err(|_| ConversionError::Overflow)?;
+        // the remainder is less than the denominator, so the result is less than 1 s
+        let nanoseconds = (numerator % denominator * NANOS_PER_SECOND / denominator) as u32;
+
+        Ok(core::time::Duration::new(seconds, nanoseconds))
+    }
+}
+
+/// The duration methods of previous releases
+///
+/// Implemented for all _named_ duration types.
+pub trait DurationCompat: Duration + FixedPoint {
+    /// See [`Duration::to_generic()`]
+    #[deprecated(since = "0.9.0", note = "Use `Duration::to_generic()`")]
+    fn try_into_generic<DestInt>(
+        self,
+        scaling_factor: Fraction,
+    ) -> Result<duration::Generic<DestInt>, ConversionError>
+    where
+        DestInt: TimeInt + TryFrom<Self::T>,
+    {
+        self.to_generic(scaling_factor)
+    }
+
+    /// See [`Rate::to_duration()`](crate::rate::Rate::to_duration)
+    #[cfg(feature = "rate")]
+    #[deprecated(since = "0.9.0", note = "Use `Rate::to_duration()`")]
+    fn try_from_rate<Rate>(rate: Rate) -> Result<Self, ConversionError>
+    where
+        Rate: crate::rate::Rate + FixedPoint,
+        Self::T: TryFrom<Rate::T>,
+    {
+        rate.to_duration()
+    }
+}
+
+impl<Dur: Duration + FixedPoint> DurationCompat for Dur {}
+
+/// The rate methods of previous releases
+///
+/// Implemented for all _named_ rate types.
+#[cfg(feature = "rate")]
+pub trait RateCompat: crate::rate::Rate + FixedPoint {
+    /// See [`Rate::to_generic()`](crate::rate::Rate::to_generic)
+    #[deprecated(since = "0.9.0", note = "Use `Rate::to_generic()`")]
+    fn try_into_generic<DestInt>(
+        self,
+        scaling_factor: Fraction,
+    ) -> Result<crate::rate::Generic<DestInt>, ConversionError>
+    where
+        DestInt: TimeInt + TryFrom<Self::T>,
+    {
+        self.to_generic(scaling_factor)
+    }
+
+    /// See [`Duration::to_rate()`]
+    #[deprecated(since = "0.9.0", note = "Use `Duration::to_rate()`")]
+    fn try_from_duration<Dur>(duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Self::T: TryFrom<Dur::T>,
+    {
+        duration.to_rate()
+    }
+}
+
+#[cfg(feature = "rate")]
+impl<Rate: crate::rate::Rate + FixedPoint> RateCompat for Rate {}
@@ -0,0 +1,230 @@
+//! Conformance checks for [`Clock`](crate::Clock) implementations
+//!
+//! The checks are `no_std` functions that can be called from an on-target test runner (eg.
+//! `defmt-test`) during board bring-up to validate a `Clock` implementation:
+//!
+//! - [`monotonicity()`]: readings never go backward and the clock advances
+//! - [`wrap_handling()`]: [`Instant`] comparisons and durations across the wrap of the clock's
+//!   integer type
+//! - [`conversions()`]: the _scaling factor_ and the conversion of ticks to/from durations
+//! - [`timer_accuracy()`]: the duration of a [`Timer`](crate::Timer) measured against a reference
+//!   clock
+//!
+//! ```rust
+//! # use embedded_time::{conformance, duration::*, Instant};
+//! # use core::sync::atomic::{AtomicU32, Ordering};
+//! # static TICKS: AtomicU32 = AtomicU32::new(0);
+//! # #[derive(Debug)]
+//! struct Clock;
+//! impl embedded_time::Clock for Clock {
+//!     type T = u32;
+//!     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+//!     // ...
+//! # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(TICKS.fetch_add(1, Ordering::SeqCst)))}
+//! }
+//!
+//! assert_eq!(conformance::monotonicity(&Clock, 1_000), Ok(()));
+//! assert_eq!(conformance::wrap_handling::<Clock>(), Ok(()));
+//! assert_eq!(conformance::conversions::<Clock>(), Ok(()));
+//! ```
+
+use crate::{
+    duration::{self, Duration, Nanoseconds, Seconds},
+    fixed_point::FixedPoint,
+    fraction::Fraction,
+    time_int::TimeInt,
+    ConversionError, Instant, TimeError,
+};
+use core::convert::TryFrom;
+
+/// Conformance check failures
+#[non_exhaustive]
+#[derive(Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Failure {
+    /// Exact cause of failure is unknown
+    #[default]
+    Unspecified,
+    /// The clock could not be read, or a timer/conversion operation failed
+    Time(TimeError),
+    /// A reading preceded the previous one
+    NotMonotonic,
+    /// The clock didn't advance
+    Stalled,
+    /// Comparisons or durations across the wrap of the clock's integer type are incorrect
+    WrapHandling,
+    /// The _scaling factor_ is invalid or ticks don't convert to/from durations correctly
+    Conversion,
+    /// The measured duration deviates from the expected one by more than the tolerance
+    Inaccurate {
+        /// The measured deviation in ppm (positive: the timer took too long)
+        error_ppm: i64,
+    },
+}
+
+impl From<TimeError> for Failure {
+    fn from(error: TimeError) -> Self {
+        Failure::Time(error)
+    }
+}
+
+impl From<crate::clock::Error> for Failure {
+    fn from(error: crate::clock::Error) -> Self {
+        Failure::Time(error.into())
+    }
+}
+
+impl From<ConversionError> for Failure {
+    fn from(error: ConversionError) -> Self {
+        Failure::Time(error.into())
+    }
+}
+
+/// Take the given number of consecutive readings, checking that none precedes the previous one
+/// and that the clock advances
+///
+/// The readings must be taken over less than the clock's
+/// [valid comparison window](Instant::valid_comparison_window).
+///
+/// # Errors
+///
+/// - [`Failure::NotMonotonic`]
+/// - [`Failure::Stalled`]: All readings were the same
+/// - [`Failure::Time`]: The clock could not be read
+pub fn monotonicity<Clock: crate::Clock>(clock: &Clock, readings: u32) -> Result<(), Failure> {
+    let first = clock.try_now()?;
+    let mut previous = first;
+    for _ in 0..readings {
+        let now = clock.try_now()?;
+        if now < previous {
+            return Err(Failure::NotMonotonic);
+        }
+        previous = now;
+    }
+
+    if previous == first {
+        Err(Failure::Stalled)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check [`Instant`] comparisons and durations across the wrap of the clock's counter (see
+/// [`Clock::COUNTER_BITS`](crate::Clock::COUNTER_BITS))
+///
+/// # Errors
+///
+/// [`Failure::WrapHandling`]
+pub fn wrap_handling<Clock: crate::Clock>() -> Result<(), Failure> {
+    let zero = Clock::T::from(0);
+    let one = Clock::T::from(1);
+    let max_ticks = *Clock::wrap_horizon().integer();
+    let last = Instant::<Clock>::new(max_ticks);
+    let first = Instant::<Clock>::new(zero);
+    let window = *Instant::<Clock>::valid_comparison_window().integer();
+
+    let durations_correct = first.checked_duration_since(&last)
+        == Some(duration::Generic::new(one, Clock::SCALING_FACTOR))
+        && last.checked_duration_until(&first)
+            == Some(duration::Generic::new(one, Clock::SCALING_FACTOR))
+        && last.checked_duration_since(&first).is_none();
+    let ordering_correct = first > last
+        && Instant::<Clock>::new(window - one) > first
+        && Instant::<Clock>::new(window + one) < first;
+    // the declared counter width must fit in the integer type
+    let horizon_correct =
+        Clock::COUNTER_BITS < 128 && max_ticks.into_u128() == (1_u128 << Clock::COUNTER_BITS) - 1;
+
+    if durations_correct && ordering_correct && horizon_correct {
+        Ok(())
+    } else {
+        Err(Failure::WrapHandling)
+    }
+}
+
+/// Check the _scaling factor_ and the conversion of ticks to/from durations
+///
+/// # Errors
+///
+/// [`Failure::Conversion`]
+pub fn conversions<Clock: crate::Clock>() -> Result<(), Failure>
+where
+    u64: From<Clock::T>,
+{
+    let scaling_factor = Clock::SCALING_FACTOR;
+    let numerator = *scaling_factor.numerator() as u64;
+    let denominator = *scaling_factor.denominator() as u64;
+    if numerator == 0 || denominator == 0 {
+        return Err(Failure::Conversion);
+    }
+
+    // one tick in nanoseconds (truncated)
+    let tick =
+        Nanoseconds::<u64>::try_from(duration::Generic::new(Clock::T::from(1), scaling_factor))
+            .map_err(|_| Failure::Conversion)?;
+    if tick != Nanoseconds(numerator * 1_000_000_000 / denominator) {
+        return Err(Failure::Conversion);
+    }
+
+    // the smallest number of ticks that is a whole number of seconds, and back
+    let divisor = num::integer::gcd(numerator, denominator);
+    let ticks = duration::Generic::new(denominator / divisor, scaling_factor);
+    let seconds = Seconds(numerator / divisor);
+    if seconds.to_generic::<u64>(scaling_factor) != Ok(ticks)
+        || Seconds::<u64>::try_from(ticks) != Ok(seconds)
+    {
+        return Err(Failure::Conversion);
+    }
+
+    Ok(())
+}
+
+/// Run a one-shot [`Timer`](crate::Timer) of the given duration, checking its actual duration
+/// against a reference clock
+///
+/// Returns the measured deviation in ppm (positive: the timer took too long). The duration should
+/// be long relative to the tick period of both clocks.
+///
+/// # Errors
+///
+/// - [`Failure::Inaccurate`]: The deviation is larger than `tolerance_ppm`
+/// - [`Failure::Time`]: A clock could not be read or the duration could not be converted
+pub fn timer_accuracy<Clock, Reference, Dur>(
+    clock: &Clock,
+    reference: &Reference,
+    duration: Dur,
+    tolerance_ppm: u32,
+) -> Result<i64, Failure>
+where
+    Clock: crate::Clock,
+    Reference: crate::Clock,
+    Dur: Duration + FixedPoint,
+    Clock::T: TryFrom<Dur::T>,
+    u64: From<Dur::T> + From<Reference::T>,
+{
+    let expected = duration
+        .to_generic::<u64>(Fraction::new(1, 1_000_000_000))
+        .map(|nanoseconds| *nanoseconds.integer())?;
+    if expected == 0 {
+        return Err(Failure::Time(TimeError::DivByZero));
+    }
+
+    let timer = clock.new_timer(duration).start()?;
+    let start = reference.try_now()?;
+    timer.wait()?;
+    let end = reference.try_now()?;
+
+    let measured = end
+        .checked_duration_since(&start)
+        .ok_or(Failure::Time(TimeError::Overflow))?;
+    let measured = *Nanoseconds::<u64>::try_from(measured)?.integer();
+
+    let error_ppm =
+        (i128::from(measured) - i128::from(expected)) * 1_000_000 / i128::from(expected);
+    let error_ppm = error_ppm.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64;
+    if error_ppm.unsigned_abs() > u64::from(tolerance_ppm) {
+        Err(Failure::Inaccurate { error_ppm })
+    } else {
+        Ok(error_ppm)
+    }
+}
@@ -0,0 +1,171 @@
+//! Ready-made [`Clock`](crate::Clock)s over the Cortex-M core peripherals
+//!
+//! - [`SysTickClock`] reads the 24-bit SysTick counter (available on every Cortex-M core)
+//! - [`DwtClock`] reads the 32-bit DWT cycle counter (`CYCCNT`, not available on ARMv6-M cores, eg.
+//!   Cortex-M0/M0+)
+//!
+//! Both are parameterized by the frequency of the counter's clock through a [`Frequency`] type,
+//! which sets the clock's _scaling factor_.
+
+use crate::{clock, fraction::Fraction, rate::Hertz, Instant};
+use ::cortex_m::peripheral::SYST;
+#[cfg(target_has_atomic = "32")]
+use ::cortex_m::peripheral::{DCB, DWT};
+use core::{fmt, marker::PhantomData};
+
+pub use ::cortex_m::peripheral::syst::SystClkSource;
+
+/// The (fixed) frequency of a counter's clock, usually the core clock
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{cortex::Frequency, rate::*};
+///
+/// struct CoreClock;
+/// impl Frequency for CoreClock {
+///     const FREQUENCY: Hertz<u32> = Hertz(64_000_000);
+/// }
+/// ```
+pub trait Frequency {
+    /// The frequency
+    const FREQUENCY: Hertz<u32>;
+}
+
+/// The value SysTick reloads on wrapping, its full 24-bit range
+const SYST_RELOAD: u32 = 0x00FF_FFFF;
+
+/// A [`Clock`](crate::Clock) over the SysTick counter
+///
+/// The counter is reloaded with its full 24-bit range and free-runs, so it can't be used for
+/// periodic SysTick interrupts meanwhile. Instants wrap every 2<sup>24</sup> cycles.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use embedded_time::{cortex::*, duration::*, rate::*, Clock as _};
+///
+/// struct CoreClock;
+/// impl Frequency for CoreClock {
+///     const FREQUENCY: Hertz<u32> = Hertz(64_000_000);
+/// }
+///
+/// let peripherals = cortex_m::Peripherals::take().unwrap();
+/// let clock = SysTickClock::<CoreClock>::new(peripherals.SYST, SystClkSource::Core);
+///
+/// clock.new_timer(Microseconds(100_u32)).start().unwrap().wait().unwrap();
+/// ```
+pub struct SysTickClock<Freq: Frequency> {
+    syst: SYST,
+    _frequency: PhantomData<Freq>,
+}
+
+impl<Freq: Frequency> SysTickClock<Freq> {
+    /// Configure and start the SysTick counter
+    ///
+    /// `Freq` must be the frequency of the chosen clock source.
+    pub fn new(mut syst: SYST, source: SystClkSource) -> Self {
+        syst.disable_counter();
+        syst.set_clock_source(source);
+        syst.set_reload(SYST_RELOAD);
+        syst.clear_current();
+        syst.enable_counter();
+
+        Self {
+            syst,
+            _frequency: PhantomData,
+        }
+    }
+
+    /// Stop the counter and release the peripheral
+    pub fn free(mut self) -> SYST {
+        self.syst.disable_counter();
+        self.syst
+    }
+}
+
+impl<Freq: Frequency> fmt::Debug for SysTickClock<Freq> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SysTickClock")
+            .field("frequency", &Freq::FREQUENCY)
+            .finish()
+    }
+}
+
+impl<Freq: Frequency> crate::Clock for SysTickClock<Freq> {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, Freq::FREQUENCY.0);
+    const COUNTER_BITS: u32 = 24;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        // SysTick counts down
+        Ok(Instant::new(SYST_RELOAD - SYST::get_current()))
+    }
+}
+
+/// A [`Clock`](crate::Clock) over the DWT cycle counter (`CYCCNT`)
+///
+/// The counter counts core clock cycles (`Freq` must be the core clock frequency), even while a
+/// debugger is not attached. Instants wrap every 2<sup>32</sup> cycles.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use embedded_time::{cortex::*, duration::*, rate::*, Clock as _};
+///
+/// struct CoreClock;
+/// impl Frequency for CoreClock {
+///     const FREQUENCY: Hertz<u32> = Hertz(64_000_000);
+/// }
+///
+/// let mut peripherals = cortex_m::Peripherals::take().unwrap();
+/// let clock = DwtClock::<CoreClock>::new(&mut peripherals.DCB, peripherals.DWT);
+///
+/// let start = clock.try_now().unwrap();
+/// // ...
+/// let elapsed = clock.try_now().unwrap().checked_duration_since(&start);
+/// ```
+// `CYCCNT` is missing from ARMv6-M targets, the only Cortex-M targets without 32-bit atomics
+#[cfg(target_has_atomic = "32")]
+pub struct DwtClock<Freq: Frequency> {
+    dwt: DWT,
+    _frequency: PhantomData<Freq>,
+}
+
+#[cfg(target_has_atomic = "32")]
+impl<Freq: Frequency> DwtClock<Freq> {
+    /// Enable tracing and start the cycle counter
+    pub fn new(dcb: &mut DCB, mut dwt: DWT) -> Self {
+        dcb.enable_trace();
+        dwt.enable_cycle_counter();
+
+        Self {
+            dwt,
+            _frequency: PhantomData,
+        }
+    }
+
+    /// Release the peripheral (the counter keeps running)
+    pub fn free(self) -> DWT {
+        self.dwt
+    }
+}
+
+#[cfg(target_has_atomic = "32")]
+impl<Freq: Frequency> fmt::Debug for DwtClock<Freq> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DwtClock")
+            .field("frequency", &Freq::FREQUENCY)
+            .finish()
+    }
+}
+
+#[cfg(target_has_atomic = "32")]
+impl<Freq: Frequency> crate::Clock for DwtClock<Freq> {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, Freq::FREQUENCY.0);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(DWT::get_cycle_count()))
+    }
+}
@@ -0,0 +1,140 @@
+//! Absolute deadlines
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::FixedPoint,
+    ConversionError, Instant, TimeError,
+};
+use core::convert::TryFrom;
+
+/// An absolute point in time by which an operation must complete
+///
+/// Passing a deadline (rather than a timeout duration) through layered driver calls keeps the
+/// overall time limit fixed, however many steps the operation takes. Deadlines are only valid
+/// within the clock's [valid comparison window](Instant::valid_comparison_window) of the instants
+/// they are compared to.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{deadline::Deadline, duration::*, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut deadline = Deadline::new(Instant::<Clock>::new(100), Milliseconds(50_u32)).unwrap();
+///
+/// assert!(!deadline.is_expired(Instant::new(149)));
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(deadline.remaining(Instant::new(120)).unwrap()),
+///     Ok(Milliseconds(30_u32))
+/// );
+/// assert!(deadline.is_expired(Instant::new(150)));
+///
+/// deadline.extend(Milliseconds(10_u32)).unwrap();
+/// assert!(!deadline.is_expired(Instant::new(150)));
+/// ```
+#[derive(Debug)]
+pub struct Deadline<Clock: crate::Clock> {
+    expiration: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Deadline<Clock> {
+    /// Construct a deadline the given duration after `now`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The duration is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The duration
+    ///   could not be converted to clock ticks
+    pub fn new<Dur>(now: Instant<Clock>, duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            expiration: Self::add(now, duration)?,
+        })
+    }
+
+    /// Construct a deadline the given duration from now
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - See [`Deadline::new()`]
+    pub fn start<Dur>(clock: &Clock, duration: Dur) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self::new(clock.try_now()?, duration)?)
+    }
+
+    /// Construct a deadline at the given instant
+    pub fn at(expiration: Instant<Clock>) -> Self {
+        Self { expiration }
+    }
+
+    /// Returns the instant the deadline expires at
+    pub fn expiration(&self) -> Instant<Clock> {
+        self.expiration
+    }
+
+    /// Returns `true` once `now` reaches the deadline
+    pub fn is_expired(&self, now: Instant<Clock>) -> bool {
+        now >= self.expiration
+    }
+
+    /// Returns the time left until the deadline or [`None`] if it has expired
+    pub fn remaining(&self, now: Instant<Clock>) -> Option<duration::Generic<Clock::T>> {
+        self.expiration
+            .checked_duration_since(&now)
+            .filter(|remaining| *remaining.integer() > Clock::T::from(0))
+    }
+
+    /// Move the deadline later by the given duration
+    ///
+    /// # Errors
+    ///
+    /// See [`Deadline::new()`] (the deadline is unchanged)
+    pub fn extend<Dur>(&mut self, duration: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        self.expiration = Self::add(self.expiration, duration)?;
+        Ok(())
+    }
+
+    fn add<Dur>(instant: Instant<Clock>, duration: Dur) -> Result<Instant<Clock>, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(instant.wrapping_add_ticks(Instant::<Clock>::window_ticks(duration)?))
+    }
+}
+
+impl<Clock: crate::Clock> Copy for Deadline<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Deadline<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Deadline<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.expiration == other.expiration
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Deadline<Clock> {}
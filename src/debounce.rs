@@ -0,0 +1,122 @@
+//! Debouncing of digital inputs
+
+use crate::{clock, duration::Duration, fixed_point::FixedPoint, ConversionError, Instant};
+use core::convert::TryFrom;
+
+/// A change of the debounced state
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Edge {
+    /// The input has been `true` for the press duration
+    Pressed,
+    /// The input has been `false` for the release duration
+    Released,
+}
+
+/// Filters a bouncing boolean input (eg. a button or a mechanical switch)
+///
+/// The debounced state changes once the raw input has differed from it for the press (to `true`)
+/// or release (to `false`) duration. A sample agreeing with the debounced state restarts the
+/// count.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{debounce::*, duration::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut button =
+///     Debouncer::<Clock>::new(false, Milliseconds(20_u32), Milliseconds(50_u32)).unwrap();
+///
+/// // a bounce
+/// assert_eq!(button.update(Instant::new(0), true), None);
+/// assert_eq!(button.update(Instant::new(5), false), None);
+///
+/// assert_eq!(button.update(Instant::new(10), true), None);
+/// assert_eq!(button.update(Instant::new(30), true), Some(Edge::Pressed));
+/// assert!(button.is_pressed());
+/// ```
+#[derive(Debug)]
+pub struct Debouncer<Clock: crate::Clock> {
+    /// The ticks the input must be `true` for
+    press: Clock::T,
+    /// The ticks the input must be `false` for
+    release: Clock::T,
+    pressed: bool,
+    /// The instant the input started differing from the debounced state
+    changing: Option<Instant<Clock>>,
+}
+
+impl<Clock: crate::Clock> Debouncer<Clock> {
+    /// Construct from the initial state and the press and release durations
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: A duration is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: A duration
+    ///   could not be converted to clock ticks
+    pub fn new<Dur>(pressed: bool, press: Dur, release: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            press: Instant::<Clock>::window_ticks(press)?,
+            release: Instant::<Clock>::window_ticks(release)?,
+            pressed,
+            changing: Option::None,
+        })
+    }
+
+    /// Feed a sample of the input taken at the given instant, returning the change of the
+    /// debounced state (if any)
+    pub fn update(&mut self, now: Instant<Clock>, input: bool) -> Option<Edge> {
+        if input == self.pressed {
+            self.changing = Option::None;
+            return Option::None;
+        }
+
+        let since = *self.changing.get_or_insert(now);
+        let duration = if input { self.press } else { self.release };
+        // `now` can't precede the start of the change
+        if now.wrapping_ticks_since(&since) < duration {
+            return Option::None;
+        }
+
+        self.pressed = input;
+        self.changing = Option::None;
+        Option::Some(if input { Edge::Pressed } else { Edge::Released })
+    }
+
+    /// Sample the input now
+    ///
+    /// # Errors
+    ///
+    /// [`clock::Error`]: The clock could not be read
+    pub fn sample(&mut self, clock: &Clock, input: bool) -> Result<Option<Edge>, clock::Error> {
+        Ok(self.update(clock.try_now()?, input))
+    }
+
+    /// Returns the debounced state
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Returns `true` if the input differs from the debounced state (it may be about to change)
+    pub fn is_changing(&self) -> bool {
+        self.changing.is_some()
+    }
+
+    /// Set the debounced state, discarding a pending change
+    pub fn reset(&mut self, pressed: bool) {
+        self.pressed = pressed;
+        self.changing = Option::None;
+    }
+}
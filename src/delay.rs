@@ -0,0 +1,145 @@
+//! Delays longer than a clock's valid comparison window
+//!
+//! An [`Instant`] can only be compared with another within the clock's
+//! [valid comparison window](Instant::valid_comparison_window) (half of its
+//! [wrap horizon](crate::Clock::wrap_horizon)). For a fast, narrow clock (eg. a 32-bit counter at
+//! 1 MHz) that is only ~35 minutes. A [`LongDelay`] splits a longer wait into consecutive chunks,
+//! each of which fits within the window.
+
+use crate::{duration::Duration, fixed_point::FixedPoint, Instant, TimeError};
+use core::convert::TryFrom;
+
+/// A delay of arbitrary length, split into chunks that fit within the clock's valid comparison
+/// window
+///
+/// Each chunk is scheduled from the end of the previous one (rather than from the time of
+/// polling), so the total delay is exact regardless of how late the chunks are polled (as long as
+/// polling gaps are shorter than the window).
+///
+/// The delay can be waited on by blocking ([`LongDelay::wait()`]), by polling
+/// ([`LongDelay::is_complete()`]), by programming an alarm/compare-match for
+/// [`LongDelay::next_deadline()`] and calling [`LongDelay::is_complete()`] when it fires, or
+/// asynchronously with an [`AsyncClock`](crate::asynch::AsyncClock) (`LongDelay::wait_async()`,
+/// `async` feature).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{delay::LongDelay, duration::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(0))}
+/// }
+///
+/// // 2 hours is far beyond the ~35 minute comparison window of a 32-bit, 1 MHz clock
+/// let delay = LongDelay::new(&Clock, Hours(2_u32)).unwrap();
+/// assert_eq!(delay.remaining_chunks(), 3);
+/// ```
+#[derive(Debug)]
+pub struct LongDelay<'a, Clock: crate::Clock> {
+    clock: &'a Clock,
+    deadline: Instant<Clock>,
+    remaining_ticks: u64,
+}
+
+impl<'a, Clock: crate::Clock> LongDelay<'a, Clock>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// Start a new delay of the given duration from this instant
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - [`TimeError::ConversionFailure`], [`TimeError::Unspecified`]: The duration could not be
+    ///   converted to clock ticks
+    pub fn new<Dur>(clock: &'a Clock, duration: Dur) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        let mut delay = Self {
+            clock,
+            deadline: clock.try_now()?,
+            remaining_ticks: duration.into_ticks(Clock::SCALING_FACTOR)?,
+        };
+        delay.schedule_next_chunk();
+
+        Ok(delay)
+    }
+
+    fn schedule_next_chunk(&mut self) {
+        let window = u64::from(*Instant::<Clock>::valid_comparison_window().integer());
+        let chunk = self.remaining_ticks.min(window);
+
+        // the chunk is no larger than the window, which is itself a `Clock::T`
+        let chunk_ticks = Clock::T::try_from(chunk).ok().unwrap();
+        self.deadline = self.deadline.wrapping_add_ticks(chunk_ticks);
+        self.remaining_ticks -= chunk;
+    }
+
+    /// Returns the end of the current chunk
+    ///
+    /// For alarm-based implementations, this is the [`Instant`] to program the alarm with.
+    pub fn next_deadline(&self) -> Instant<Clock> {
+        self.deadline
+    }
+
+    /// Returns the number of chunks remaining after the current one
+    pub fn remaining_chunks(&self) -> u64 {
+        let window = u64::from(*Instant::<Clock>::valid_comparison_window().integer());
+        match self.remaining_ticks % window {
+            0 => self.remaining_ticks / window,
+            _ => self.remaining_ticks / window + 1,
+        }
+    }
+
+    /// Check whether the entire delay has elapsed
+    ///
+    /// If the current chunk has elapsed, the next one is scheduled.
+    pub fn is_complete(&mut self) -> Result<bool, TimeError> {
+        if self.clock.try_now()? < self.deadline {
+            Ok(false)
+        } else if self.remaining_ticks == 0 {
+            Ok(true)
+        } else {
+            self.schedule_next_chunk();
+            Ok(false)
+        }
+    }
+
+    /// Block until the entire delay has elapsed
+    pub fn wait(mut self) -> Result<(), TimeError> {
+        while !self.is_complete()? {}
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, Clock: crate::asynch::AsyncClock> LongDelay<'a, Clock>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// Wait asynchronously until the entire delay has elapsed, awaiting a
+    /// [`Delay`](crate::asynch::Delay) per chunk
+    ///
+    /// # Errors
+    ///
+    /// [`TimeError::Clock`]: The clock could not be read
+    pub async fn wait_async(mut self) -> Result<(), TimeError> {
+        loop {
+            crate::asynch::Delay::until(self.clock, self.deadline).await?;
+            if self.remaining_ticks == 0 {
+                return Ok(());
+            }
+            self.schedule_next_chunk();
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Disciplining of clocks to an external time reference
+//!
+//! Firmware synchronizing to an NTP, PTP, or GNSS reference estimates how far its local clock is
+//! off (the _offset_) and how fast it drifts (the _rate_ error). A [`DisciplinedClock`] applies
+//! both corrections to another clock, updated at runtime as the estimates improve.
+
+use crate::{
+    calibration::{apply_ppb, MAX_TRIM_PPB},
+    clock,
+    duration::Duration,
+    fixed_point::FixedPoint,
+    ConversionError, Instant,
+};
+use core::{cell::Cell, convert::TryFrom};
+
+/// A [`Clock`](crate::Clock) correcting the rate and offset of another clock
+///
+/// The rate correction is applied to the ticks elapsed between consecutive readings (sub-tick
+/// remainders are carried over), so changing it never makes the clock jump. Offset corrections
+/// step the clock on the next reading; a step backward makes the clock run backwards, so small
+/// offsets are usually better slewed away with a temporary rate correction. For this to work, the
+/// clock must be read at least once per [wrap horizon](crate::Clock::wrap_horizon) of the
+/// wrapped clock.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{discipline::DisciplinedClock, duration::*, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Oscillator(Cell<u32>);
+/// impl embedded_time::Clock for Oscillator {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// let clock = DisciplinedClock::new(Oscillator(Cell::new(0)));
+/// clock.try_now().unwrap();
+///
+/// // the reference says the oscillator runs 20 ppm slow and is 5 ms behind
+/// clock.set_rate_ppm(20);
+/// clock.step_forward(Milliseconds(5_u32)).unwrap();
+///
+/// clock.inner().0.set(1_000_000);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(1_005_020)));
+/// ```
+#[derive(Debug)]
+pub struct DisciplinedClock<Clock: crate::Clock> {
+    clock: Clock,
+    rate_ppb: Cell<i32>,
+    /// The (wrapping) offset correction not yet applied
+    step: Cell<Clock::T>,
+    /// The last raw and corrected readings
+    last: Cell<Option<(Clock::T, Clock::T)>>,
+    /// Uncorrected error carried over from previous readings (ticks × 10⁻⁹)
+    residue: Cell<i64>,
+}
+
+impl<Clock: crate::Clock> DisciplinedClock<Clock> {
+    /// Wrap a clock (without corrections)
+    pub fn new(clock: Clock) -> Self {
+        Self {
+            clock,
+            rate_ppb: Cell::new(0),
+            step: Cell::new(Clock::T::from(0)),
+            last: Cell::new(Option::None),
+            residue: Cell::new(0),
+        }
+    }
+
+    /// Set the rate correction in ppm (positive: speed up the clock)
+    pub fn set_rate_ppm(&self, ppm: i32) {
+        self.set_rate_ppb(ppm.saturating_mul(1_000));
+    }
+
+    /// Set the rate correction in ppb (positive: speed up the clock), limited to
+    /// ±[`MAX_TRIM_PPB`]
+    pub fn set_rate_ppb(&self, ppb: i32) {
+        self.rate_ppb.set(ppb.clamp(-MAX_TRIM_PPB, MAX_TRIM_PPB));
+    }
+
+    /// Returns the rate correction in ppb
+    pub fn rate_ppb(&self) -> i32 {
+        self.rate_ppb.get()
+    }
+
+    /// Step the clock forward by the given offset (truncated to whole ticks) on the next reading
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError`]: The offset could not be converted to clock ticks
+    pub fn step_forward<Dur>(&self, offset: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let ticks = *offset.to_generic(Clock::SCALING_FACTOR)?.integer();
+        let step = Instant::<Clock>::new(self.step.get()).wrapping_add_ticks(ticks);
+        self.step.set(*step.duration_since_epoch().integer());
+
+        Ok(())
+    }
+
+    /// Step the clock backward by the given offset (truncated to whole ticks) on the next reading
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError`]: The offset could not be converted to clock ticks
+    pub fn step_backward<Dur>(&self, offset: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let ticks = *offset.to_generic(Clock::SCALING_FACTOR)?.integer();
+        let step = Instant::<Clock>::new(self.step.get()).wrapping_sub_ticks(ticks);
+        self.step.set(*step.duration_since_epoch().integer());
+
+        Ok(())
+    }
+
+    /// Returns a reference to the wrapped clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for DisciplinedClock<Clock>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let raw = self.clock.try_now()?;
+
+        let corrected = match self.last.get() {
+            Option::None => Instant::new(*raw.duration_since_epoch().integer()),
+            Option::Some((last_raw, last_corrected)) => apply_ppb(
+                Instant::<Self>::new(last_corrected),
+                raw.wrapping_ticks_since(&Instant::new(last_raw)),
+                self.rate_ppb(),
+                &self.residue,
+            ),
+        };
+        let corrected = corrected.wrapping_add_ticks(self.step.replace(Clock::T::from(0)));
+        self.last.set(Option::Some((
+            *raw.duration_since_epoch().integer(),
+            *corrected.duration_since_epoch().integer(),
+        )));
+
+        Ok(corrected)
+    }
+}
@@ -2,15 +2,17 @@
 
 pub use crate::fraction::Fraction;
 use crate::{
+    accuracy::{self, Ppm},
     fixed_point::{self, FixedPoint},
-    rate,
+    humanize::Humanized,
     time_int::TimeInt,
     ConversionError,
 };
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
+use core::{convert::TryFrom, ops::RangeInclusive, prelude::v1::*};
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
-use num::{CheckedDiv, CheckedMul};
+#[doc(inline)]
+pub use units::Extensions as NumericalDuration;
 #[doc(inline)]
 pub use units::*;
 
@@ -25,7 +27,8 @@ pub use units::*;
 ///
 /// let millis = <Milliseconds>::new(5);
 /// let millis = Milliseconds(5_u32);
-/// let millis = 5_u32.milliseconds();
+/// let millis = Milliseconds::from(5_u32);
+/// let millis = 5_u32.milliseconds(); // see `NumericalDuration`
 /// ```
 ///
 /// # Get the integer part
@@ -36,16 +39,28 @@ pub use units::*;
 /// let millis = Milliseconds(23_u32).integer();
 ///
 /// assert_eq!(millis, &23_u32);
+/// assert_eq!(u32::from(Milliseconds(23_u32)), 23_u32);
 /// ```
 ///
+/// Prefer `new()`/`from()` and `integer()`/`into()` to the tuple field where the code should keep
+/// working if the representation of the duration types changes.
+///
 /// # Formatting
 ///
-/// Just forwards the underlying integer to [`core::fmt::Display::fmt()`]
+/// The underlying integer (forwarded to [`core::fmt::Display::fmt()`], so the width, fill, etc.
+/// apply to it) followed by the unit symbol. The alternate form (`{:#}`) normalizes the duration
+/// into its non-zero components from hours down to nanoseconds.
 ///
 /// ```rust
 /// use embedded_time::duration::*;
 ///
-/// assert_eq!(format!("{}", Seconds(123_u32)), "123");
+/// assert_eq!(format!("{}", Seconds(123_u32)), "123 s");
+/// assert_eq!(format!("{}", Microseconds(250_u32)), "250 µs");
+/// assert_eq!(format!("{:03}", Milliseconds(5_u32)), "005 ms");
+///
+/// assert_eq!(format!("{:#}", Seconds(90_u32)), "1 min 30 s");
+/// assert_eq!(format!("{:#}", Milliseconds(3_723_004_u32)), "1 h 2 min 3 s 4 ms");
+/// assert_eq!(format!("{:#}", Milliseconds(0_u32)), "0 ms");
 /// ```
 ///
 /// # Getting H:M:S.MS... Components
@@ -62,6 +77,8 @@ pub use units::*;
 /// // ...
 /// ```
 ///
+/// To only format them, see [`Duration::hhmmss()`].
+///
 /// # Converting between `Duration`s
 ///
 /// Many intra-duration conversions can be done using `From`/`Into`:
@@ -89,6 +106,33 @@ pub use units::*;
 /// assert_eq!(millis.integer(), &23_000_u32);
 /// ```
 ///
+/// # Converting with rounding
+///
+/// The conversions above truncate. [`try_from_rounded()`](Seconds::try_from_rounded) selects the
+/// [`Rounding`](crate::fraction::Rounding), eg. so that a watchdog is never fed late.
+///
+/// ```rust
+/// use embedded_time::{duration::*, fraction::Rounding, ConversionError};
+///
+/// assert_eq!(Seconds::<u32>::try_from_rounded(Milliseconds(1_999_u32), Rounding::Down), Ok(Seconds(1)));
+/// assert_eq!(Seconds::<u32>::try_from_rounded(Milliseconds(1_499_u32), Rounding::Nearest), Ok(Seconds(1)));
+/// assert_eq!(Seconds::<u32>::try_from_rounded(Milliseconds(1_500_u32), Rounding::Nearest), Ok(Seconds(2)));
+/// assert_eq!(Seconds::<u32>::try_from_rounded(Milliseconds(1_001_u32), Rounding::Up), Ok(Seconds(2)));
+/// ```
+///
+/// Where discarding a remainder is a bug (eg. protocol fields),
+/// [`try_from_exact()`](Seconds::try_from_exact) fails instead.
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// assert_eq!(Seconds::<u32>::try_from_exact(Milliseconds(2_000_u32)), Ok(Seconds(2)));
+/// assert_eq!(
+///     Seconds::<u32>::try_from_exact(Milliseconds(1_999_u32)),
+///     Err(ConversionError::LossOfPrecision)
+/// );
+/// ```
+///
 /// # Converting to `core` types
 ///
 /// ([`core::time::Duration`])
@@ -118,6 +162,20 @@ pub use units::*;
 /// assert_eq!(core_duration.subsec_nanos(), 569_000_000);
 /// ```
 ///
+/// ## Errors
+///
+/// [`ConversionError::Unspecified`]: The duration is longer than `u64::MAX` seconds
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(
+///     core::time::Duration::try_from(Hours(u64::MAX)),
+///     Err(ConversionError::Unspecified)
+/// );
+/// ```
+///
 /// # Converting from `core` types
 ///
 /// ([`core::time::Duration`])
@@ -261,10 +319,73 @@ pub use units::*;
 /// let _ = Seconds(u32::MAX) + Seconds(1_u32);
 /// ```
 ///
+/// ## Checked
+///
+/// [`checked_add()`](FixedPoint::checked_add) and [`checked_sub()`](FixedPoint::checked_sub)
+/// return [`None`] rather than panicking.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Seconds(u32::MAX).checked_add(&Seconds(1_u32)), None);
+/// assert_eq!(Seconds(1_u32).checked_sub(&Milliseconds(2_000_u32)), None);
+/// ```
+///
+/// ## Saturating
+///
+/// [`saturating_add()`](FixedPoint::saturating_add) and
+/// [`saturating_sub()`](FixedPoint::saturating_sub) clamp the result at the bounds of the type.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Seconds(u32::MAX).saturating_add(&Seconds(1_u32)), Seconds(u32::MAX));
+/// assert_eq!(Seconds(1_u32).saturating_sub(&Milliseconds(2_000_u32)), Seconds(0_u32));
+/// ```
+///
 /// # Mul/Div
 ///
 /// Durations may also be multiplied and divided by integers. The result is of the LHS type. Both
-/// _panicky_ and _checked_ operations are available.
+/// _panicky_, _checked_ ([`checked_mul()`](FixedPoint::checked_mul),
+/// [`checked_div()`](FixedPoint::checked_div)), and _saturating_
+/// ([`saturating_mul()`](FixedPoint::saturating_mul)) operations are available.
+///
+/// # Scaling by a `Fraction`
+///
+/// Multiplying by a [`Fraction`] (eg. by 3/2 for a retry backoff) truncates the result and panics
+/// if it overflows. [`checked_mul_fraction()`](Milliseconds::checked_mul_fraction) selects the
+/// [`Rounding`](crate::fraction::Rounding) and returns [`None`] rather than panicking.
+///
+/// ```rust
+/// use embedded_time::{duration::*, fraction::Rounding, ConversionError};
+///
+/// assert_eq!(Milliseconds(100_u32) * Fraction::new(3, 2), Milliseconds(150_u32));
+/// assert_eq!(Milliseconds(5_u32) * Fraction::new(1, 2), Milliseconds(2_u32));
+///
+/// assert_eq!(
+///     Milliseconds(5_u32).checked_mul_fraction(&Fraction::new(1, 2), Rounding::Nearest),
+///     Some(Milliseconds(3_u32))
+/// );
+/// assert_eq!(
+///     Milliseconds(10_u32).checked_mul_fraction(&Fraction::new(1, 3), Rounding::Up),
+///     Some(Milliseconds(4_u32))
+/// );
+/// assert_eq!(
+///     Milliseconds(u32::MAX).checked_mul_fraction(&Fraction::new(3, 2), Rounding::Down),
+///     None
+/// );
+/// ```
+///
+/// # Multiplying by a `Rate`
+///
+/// A duration multiplied by a frequency results in the number of cycles within the duration. See
+/// [`Rate`](crate::rate::Rate#multiplying-by-a-duration).
+///
+/// ```rust
+/// use embedded_time::{duration::*, rate::*};
+///
+/// assert_eq!(Milliseconds(10_u32) * Kilohertz(8_u32), 80);
+/// ```
 ///
 /// # Comparisons
 ///
@@ -278,6 +399,9 @@ pub use units::*;
 /// assert!(Seconds(2_u32) > Milliseconds(1_999_u32));
 /// ```
 ///
+/// Each duration type is also [`Ord`] and [`Hash`](core::hash::Hash), so it can be used as the key
+/// of sorted queues and maps.
+///
 /// # Remainder
 ///
 /// ```rust
@@ -285,6 +409,133 @@ pub use units::*;
 ///
 /// assert_eq!(Minutes(62_u32) % Hours(1_u32), Minutes(2_u32));
 /// ```
+///
+/// # Zero
+///
+/// The [`Default`] of the duration types is zero, so they can be fields of `#[derive(Default)]`
+/// structs.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// #[derive(Default)]
+/// struct Config {
+///     timeout: Milliseconds<u32>,
+/// }
+///
+/// assert_eq!(Config::default().timeout, Milliseconds::<u32>::zero());
+/// assert!(Milliseconds::<u32>::zero().is_zero());
+/// assert!(!Seconds(1_u32).is_zero());
+/// ```
+///
+/// # Min/Max/Clamp
+///
+/// The inherent `min()`, `max()`, and `clamp()` of the duration types take durations of any unit
+/// and return the caller's unit (truncated).
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Milliseconds(1_500_u32).min(Seconds(1_u32)), Milliseconds(1_000_u32));
+/// assert_eq!(Seconds(5_u32).max(Milliseconds(7_500_u32)), Seconds(7_u32));
+///
+/// // no shorter than 10 ms, no longer than 2 s
+/// let retry = Microseconds(500_u32).clamp(Milliseconds(10_u32), Seconds(2_u32));
+/// assert_eq!(retry, Microseconds(10_000_u32));
+/// ```
+///
+/// `max()` saturates if the other duration doesn't fit in the caller's type.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Nanoseconds(1_u32).max(Seconds(5_u32)), Nanoseconds(u32::MAX));
+/// ```
+///
+/// # Compound assignment
+///
+/// All of the above operators have assignment variants (`+=`, `-=`, `*=`, `/=`, `%=`) with the
+/// same semantics (and panics). As the duration integer types are unsigned, durations can't be
+/// negated.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// let mut timeout = Milliseconds(500_u32);
+/// timeout += Seconds(1_u32);
+/// timeout *= 2;
+/// timeout -= Milliseconds(1_000_u32);
+/// timeout /= 4;
+/// assert_eq!(timeout, Milliseconds(500_u32));
+///
+/// timeout %= Milliseconds(300_u32);
+/// assert_eq!(timeout, Milliseconds(200_u32));
+/// ```
+///
+/// # `const` construction and conversion
+///
+/// The unit constructors and `new()` are `const`, as is conversion to the ticks of another
+/// _scaling factor_ ([`to_ticks()`](Milliseconds::to_ticks)) for durations of any primitive
+/// unsigned integer type. This allows durations, and values derived from them (eg. timer reload
+/// registers), to be computed at compile time.
+///
+/// ## `const` conversions
+///
+/// ```rust
+/// use embedded_time::{duration::*, fixed_point::FixedPoint};
+///
+/// const BLINK_PERIOD: Milliseconds<u32> = Milliseconds(500);
+/// const TIMEOUT: Seconds<u64> = Seconds::new(3);
+///
+/// // 32.768 kHz timer reload value
+/// const RELOAD: u32 = match BLINK_PERIOD.to_ticks(Fraction::new(1, 32_768)) {
+///     Some(ticks) => ticks,
+///     None => panic!("the blink period doesn't fit in the timer"),
+/// };
+/// assert_eq!(RELOAD, 16_384);
+///
+/// // between units
+/// const TIMEOUT_MICROS: Option<u64> = TIMEOUT.to_ticks(Microseconds::<u64>::SCALING_FACTOR);
+/// assert_eq!(TIMEOUT_MICROS, Some(3_000_000));
+///
+/// assert_eq!(Seconds(u32::MAX).to_ticks(Nanoseconds::<u32>::SCALING_FACTOR), None);
+/// ```
+///
+/// # Integer types
+///
+/// The _integer_ of every unit is unsigned, either [`u32`] (the default) or [`u64`], matching the
+/// width of hardware tick counters. Conversions, _scaling factor_ math, and the limits are the same
+/// for both.
+///
+/// ```rust
+/// use embedded_time::{duration::*, fixed_point::FixedPoint};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(Minutes::<u32>::try_from(Hours(2_u32)), Ok(Minutes(120_u32)));
+/// assert_eq!(Seconds::<u64>::try_from(Minutes(2_u64)), Ok(Seconds(120_u64)));
+/// assert_eq!(Milliseconds::<u32>::try_from(Seconds(2_u32)), Ok(Milliseconds(2_000_u32)));
+/// assert_eq!(Microseconds::<u64>::try_from(Milliseconds(2_u64)), Ok(Microseconds(2_000_u64)));
+/// assert_eq!(Nanoseconds::<u64>::from(Microseconds(2_u32)), Nanoseconds(2_000_u64));
+/// assert_eq!(Hours::<u64>::from(Nanoseconds(7_200_000_000_000_u64)), Hours(2_u64));
+///
+/// // between integer types
+/// assert_eq!(Nanoseconds::<u64>::from(Seconds(u32::MAX)), Nanoseconds(4_294_967_295_000_000_000_u64));
+/// assert_eq!(Seconds::<u64>::from(Nanoseconds(u64::MAX)), Seconds(18_446_744_073_u64));
+/// assert!(Seconds::<u32>::try_from(Nanoseconds(u64::MAX)).is_err());
+/// assert!(Milliseconds::<u32>::try_from(Hours(u64::MAX)).is_err());
+///
+/// // scaling factor math
+/// assert_eq!(
+///     Milliseconds(1_500_u32).to_generic::<u32>(Fraction::new(1, 32_768)),
+///     Ok(Generic::new(49_152_u32, Fraction::new(1, 32_768)))
+/// );
+///
+/// // limits
+/// assert_eq!(Hours::<u32>::MIN, Hours(0_u32));
+/// assert_eq!(Nanoseconds::<u64>::MAX, Nanoseconds(u64::MAX));
+/// assert_eq!(Seconds(u32::MAX).checked_add(&Seconds(1_u32)), None);
+/// assert_eq!(Seconds(0_u64).checked_sub(&Seconds(1_u64)), None);
+/// ```
 pub trait Duration: Sized + Copy {
     /// Construct a `Generic` `Duration` from a _named_ `Duration` (eg.
     /// [`Milliseconds`])
@@ -344,7 +595,7 @@ pub trait Duration: Sized + Copy {
         ))
     }
 
-    /// Convert to _named_ [`Rate`](rate::Rate)
+    /// Convert to _named_ [`Rate`](crate::rate::Rate)
     ///
     /// (the duration is equal to the reciprocal of the rate)
     ///
@@ -388,7 +639,8 @@ pub trait Duration: Sized + Copy {
     ///     Err(ConversionError::DivByZero)
     /// );
     /// ```
-    fn to_rate<Rate: rate::Rate>(&self) -> Result<Rate, ConversionError>
+    #[cfg(feature = "rate")]
+    fn to_rate<Rate: crate::rate::Rate>(&self) -> Result<Rate, ConversionError>
     where
         Rate: FixedPoint,
         Self: FixedPoint,
@@ -399,33 +651,170 @@ pub trait Duration: Sized + Copy {
             .ok_or(ConversionError::Unspecified)?
             .recip();
 
-        if size_of::<Self::T>() >= size_of::<Rate::T>() {
-            fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &self
-                            .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Rate::SCALING_FACTOR,
-            )
-        } else {
-            fixed_point::FixedPoint::from_ticks(
-                Rate::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &Rate::T::try_from(*self.integer())
-                            .ok()
-                            .unwrap()
-                            .checked_mul(&Rate::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Rate::SCALING_FACTOR,
+        // (done in `u128`, so narrow integer types don't have to hold the conversion factor, but
+        // the product must fit in the wider of the two integer types)
+        let max = <Self as FixedPoint>::max_value()
+            .into_u128()
+            .max(<Rate as FixedPoint>::max_value().into_u128());
+        let denominator = self
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*conversion_factor.denominator()))
+            .filter(|denominator| *denominator <= max)
+            .ok_or(ConversionError::Overflow)?;
+        let integer = u128::from(*conversion_factor.numerator())
+            .checked_div(denominator)
+            .ok_or(ConversionError::DivByZero)?;
+
+        Rate::T::try_from_u128(integer)
+            .map(Rate::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    /// Returns a coarse, human-friendly approximation of the duration (eg. "about 5 minutes")
+    ///
+    /// See [`humanize`](crate::humanize) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Seconds(310_u32).humanize().to_string(), "about 5 minutes");
+    /// ```
+    fn humanize(&self) -> Humanized
+    where
+        Self: FixedPoint,
+        u64: TryFrom<Self::T>,
+    {
+        Humanized::from_seconds(self.into_ticks(Fraction::new(1, 1)).unwrap_or(u64::MAX))
+    }
+
+    /// Returns a formatting adapter rendering the duration as `HH:MM:SS.mmm`
+    ///
+    /// No allocation is needed, so it can be written directly to (eg.) a character LCD or a log
+    /// through [`core::fmt::Write`]. Sub-millisecond parts are truncated, and hours beyond `99`
+    /// take more digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// assert_eq!(Milliseconds(3_723_004_u32).hhmmss().to_string(), "01:02:03.004");
+    /// assert_eq!(Hours(100_u32).hhmmss().to_string(), "100:00:00.000");
+    /// ```
+    fn hhmmss(&self) -> Hhmmss
+    where
+        Self: FixedPoint,
+    {
+        Hhmmss {
+            milliseconds: fixed_point::scale_u128(
+                self.integer().into_u128(),
+                Self::SCALING_FACTOR,
+                Fraction::new(1, 1_000),
             )
+            .unwrap_or(u128::MAX),
         }
     }
+
+    /// Returns `true` if the duration deviates from the expected one (of any unit) by no more than
+    /// the tolerance (relative to the expected duration)
+    ///
+    /// Useful for asserting timing requirements, eg. in tests. Also returns `false` if the
+    /// comparison overflows (only possible with extreme _scaling factors_).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{accuracy::Ppm, duration::*};
+    ///
+    /// assert!(Microseconds(10_050_u32).within(Milliseconds(10_u32), Ppm::from_percent(1)));
+    /// assert!(Microseconds(9_900_u32).within(Milliseconds(10_u32), Ppm::from_percent(1)));
+    /// assert!(!Microseconds(9_899_u32).within(Milliseconds(10_u32), Ppm::from_percent(1)));
+    /// ```
+    fn within<Expected>(&self, expected: Expected, tolerance: Ppm) -> bool
+    where
+        Self: FixedPoint,
+        Expected: Duration + FixedPoint,
+    {
+        accuracy::within(
+            (self.integer().into_u128(), Self::SCALING_FACTOR),
+            (expected.integer().into_u128(), Expected::SCALING_FACTOR),
+            tolerance,
+        )
+    }
+
+    /// Returns the range of values that can be converted to the given _named_ duration type
+    /// without overflow
+    ///
+    /// Useful for validating user-supplied durations against the limits of the types they will be
+    /// converted to. Conversions of values beyond the range fail (or panic for infallible `From`
+    /// conversions).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::duration::*;
+    ///
+    /// let range = Seconds::<u32>::representable_range_in::<Milliseconds<u32>>();
+    /// assert_eq!(range, Seconds(0_u32)..=Seconds(4_294_967_u32));
+    /// assert!(!range.contains(&Seconds(5_000_000_u32)));
+    ///
+    /// assert_eq!(
+    ///     Seconds::<u32>::representable_range_in::<Milliseconds<u64>>(),
+    ///     Seconds::<u32>::MIN..=Seconds::<u32>::MAX
+    /// );
+    /// ```
+    fn representable_range_in<Dest>() -> RangeInclusive<Self>
+    where
+        Self: FixedPoint,
+        Dest: Duration + FixedPoint,
+        u64: From<Self::T> + From<Dest::T>,
+        Self::T: TryFrom<u64>,
+    {
+        let max = u64::from(Self::max_value());
+
+        // the largest integer converting to less than `Dest::max_value() + 1`:
+        // ceil((Dest max + 1) × Dest scaling factor / Self scaling factor) - 1
+        let numerator = (u128::from(u64::from(Dest::max_value())) + 1)
+            .checked_mul(u128::from(*Dest::SCALING_FACTOR.numerator()))
+            .and_then(|numerator| {
+                numerator.checked_mul(u128::from(*Self::SCALING_FACTOR.denominator()))
+            });
+        let denominator = u128::from(*Dest::SCALING_FACTOR.denominator())
+            * u128::from(*Self::SCALING_FACTOR.numerator());
+        let end = numerator.map_or(max, |numerator| {
+            let end = numerator / denominator - u128::from(numerator % denominator == 0);
+            u64::try_from(end).map_or(max, |end| end.min(max))
+        });
+
+        // `end` is no larger than `max`
+        let end = Self::T::try_from(end).ok().unwrap();
+        Self::new(Self::min_value())..=Self::new(end)
+    }
+}
+
+/// A duration formatted (via [`Display`](core::fmt::Display)) as `HH:MM:SS.mmm`
+///
+/// See [`Duration::hhmmss()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Hhmmss {
+    milliseconds: u128,
+}
+
+impl core::fmt::Display for Hhmmss {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let seconds = self.milliseconds / 1_000;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:03}",
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.milliseconds % 1_000
+        )
+    }
 }
 
 /// The `Generic` `Duration` type allows an arbitrary _scaling factor_ to be used without having to
@@ -433,7 +822,50 @@ pub trait Duration: Sized + Copy {
 ///
 /// The purpose of this type is to allow a simple `Duration` object that can be defined at run-time.
 /// It does this by replacing the `const` _scaling factor_ with a struct field.
+///
+/// # Runtime periods
+///
+/// When the tick period is only known at run-time (eg. a configurable prescaler), the _scaling
+/// factor_ is built from the configuration and the ticks are used as is:
+///
+/// ```rust
+/// # use embedded_time::duration::*;
+/// # use core::convert::TryFrom;
+/// fn elapsed(ticks: u32, prescaler: u32) -> Generic<u32> {
+///     // a 16 MHz timer clock divided by the prescaler
+///     Generic::new(ticks, Fraction::new(prescaler, 16_000_000))
+/// }
+///
+/// let elapsed = elapsed(2_500, 64);
+/// assert_eq!(Microseconds::<u32>::try_from(elapsed), Ok(Microseconds(10_000_u32)));
+/// assert_eq!(
+///     elapsed.checked_add(&Milliseconds(10_u32).into()),
+///     Some(Generic::new(5_000_u32, Fraction::new(1, 250_000)))
+/// );
+/// ```
+///
+/// # Fractional durations
+///
+/// As the _scaling factor_ can be any [`Fraction`], a `Generic` can hold values that fall between
+/// the integer counts of the named units (eg. 1.5 ms as 3 × 1/2000 s). They are only truncated by
+/// the final conversion.
+///
+/// ```rust
+/// # use embedded_time::duration::*;
+/// # use core::convert::TryFrom;
+/// let one_and_a_half_ms = Generic::new(3_u32, Fraction::new(1, 2_000));
+///
+/// assert_eq!(Microseconds::<u32>::try_from(one_and_a_half_ms), Ok(Microseconds(1_500_u32)));
+/// assert_eq!(
+///     one_and_a_half_ms.to_generic::<u32>(Fraction::new(1, 32_768)),
+///     Ok(Generic::new(49_u32, Fraction::new(1, 32_768)))
+/// );
+///
+/// // whereas `Milliseconds` truncates
+/// assert_eq!(Milliseconds::<u32>::try_from(one_and_a_half_ms), Ok(Milliseconds(1_u32)));
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Generic<T> {
     integer: T,
     scaling_factor: Fraction,
@@ -459,15 +891,139 @@ impl<T> Generic<T> {
     }
 }
 
+impl<T: TimeInt> Generic<T> {
+    /// Returns `true` if the duration is `0`
+    pub fn is_zero(&self) -> bool {
+        self.integer == T::from(0)
+    }
+
+    /// Convert to a `Generic` `Duration` with the given _scaling factor_ (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Unspecified`]: The result doesn't fit in the wider of the two integer
+    ///   types (or a _scaling factor_ is `0`)
+    /// - [`ConversionError::ConversionFailure`]: The result doesn't fit in `DestInt`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// assert_eq!(
+    ///     Generic::new(3_u32, Fraction::new(1, 2_000)).to_generic::<u64>(Fraction::new(1, 1_000_000)),
+    ///     Ok(Generic::new(1_500_u64, Fraction::new(1, 1_000_000)))
+    /// );
+    /// ```
+    pub fn to_generic<DestInt: TimeInt>(
+        self,
+        scaling_factor: Fraction,
+    ) -> Result<Generic<DestInt>, ConversionError> {
+        Ok(Generic::new(
+            fixed_point::scale(self.integer, self.scaling_factor, scaling_factor)?,
+            scaling_factor,
+        ))
+    }
+
+    /// Add with overflow checking
+    ///
+    /// The RHS is converted to the _scaling factor_ of the LHS (truncated). Returns [`None`] if it
+    /// can't be converted or the sum would overflow.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// let generic = Generic::new(3_u32, Fraction::new(1, 2_000));
+    ///
+    /// assert_eq!(
+    ///     generic.checked_add(&Milliseconds(1_u32).into()),
+    ///     Some(Generic::new(5_u32, Fraction::new(1, 2_000)))
+    /// );
+    /// assert_eq!(generic.checked_add(&Generic::new(u32::MAX, Fraction::new(1, 2_000))), None);
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let rhs = rhs.to_generic::<T>(self.scaling_factor).ok()?;
+        Some(Self::new(
+            self.integer.checked_add(&rhs.integer)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Subtract with overflow checking
+    ///
+    /// The RHS is converted to the _scaling factor_ of the LHS (truncated). Returns [`None`] if it
+    /// can't be converted or the difference would be negative.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// let generic = Generic::new(3_u32, Fraction::new(1, 2_000));
+    ///
+    /// assert_eq!(
+    ///     generic.checked_sub(&Milliseconds(1_u32).into()),
+    ///     Some(Generic::new(1_u32, Fraction::new(1, 2_000)))
+    /// );
+    /// assert_eq!(generic.checked_sub(&Milliseconds(2_u32).into()), None);
+    /// ```
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let rhs = rhs.to_generic::<T>(self.scaling_factor).ok()?;
+        Some(Self::new(
+            self.integer.checked_sub(&rhs.integer)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Multiply by an integer with overflow checking
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// assert_eq!(
+    ///     Generic::new(3_u32, Fraction::new(1, 2_000)).checked_mul(&4),
+    ///     Some(Generic::new(12_u32, Fraction::new(1, 2_000)))
+    /// );
+    /// assert_eq!(Generic::new(u32::MAX, Fraction::new(1, 2_000)).checked_mul(&2), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: &T) -> Option<Self> {
+        Some(Self::new(
+            self.integer.checked_mul(rhs)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Divide by an integer with checking (truncated)
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// assert_eq!(
+    ///     Generic::new(12_u32, Fraction::new(1, 2_000)).checked_div(&5),
+    ///     Some(Generic::new(2_u32, Fraction::new(1, 2_000)))
+    /// );
+    /// assert_eq!(Generic::new(12_u32, Fraction::new(1, 2_000)).checked_div(&0), None);
+    /// ```
+    pub fn checked_div(&self, rhs: &T) -> Option<Self> {
+        Some(Self::new(
+            self.integer.checked_div(rhs)?,
+            self.scaling_factor,
+        ))
+    }
+}
+
 impl<T: TimeInt> Duration for Generic<T> {}
 
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} × {} s", self.integer, self.scaling_factor)
+    }
+}
+
+mod ticks;
+pub use ticks::Ticks;
+
 /// Duration units
 #[doc(hidden)]
 pub mod units {
     use super::*;
     use crate::{
         fixed_point::{self, FixedPoint},
-        fraction::Fraction,
+        fraction::{Fraction, Rounding},
         time_int::TimeInt,
         ConversionError,
     };
@@ -481,16 +1037,191 @@ pub mod units {
     pub use Extensions as _;
 
     macro_rules! impl_duration {
-        ( $name:ident, ($numer:expr, $denom:expr) ) => {
+        ( @int $name:ident, ($numer:expr, $denom:expr), $int:ident ) => {
+            impl $name<$int> {
+                #[doc = concat!("The largest `", stringify!($int), "`-based value")]
+                pub const MAX: Self = Self($int::MAX);
+                #[doc = concat!("The smallest `", stringify!($int), "`-based value")]
+                pub const MIN: Self = Self(0);
+
+                /// Returns the duration in ticks of the given _scaling factor_ (truncated) or
+                /// [`None`] if it doesn't fit
+                ///
+                /// See [`const` conversions](trait.Duration.html#const-conversions)
+                pub const fn to_ticks(self, scaling_factor: Fraction) -> Option<$int> {
+                    let ticks = fixed_point::scale_u128(
+                        self.0 as u128,
+                        Fraction::new($numer, $denom),
+                        scaling_factor,
+                    );
+                    match ticks {
+                        Option::Some(ticks) if ticks <= $int::MAX as u128 => {
+                            Option::Some(ticks as $int)
+                        }
+                        _ => Option::None,
+                    }
+                }
+            }
+
+            impl From<$name<$int>> for $int {
+                /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+                fn from(duration: $name<$int>) -> Self {
+                    duration.0
+                }
+            }
+        };
+        ( $name:ident, ($numer:expr, $denom:expr), $symbol:literal ) => {
             /// A duration unit type
             #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+            #[cfg_attr(
+                feature = "serde",
+                derive(serde::Serialize, serde::Deserialize),
+                serde(transparent)
+            )]
             pub struct $name<T: TimeInt = u32>(pub T);
 
             impl<T: TimeInt> $name<T> {
                 /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
-                pub fn new(value: T) -> Self {
+                pub const fn new(value: T) -> Self {
                     Self(value)
                 }
+
+                /// Returns a duration of `0` (the [`Default`])
+                ///
+                /// See [Zero](trait.Duration.html#zero)
+                pub fn zero() -> Self {
+                    Self(T::from(0))
+                }
+
+                /// Returns `true` if the duration is `0`
+                ///
+                /// See [Zero](trait.Duration.html#zero)
+                pub fn is_zero(&self) -> bool {
+                    self.0 == T::from(0)
+                }
+
+                /// Returns the smaller of the two durations, in this unit (truncated)
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn min<Rhs>(self, other: Rhs) -> Self
+                where
+                    Rhs: Duration + FixedPoint,
+                    Self: TryFrom<Rhs> + PartialOrd<Rhs>,
+                {
+                    if self <= other {
+                        self
+                    } else {
+                        // smaller than `self`, so it fits
+                        Self::try_from(other).ok().unwrap()
+                    }
+                }
+
+                /// Returns the larger of the two durations, in this unit (truncated, saturating
+                /// at the maximum value)
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn max<Rhs>(self, other: Rhs) -> Self
+                where
+                    Rhs: Duration + FixedPoint,
+                    Self: TryFrom<Rhs> + PartialOrd<Rhs>,
+                {
+                    if self >= other {
+                        self
+                    } else {
+                        Self::try_from(other)
+                            .unwrap_or_else(|_| Self(<Self as FixedPoint>::max_value()))
+                    }
+                }
+
+                /// Restricts the duration to `lo..=hi`, in this unit (truncated, saturating at
+                /// the maximum value)
+                ///
+                /// If `lo` is greater than `hi`, `hi` wins.
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn clamp<Lo, Hi>(self, lo: Lo, hi: Hi) -> Self
+                where
+                    Lo: Duration + FixedPoint,
+                    Hi: Duration + FixedPoint,
+                    Self: TryFrom<Lo> + PartialOrd<Lo> + TryFrom<Hi> + PartialOrd<Hi>,
+                {
+                    self.max(lo).min(hi)
+                }
+
+                /// Multiply by a [`Fraction`], rounding as given
+                ///
+                /// Returns [`None`] if the result doesn't fit or the denominator is `0`.
+                ///
+                /// See [Scaling by a `Fraction`](trait.Duration.html#scaling-by-a-fraction)
+                pub fn checked_mul_fraction(
+                    &self,
+                    fraction: &Fraction,
+                    rounding: Rounding,
+                ) -> Option<Self> {
+                    self.0
+                        .into_u128()
+                        .checked_mul(u128::from(*fraction.numerator()))
+                        .and_then(|product| {
+                            rounding.divide(product, u128::from(*fraction.denominator()))
+                        })
+                        .and_then(T::try_from_u128)
+                        .map(Self)
+                }
+
+                /// Convert from a duration of any unit, rounding as given
+                ///
+                /// # Errors
+                ///
+                /// - [`ConversionError::ConversionFailure`]: The result doesn't fit in this type
+                /// - [`ConversionError::Unspecified`]: The result doesn't fit in the wider of the
+                ///   two integer types
+                ///
+                /// See [Converting with rounding](trait.Duration.html#converting-with-rounding)
+                pub fn try_from_rounded<Source>(
+                    duration: Source,
+                    rounding: Rounding,
+                ) -> Result<Self, ConversionError>
+                where
+                    Source: Duration + FixedPoint,
+                {
+                    fixed_point::convert_rounded(duration, rounding)
+                }
+
+                /// Convert from a duration of any unit, failing rather than discarding a remainder
+                ///
+                /// # Errors
+                ///
+                /// - [`ConversionError::LossOfPrecision`]: The duration isn't a whole number of
+                ///   this unit
+                /// - See [`try_from_rounded()`](Self::try_from_rounded)
+                ///
+                /// See [Converting with rounding](trait.Duration.html#converting-with-rounding)
+                pub fn try_from_exact<Source>(duration: Source) -> Result<Self, ConversionError>
+                where
+                    Source: Duration + FixedPoint,
+                {
+                    fixed_point::convert_exact(duration)
+                }
+            }
+
+            impl_duration![@int $name, ($numer, $denom), u8];
+            impl_duration![@int $name, ($numer, $denom), u16];
+            impl_duration![@int $name, ($numer, $denom), u32];
+            impl_duration![@int $name, ($numer, $denom), u64];
+
+            impl $name<u128> {
+                /// The largest `u128`-based value
+                pub const MAX: Self = Self(u128::MAX);
+                /// The smallest `u128`-based value
+                pub const MIN: Self = Self(0);
+
+                /// Returns the duration in ticks of the given _scaling factor_ (truncated) or
+                /// [`None`] if it doesn't fit
+                ///
+                /// See [`const` conversions](trait.Duration.html#const-conversions)
+                pub const fn to_ticks(self, scaling_factor: Fraction) -> Option<u128> {
+                    fixed_point::scale_u128(self.0, Fraction::new($numer, $denom), scaling_factor)
+                }
             }
 
             impl<T: TimeInt> Duration for $name<T> {}
@@ -513,7 +1244,24 @@ pub mod units {
             impl<T: TimeInt> fmt::Display for $name<T> {
                 /// See [Formatting](trait.Duration.html#formatting)
                 fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                    fmt::Display::fmt(&self.0, f)
+                    if f.alternate() {
+                        // (plain formatting if a `u128` duration doesn't fit in nanoseconds)
+                        if let Option::Some(nanoseconds) =
+                            self.0.into_u128().checked_mul($numer * 1_000_000_000)
+                        {
+                            return fmt_mixed(nanoseconds / $denom, $symbol, f);
+                        }
+                    }
+
+                    fmt::Display::fmt(&self.0, f)?;
+                    write!(f, " {}", $symbol)
+                }
+            }
+
+            #[cfg(feature = "defmt")]
+            impl<T: TimeInt + defmt::Format> defmt::Format for $name<T> {
+                fn format(&self, f: defmt::Formatter<'_>) {
+                    defmt::write!(f, "{} {=str}", self.0, $symbol)
                 }
             }
 
@@ -561,6 +1309,15 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt> ops::Mul<Fraction> for $name<T> {
+                type Output = Self;
+
+                /// See [Scaling by a `Fraction`](trait.Duration.html#scaling-by-a-fraction)
+                fn mul(self, rhs: Fraction) -> Self::Output {
+                    Self(self.0 * rhs)
+                }
+            }
+
             impl<T: TimeInt, Rhs: Duration> ops::Rem<Rhs> for $name<T>
             where
                 Self: TryFrom<Rhs>,
@@ -574,6 +1331,53 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt, Rhs: Duration> ops::AddAssign<Rhs> for $name<T>
+            where
+                Rhs: FixedPoint,
+                Self: TryFrom<Rhs>,
+            {
+                /// See [Compound assignment](trait.Duration.html#compound-assignment)
+                fn add_assign(&mut self, rhs: Rhs) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<T: TimeInt, Rhs: Duration> ops::SubAssign<Rhs> for $name<T>
+            where
+                Rhs: FixedPoint,
+                Self: TryFrom<Rhs>,
+            {
+                /// See [Compound assignment](trait.Duration.html#compound-assignment)
+                fn sub_assign(&mut self, rhs: Rhs) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<T: TimeInt> ops::MulAssign<T> for $name<T> {
+                /// See [Compound assignment](trait.Duration.html#compound-assignment)
+                fn mul_assign(&mut self, rhs: T) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<T: TimeInt> ops::DivAssign<T> for $name<T> {
+                /// See [Compound assignment](trait.Duration.html#compound-assignment)
+                fn div_assign(&mut self, rhs: T) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl<T: TimeInt, Rhs: Duration> ops::RemAssign<Rhs> for $name<T>
+            where
+                Rhs: FixedPoint,
+                Self: TryFrom<Rhs>,
+            {
+                /// See [Compound assignment](trait.Duration.html#compound-assignment)
+                fn rem_assign(&mut self, rhs: Rhs) {
+                    *self = *self % rhs;
+                }
+            }
+
             impl<SourceInt: TimeInt, DestInt: TimeInt> TryFrom<Generic<SourceInt>>
                 for $name<DestInt>
             where
@@ -598,13 +1402,26 @@ pub mod units {
                     Self::new(*duration.integer(), $name::<T>::SCALING_FACTOR)
                 }
             }
+
+            impl<T: TimeInt> From<T> for $name<T> {
+                /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+                fn from(integer: T) -> Self {
+                    Self(integer)
+                }
+            }
+
+            impl From<$name<u128>> for u128 {
+                /// See [Get the integer part](trait.Duration.html#get-the-integer-part)
+                fn from(duration: $name<u128>) -> Self {
+                    duration.0
+                }
+            }
         };
 
-        ( $name:ident, ($numer:expr, $denom:expr), ge_secs ) => {
-            impl_duration![$name, ($numer, $denom)];
+        ( $name:ident, ($numer:expr, $denom:expr), $symbol:literal, ge_secs ) => {
+            impl_duration![$name, ($numer, $denom), $symbol];
 
-            // TODO: Make this more custom (seconds and higher<u32> can be `From`) and comprehensive
-            // (allow u64 durations)
+            // TODO: Make this more custom (seconds and higher<u32> can be `From`)
             impl TryFrom<$name<u32>> for core::time::Duration {
                 type Error = ConversionError;
 
@@ -616,6 +1433,17 @@ pub mod units {
                 }
             }
 
+            impl TryFrom<$name<u64>> for core::time::Duration {
+                type Error = ConversionError;
+
+                /// See [Converting to `core`
+                /// types](trait.Duration.html#converting-to-core-types)
+                fn try_from(duration: $name<u64>) -> Result<Self, Self::Error> {
+                    let seconds: Seconds<u64> = fixed_point::convert(duration)?;
+                    Ok(Self::from_secs(*seconds.integer()))
+                }
+            }
+
             impl TryFrom<core::time::Duration> for $name<u32> {
                 type Error = ConversionError;
 
@@ -636,8 +1464,14 @@ pub mod units {
                 }
             }
         };
-        ( $name:ident, ($numer:expr, $denom:expr), $from_core_dur:ident, $as_core_dur:ident ) => {
-            impl_duration![$name, ($numer, $denom)];
+        (
+            $name:ident,
+            ($numer:expr, $denom:expr),
+            $symbol:literal,
+            $from_core_dur:ident,
+            $as_core_dur:ident
+        ) => {
+            impl_duration![$name, ($numer, $denom), $symbol];
 
             impl<T: TimeInt> TryFrom<$name<T>> for core::time::Duration
             where
@@ -670,12 +1504,69 @@ pub mod units {
             }
         };
     }
-    impl_duration![Hours, (3600, 1), ge_secs];
-    impl_duration![Minutes, (60, 1), ge_secs];
-    impl_duration![Seconds, (1, 1), ge_secs];
-    impl_duration![Milliseconds, (1, 1_000), from_millis, as_millis];
-    impl_duration![Microseconds, (1, 1_000_000), from_micros, as_micros];
-    impl_duration![Nanoseconds, (1, 1_000_000_000), from_nanos, as_nanos];
+    impl_duration![Hours, (3600, 1), "h", ge_secs];
+    impl_duration![Minutes, (60, 1), "min", ge_secs];
+    impl_duration![Seconds, (1, 1), "s", ge_secs];
+    impl_duration![Milliseconds, (1, 1_000), "ms", from_millis, as_millis];
+    impl_duration![Microseconds, (1, 1_000_000), "µs", from_micros, as_micros];
+    impl_duration![Nanoseconds, (1, 1_000_000_000), "ns", from_nanos, as_nanos];
+
+    /// Write the non-zero components of a duration from hours down to nanoseconds (eg. "1 min 30
+    /// s"), or `0` of the given unit
+    fn fmt_mixed(nanoseconds: u128, symbol: &str, f: &mut Formatter<'_>) -> fmt::Result {
+        const COMPONENTS: [(u128, &str); 6] = [
+            (3_600_000_000_000, "h"),
+            (60_000_000_000, "min"),
+            (1_000_000_000, "s"),
+            (1_000_000, "ms"),
+            (1_000, "µs"),
+            (1, "ns"),
+        ];
+
+        if nanoseconds == 0 {
+            return write!(f, "0 {}", symbol);
+        }
+
+        let mut remainder = nanoseconds;
+        let mut separator = "";
+        for &(size, symbol) in COMPONENTS.iter() {
+            let count = remainder / size;
+            remainder %= size;
+            if count != 0 {
+                write!(f, "{}{} {}", separator, count, symbol)?;
+                separator = " ";
+            }
+        }
+
+        Ok(())
+    }
+
+    macro_rules! impl_aliases {
+        ( $name:ident, $name32:ident, $name64:ident, $name128:ident ) => {
+            /// `u32`-based duration
+            pub type $name32 = $name<u32>;
+            /// `u64`-based duration
+            pub type $name64 = $name<u64>;
+            /// `u128`-based duration
+            pub type $name128 = $name<u128>;
+        };
+    }
+    impl_aliases![Hours, Hours32, Hours64, Hours128];
+    impl_aliases![Minutes, Minutes32, Minutes64, Minutes128];
+    impl_aliases![Seconds, Seconds32, Seconds64, Seconds128];
+    impl_aliases![
+        Milliseconds,
+        Milliseconds32,
+        Milliseconds64,
+        Milliseconds128
+    ];
+    impl_aliases![
+        Microseconds,
+        Microseconds32,
+        Microseconds64,
+        Microseconds128
+    ];
+    impl_aliases![Nanoseconds, Nanoseconds32, Nanoseconds64, Nanoseconds128];
 
     macro_rules! impl_partial_eq {
         ($name:ident) => {
@@ -861,10 +1752,36 @@ pub mod units {
                 /// See [Converting between
                 /// `Duration`s](trait.Duration.html#converting-between-durations)
                 fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        *source.integer(),
-                        $name::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::convert(source)
+                }
+            }
+
+            impl_from![$name, u8, u16];
+            impl_from![$name, u8, u32];
+            impl_from![$name, u8, u64];
+            impl_from![$name, u8, u128];
+            impl_from![$name, u16, u32];
+            impl_from![$name, u16, u64];
+            impl_from![$name, u16, u128];
+            impl_from![$name, u32, u128];
+            impl_from![$name, u64, u128];
+        };
+        ($name:ident, $small:ty, $big:ty) => {
+            impl From<$name<$small>> for $name<$big> {
+                /// See [Converting between
+                /// `Duration`s](trait.Duration.html#converting-between-durations)
+                fn from(source: $name<$small>) -> Self {
+                    Self::new(<$big>::from(*source.integer()))
+                }
+            }
+
+            impl TryFrom<$name<$big>> for $name<$small> {
+                type Error = ConversionError;
+
+                /// See [Converting between
+                /// `Duration`s](trait.Duration.html#converting-between-durations)
+                fn try_from(source: $name<$big>) -> Result<Self, Self::Error> {
+                    fixed_point::convert(source)
                 }
             }
         };
@@ -884,7 +1801,7 @@ pub mod units {
                 {
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn from(small: $small<T>) -> Self {
-                        fixed_point::FixedPoint::from_ticks(*small.integer(), $small::<T>::SCALING_FACTOR).ok().unwrap()
+                        fixed_point::convert(small).ok().unwrap()
                     }
                 }
 
@@ -892,7 +1809,7 @@ pub mod units {
                 {
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn from(small: $small<u32>) -> Self {
-                        fixed_point::FixedPoint::from_ticks(*small.integer(), $small::<u32>::SCALING_FACTOR).ok().unwrap()
+                        fixed_point::convert(small).ok().unwrap()
                     }
                 }
 
@@ -902,10 +1819,7 @@ pub mod units {
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(small: $small<u64>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            *small.integer(),
-                            $small::<u64>::SCALING_FACTOR,
-                        )
+                        fixed_point::convert(small)
                     }
                 }
             )+
@@ -931,7 +1845,7 @@ pub mod units {
                 {
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn from(big: $big<u32>) -> Self {
-                        fixed_point::FixedPoint::from_ticks(*big.integer(), $big::<u32>::SCALING_FACTOR).ok().unwrap()
+                        fixed_point::convert(big).ok().unwrap()
                     }
                 }
 
@@ -941,10 +1855,7 @@ pub mod units {
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(big: $big<T>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            *big.integer(),
-                            $big::<T>::SCALING_FACTOR,
-                        )
+                        fixed_point::convert(big)
                     }
                 }
 
@@ -954,10 +1865,7 @@ pub mod units {
 
                     /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
                     fn try_from(big: $big<u64>) -> Result<Self, Self::Error> {
-                        fixed_point::FixedPoint::from_ticks(
-                            *big.integer(),
-                            $big::<u64>::SCALING_FACTOR,
-                        )
+                        fixed_point::convert(big)
                     }
                 }
             )+
@@ -977,6 +1885,8 @@ pub mod units {
 
     /// Create duration-based extensions from primitive numeric types.
     ///
+    /// Also available as `NumericalDuration`.
+    ///
     /// ```rust
     /// use embedded_time::duration::*;
     ///
@@ -1014,9 +1924,15 @@ pub mod units {
         }
     }
 
+    impl Extensions for u8 {}
+
+    impl Extensions for u16 {}
+
     impl Extensions for u32 {}
 
     impl Extensions for u64 {}
+
+    impl Extensions for u128 {}
 }
 
 #[cfg(test)]
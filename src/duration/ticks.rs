@@ -0,0 +1,226 @@
+//! Durations with a const-generic tick period
+
+use super::{Duration, Generic, Hours, Microseconds, Milliseconds, Minutes, Nanoseconds, Seconds};
+use crate::{
+    fixed_point::{self, FixedPoint},
+    fraction::{Fraction, Rounding},
+    time_int::TimeInt,
+    ConversionError,
+};
+use core::{convert::TryFrom, fmt, ops};
+
+/// A duration in ticks of a period (`NUM / DENOM` seconds) fixed at compile time
+///
+/// Counters of any frequency (eg. a 32.768 kHz RTC or a 72 MHz cycle counter) can have their own
+/// duration type without a new named unit. Unlike a [`Generic`] duration, the _scaling factor_ is
+/// part of the type: it takes no space at run-time, and conversions reduce it at compile time.
+///
+/// The named units remain distinct types (rather than aliases of `Ticks`) so they keep their tuple
+/// constructors and unit-specific conversions (eg. `core::time::Duration`).
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{duration::*, fraction::Rounding, ConversionError};
+///
+/// type RtcTicks = Ticks<u32, 1, 32_768>;
+/// type CpuCycles = Ticks<u64, 1, 72_000_000>;
+///
+/// let timeout = RtcTicks::try_from(Milliseconds(1_500_u32)).unwrap();
+/// assert_eq!(timeout, Ticks(49_152));
+/// assert_eq!(Milliseconds::<u32>::try_from(timeout), Ok(Milliseconds(1_500)));
+///
+/// // between tick periods
+/// assert_eq!(CpuCycles::try_from_rounded(timeout, Rounding::Down), Ok(Ticks(108_000_000)));
+/// assert_eq!(RtcTicks::try_from_exact(CpuCycles::new(1)), Err(ConversionError::LossOfPrecision));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Ticks<T: TimeInt, const NUM: u32, const DENOM: u32>(pub T);
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> Ticks<T, NUM, DENOM> {
+    /// Constructs a new `Ticks` `Duration` value
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns `true` if the duration is `0`
+    pub fn is_zero(&self) -> bool {
+        self.0 == T::from(0)
+    }
+
+    /// Convert from a duration of any unit or tick period, rounding as given
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`]: The result doesn't fit in this type
+    /// - [`ConversionError::Unspecified`]: The result doesn't fit in the wider of the two integer
+    ///   types
+    pub fn try_from_rounded<Source>(
+        duration: Source,
+        rounding: Rounding,
+    ) -> Result<Self, ConversionError>
+    where
+        Source: Duration + FixedPoint,
+    {
+        fixed_point::convert_rounded(duration, rounding)
+    }
+
+    /// Convert from a duration of any unit or tick period, failing rather than discarding a
+    /// remainder
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::LossOfPrecision`]: The duration isn't a whole number of ticks
+    /// - See [`try_from_rounded()`](Self::try_from_rounded)
+    pub fn try_from_exact<Source>(duration: Source) -> Result<Self, ConversionError>
+    where
+        Source: Duration + FixedPoint,
+    {
+        fixed_point::convert_exact(duration)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> Duration for Ticks<T, NUM, DENOM> {}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> FixedPoint for Ticks<T, NUM, DENOM> {
+    type T = T;
+    const SCALING_FACTOR: Fraction = Fraction::new(NUM, DENOM);
+
+    fn new(value: Self::T) -> Self {
+        Self(value)
+    }
+
+    fn integer(&self) -> &Self::T {
+        &self.0
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> fmt::Display for Ticks<T, NUM, DENOM> {
+    /// Formats as `ticks × numerator/denominator s`
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// assert_eq!(format!("{}", Ticks::<u32, 1, 32_768>(5)), "5 × 1/32768 s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, " × {}/{} s", NUM, DENOM)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: TimeInt + defmt::Format, const NUM: u32, const DENOM: u32> defmt::Format
+    for Ticks<T, NUM, DENOM>
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} × {}/{} s", self.0, NUM, DENOM)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Add for Ticks<T, NUM, DENOM> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// The sum overflows
+    fn add(self, rhs: Self) -> Self::Output {
+        <Self as FixedPoint>::add(self, rhs)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Sub for Ticks<T, NUM, DENOM> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// The difference is negative
+    fn sub(self, rhs: Self) -> Self::Output {
+        <Self as FixedPoint>::sub(self, rhs)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Mul<T> for Ticks<T, NUM, DENOM> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// The product overflows
+    fn mul(self, rhs: T) -> Self::Output {
+        <Self as FixedPoint>::mul(self, rhs)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> ops::Div<T> for Ticks<T, NUM, DENOM> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// The divisor is `0`
+    fn div(self, rhs: T) -> Self::Output {
+        <Self as FixedPoint>::div(self, rhs)
+    }
+}
+
+impl<SourceInt: TimeInt, DestInt: TimeInt, const NUM: u32, const DENOM: u32>
+    TryFrom<Generic<SourceInt>> for Ticks<DestInt, NUM, DENOM>
+where
+    DestInt: TryFrom<SourceInt>,
+{
+    type Error = ConversionError;
+
+    /// See [Converting from a `Generic`
+    /// `Duration`](trait.Duration.html#converting-from-a-generic-duration)
+    fn try_from(generic_duration: Generic<SourceInt>) -> Result<Self, Self::Error> {
+        FixedPoint::from_ticks(generic_duration.integer, generic_duration.scaling_factor)
+    }
+}
+
+impl<T: TimeInt, const NUM: u32, const DENOM: u32> From<Ticks<T, NUM, DENOM>> for Generic<T> {
+    /// See [Converting to a `Generic`
+    /// `Duration`](trait.Duration.html#converting-to-a-generic-duration)
+    fn from(duration: Ticks<T, NUM, DENOM>) -> Self {
+        Self::new(duration.0, Fraction::new(NUM, DENOM))
+    }
+}
+
+macro_rules! impl_ticks {
+    ( $($name:ident),+ ) => {
+        $(
+            impl<T: TimeInt, const NUM: u32, const DENOM: u32> TryFrom<$name<T>>
+                for Ticks<T, NUM, DENOM>
+            {
+                type Error = ConversionError;
+
+                /// Convert a named duration to ticks (truncated)
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    fixed_point::convert(duration)
+                }
+            }
+
+            impl<T: TimeInt, const NUM: u32, const DENOM: u32> TryFrom<Ticks<T, NUM, DENOM>>
+                for $name<T>
+            {
+                type Error = ConversionError;
+
+                /// Convert ticks to a named duration (truncated)
+                fn try_from(ticks: Ticks<T, NUM, DENOM>) -> Result<Self, Self::Error> {
+                    fixed_point::convert(ticks)
+                }
+            }
+        )+
+    };
+}
+impl_ticks![
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
@@ -0,0 +1,206 @@
+//! PWM duty cycles
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, fraction::Fraction, rate::Rate, time_int::TimeInt,
+    ConversionError,
+};
+use num::integer::gcd;
+
+/// The fraction of a period a signal is on (eg. a PWM output), from `0` to `1`
+///
+/// Stored as an exact (reduced) ratio, so converting between on-times, periods, and compare
+/// values truncates only once.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, duty::DutyCycle, rate::*};
+///
+/// let duty = DutyCycle::from_percent(25).unwrap();
+///
+/// assert_eq!(duty.on_time(Milliseconds(20_u32)), Ok(Milliseconds(5_u32)));
+/// assert_eq!(duty.off_time(Milliseconds(20_u32)), Ok(Milliseconds(15_u32)));
+/// assert_eq!(duty.on_time_at(Kilohertz(1_u32)), Ok(Microseconds(250_u32)));
+/// assert_eq!(duty.compare_value(1_000), 250);
+///
+/// assert_eq!(
+///     DutyCycle::from_durations(Microseconds(1_500_u32), Milliseconds(20_u32)),
+///     Ok(DutyCycle::new(3, 40).unwrap())
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DutyCycle(Fraction);
+
+impl DutyCycle {
+    /// Always off
+    pub const OFF: Self = Self(Fraction::new(0, 1));
+
+    /// Always on
+    pub const FULL: Self = Self(Fraction::new(1, 1));
+
+    /// Construct from the on-part and the whole of a period
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The denominator is `0`
+    /// - [`ConversionError::Overflow`]: The duty cycle is greater than `1`
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self, ConversionError> {
+        Self::reduce(u128::from(numerator), u128::from(denominator))
+    }
+
+    /// Construct from a percentage
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The percentage is greater than `100`
+    pub fn from_percent(percent: u32) -> Result<Self, ConversionError> {
+        Self::new(percent, 100)
+    }
+
+    /// Construct from an on-time and the period (of any units)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The period is `0`
+    /// - [`ConversionError::Overflow`]: The on-time is longer than the period, the (reduced)
+    ///   ratio doesn't fit in a [`Fraction`], or an intermediate product doesn't fit in a `u128`
+    pub fn from_durations<On, Period>(on_time: On, period: Period) -> Result<Self, ConversionError>
+    where
+        On: Duration + FixedPoint,
+        Period: Duration + FixedPoint,
+    {
+        // (on-time × on-time scaling factor) / (period × period scaling factor)
+        let scaled = |integer: u128, numerator: u32, denominator: u32| {
+            integer
+                .checked_mul(u128::from(numerator))
+                .and_then(|product| product.checked_mul(u128::from(denominator)))
+                .ok_or(ConversionError::Overflow)
+        };
+
+        Self::reduce(
+            scaled(
+                on_time.integer().into_u128(),
+                *On::SCALING_FACTOR.numerator(),
+                *Period::SCALING_FACTOR.denominator(),
+            )?,
+            scaled(
+                period.integer().into_u128(),
+                *Period::SCALING_FACTOR.numerator(),
+                *On::SCALING_FACTOR.denominator(),
+            )?,
+        )
+    }
+
+    /// Returns the duty cycle as a reduced ratio
+    pub fn ratio(&self) -> Fraction {
+        self.0
+    }
+
+    /// Returns the duty cycle as a percentage (truncated)
+    pub fn percent(&self) -> u32 {
+        self.compare_value(100)
+    }
+
+    /// Returns the on-time within a period (truncated)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The intermediate product doesn't fit in a `u128`
+    pub fn on_time<Dur>(&self, period: Dur) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        period
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*self.0.numerator()))
+            .map(|integer| integer / u128::from(*self.0.denominator()))
+            .and_then(Dur::T::try_from_u128)
+            .map(Dur::new)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the off-time within a period (the remainder of [`DutyCycle::on_time()`])
+    ///
+    /// # Errors
+    ///
+    /// See [`DutyCycle::on_time()`]
+    pub fn off_time<Dur>(&self, period: Dur) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let on_time = self.on_time(period)?;
+        Ok(Dur::new(*period.integer() - *on_time.integer()))
+    }
+
+    /// Returns the on-time within a period of the given frequency (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The frequency or a _scaling factor_ is `0`
+    /// - [`ConversionError::Overflow`]: An intermediate product doesn't fit in a `u128`
+    /// - [`ConversionError::ConversionFailure`]: The on-time doesn't fit in the destination type
+    pub fn on_time_at<Dur, R>(&self, frequency: R) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        R: Rate + FixedPoint,
+    {
+        // duty / (frequency × rate scaling factor × duration scaling factor)
+        let denominator = frequency
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*self.0.denominator()))
+            .and_then(|product| product.checked_mul(u128::from(*R::SCALING_FACTOR.numerator())))
+            .and_then(|product| product.checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator())))
+            .ok_or(ConversionError::Overflow)?;
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        // at most (2³² - 1)³
+        let integer = u128::from(*self.0.numerator())
+            * u128::from(*R::SCALING_FACTOR.denominator())
+            * u128::from(*Dur::SCALING_FACTOR.denominator())
+            / denominator;
+
+        Dur::T::try_from_u128(integer)
+            .map(Dur::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    /// Returns the compare (match) value for a PWM counter whose full duty cycle is `max_duty`
+    /// (truncated)
+    pub fn compare_value(&self, max_duty: u32) -> u32 {
+        // never greater than `max_duty`
+        (u64::from(max_duty) * u64::from(*self.0.numerator()) / u64::from(*self.0.denominator()))
+            as u32
+    }
+
+    pub(crate) fn reduce(numerator: u128, denominator: u128) -> Result<Self, ConversionError> {
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        if numerator > denominator {
+            return Err(ConversionError::Overflow);
+        }
+        if numerator == 0 {
+            return Ok(Self::OFF);
+        }
+        let divisor = gcd(numerator, denominator);
+
+        match (
+            u32::try_from_u128(numerator / divisor),
+            u32::try_from_u128(denominator / divisor),
+        ) {
+            (Option::Some(numerator), Option::Some(denominator)) => {
+                Ok(Self(Fraction::new(numerator, denominator)))
+            }
+            _ => Err(ConversionError::Overflow),
+        }
+    }
+}
+
+impl Default for DutyCycle {
+    fn default() -> Self {
+        Self::OFF
+    }
+}
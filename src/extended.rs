@@ -0,0 +1,130 @@
+//! Extension of narrow hardware counters to 64 bits
+//!
+//! An [`ExtendedClock`] wraps a clock with a narrow counter (eg. a 16-bit timer or 24-bit SysTick)
+//! and counts its rollovers to present a 64-bit [`Instant`] that doesn't wrap within any practical
+//! lifetime. Rollovers are tracked either by polling or from the counter's overflow interrupt.
+
+use crate::{clock, Instant};
+use core::cell::Cell;
+
+/// A [`Clock`](crate::Clock) extending a narrow counter to 64 bits by counting its rollovers
+///
+/// Rollovers are tracked in one of two ways:
+///
+/// - Polling: every reading advances the extended count by the ticks elapsed since the previous
+///   reading, so the clock must be read at least once per [wrap
+///   horizon](crate::Clock::wrap_horizon) of the wrapped counter.
+/// - Interrupt: [`on_rollover()`](ExtendedClock::on_rollover) is called from the counter's
+///   overflow interrupt. Readings taken while the interrupt is pending are still correct, and the
+///   clock may be read arbitrarily rarely.
+///
+/// The first reading is that of the wrapped counter.
+///
+/// To share the clock with an interrupt handler, it must be wrapped in a critical-section-based
+/// mutex (eg. [`SharedClock`](crate::shared::SharedClock), `critical-section` feature).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, extended::*, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Timer(Cell<u32>);
+/// impl embedded_time::Clock for Timer {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     const COUNTER_BITS: u32 = 16;
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// let clock = ExtendedClock::new(Timer(Cell::new(0xFFF0)));
+/// assert_eq!(clock.try_now(), Ok(Instant::new(0xFFF0_u64)));
+///
+/// // the counter wraps
+/// clock.counter().0.set(0x10);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(0x1_0010_u64)));
+/// ```
+#[derive(Debug)]
+pub struct ExtendedClock<Counter: crate::Clock> {
+    counter: Counter,
+    /// The extended and the counter ticks of the last reading
+    last: Cell<Option<(u64, Counter::T)>>,
+    /// A reading has seen a rollover since the last `on_rollover()` call
+    rollover_seen: Cell<bool>,
+}
+
+impl<Counter: crate::Clock> ExtendedClock<Counter> {
+    /// Construct from the narrow clock
+    pub const fn new(counter: Counter) -> Self {
+        Self {
+            counter,
+            last: Cell::new(Option::None),
+            rollover_seen: Cell::new(false),
+        }
+    }
+
+    /// Returns a reference to the wrapped clock
+    pub fn counter(&self) -> &Counter {
+        &self.counter
+    }
+}
+
+impl<Counter: crate::Clock> ExtendedClock<Counter>
+where
+    u64: From<Counter::T>,
+{
+    /// Record a rollover of the counter
+    ///
+    /// To be called from the counter's overflow interrupt, once per rollover. Rollovers already
+    /// seen by a reading (eg. one taken while the interrupt was pending) are not counted twice.
+    ///
+    /// # Errors
+    ///
+    /// [`clock::Error`]: The counter could not be read (the rollover is not recorded)
+    pub fn on_rollover(&self) -> Result<(), clock::Error> {
+        let first = self.last.get().is_none();
+        let reading = self.read()?;
+
+        if !self.rollover_seen.replace(false) && !first {
+            // the counter advanced by a whole period (or more) since the last reading
+            let period = (1_u128 << Counter::COUNTER_BITS) as u64;
+            self.last
+                .set(Option::Some((reading.0.wrapping_add(period), reading.1)));
+        }
+
+        Ok(())
+    }
+
+    /// Read the counter and advance the extended reading by the ticks elapsed since the last one
+    fn read(&self) -> Result<(u64, Counter::T), clock::Error> {
+        let now = self.counter.try_now()?;
+        let ticks = *now.duration_since_epoch().integer();
+
+        let reading = match self.last.get() {
+            Option::Some((extended, last)) => {
+                if ticks < last {
+                    self.rollover_seen.set(true);
+                }
+                let elapsed = now.wrapping_ticks_since(&Instant::new(last));
+                (extended.wrapping_add(u64::from(elapsed)), ticks)
+            }
+            Option::None => (u64::from(ticks), ticks),
+        };
+        self.last.set(Option::Some(reading));
+
+        Ok(reading)
+    }
+}
+
+impl<Counter: crate::Clock> crate::Clock for ExtendedClock<Counter>
+where
+    u64: From<Counter::T>,
+{
+    type T = u64;
+    const SCALING_FACTOR: crate::fraction::Fraction = Counter::SCALING_FACTOR;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(self.read()?.0))
+    }
+}
@@ -0,0 +1,244 @@
+//! Primary/backup clock-source failover
+//!
+//! A [`FailoverClock`] serves time from a primary clock and switches to a backup clock when the
+//! primary reports an error or stops advancing. Readings advance by the ticks elapsed on the
+//! active source (backup ticks are converted to primary ticks, carrying sub-tick remainders over),
+//! so a switch never makes the clock jump or run backwards. For this to work, the clock must be
+//! read at least once per [wrap horizon](crate::Clock::wrap_horizon) of both wrapped clocks.
+
+use crate::{clock, duration::Duration, fixed_point::FixedPoint, ConversionError, Instant};
+use core::{cell::Cell, convert::TryFrom};
+use num::{Bounded, CheckedAdd};
+
+/// The clock source readings are currently served from
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Source {
+    /// The primary clock
+    Primary,
+    /// The backup clock
+    Backup,
+}
+
+/// A [`Clock`](crate::Clock) serving time from a primary clock, failing over to a backup clock
+///
+/// The clock fails over when the primary clock returns an error or when its reading doesn't
+/// change while the backup clock advances by the _stall timeout_. Once failed over, the backup
+/// remains the source until [`restore_primary()`](FailoverClock::restore_primary) is called.
+///
+/// The backup clock is read along with the primary one to detect stalls and to bridge the time
+/// since the last primary reading. The first reading is that of the primary clock (`0` if it is
+/// unavailable).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, failover::*, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Crystal(Cell<u32>);
+/// impl embedded_time::Clock for Crystal {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// # #[derive(Debug)]
+/// struct Rc(Cell<u32>);
+/// impl embedded_time::Clock for Rc {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// let clock =
+///     FailoverClock::new(Crystal(Cell::new(0)), Rc(Cell::new(0)), Milliseconds(100_u32)).unwrap();
+/// assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+///
+/// // the crystal stops while the backup advances by 100 ms
+/// clock.backup().0.set(100);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(3_276)));
+/// assert_eq!(clock.active_source(), Source::Backup);
+///
+/// clock.backup().0.set(1_100);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(36_044)));
+/// ```
+#[derive(Debug)]
+pub struct FailoverClock<Primary: crate::Clock, Backup: crate::Clock> {
+    primary: Primary,
+    backup: Backup,
+    /// The stall timeout in backup ticks
+    stall_timeout: Backup::T,
+    source: Cell<Source>,
+    last_primary: Cell<Option<Primary::T>>,
+    last_backup: Cell<Option<Backup::T>>,
+    /// Backup ticks elapsed since the primary reading last changed
+    stalled: Cell<Backup::T>,
+    last_reading: Cell<Option<Primary::T>>,
+    /// Sub-tick remainder of converted backup ticks (× backup denominator × primary numerator)
+    residue: Cell<u64>,
+}
+
+impl<Primary: crate::Clock, Backup: crate::Clock> FailoverClock<Primary, Backup> {
+    /// Construct from the two clocks and the stall timeout
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The stall timeout
+    /// could not be converted to backup clock ticks
+    pub fn new<Dur>(
+        primary: Primary,
+        backup: Backup,
+        stall_timeout: Dur,
+    ) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Backup::T: TryFrom<Dur::T>,
+    {
+        let stall_timeout = *stall_timeout.to_generic(Backup::SCALING_FACTOR)?.integer();
+
+        Ok(Self {
+            primary,
+            backup,
+            stall_timeout,
+            source: Cell::new(Source::Primary),
+            last_primary: Cell::new(Option::None),
+            last_backup: Cell::new(Option::None),
+            stalled: Cell::new(Backup::T::from(0)),
+            last_reading: Cell::new(Option::None),
+            residue: Cell::new(0),
+        })
+    }
+
+    /// Returns the source readings are currently served from
+    pub fn active_source(&self) -> Source {
+        self.source.get()
+    }
+
+    /// Switch back to the primary clock (eg. after its failure has been cleared)
+    ///
+    /// The time until the next primary reading is bridged by the backup clock.
+    pub fn restore_primary(&self) {
+        self.source.set(Source::Primary);
+        self.last_primary.set(Option::None);
+        self.stalled.set(Backup::T::from(0));
+    }
+
+    /// Returns a reference to the primary clock
+    pub fn primary(&self) -> &Primary {
+        &self.primary
+    }
+
+    /// Returns a reference to the backup clock
+    pub fn backup(&self) -> &Backup {
+        &self.backup
+    }
+}
+
+impl<Primary: crate::Clock, Backup: crate::Clock> FailoverClock<Primary, Backup>
+where
+    u64: From<Backup::T>,
+    Primary::T: TryFrom<u64>,
+{
+    /// Convert backup ticks to primary ticks, carrying the remainder over to the next conversion
+    fn to_primary_ticks(&self, ticks: Backup::T) -> Primary::T {
+        // ticks × backup scaling factor / primary scaling factor
+        let numerator = u128::from(u64::from(ticks))
+            * u128::from(*Backup::SCALING_FACTOR.numerator())
+            * u128::from(*Primary::SCALING_FACTOR.denominator())
+            + u128::from(self.residue.get());
+        let denominator = u128::from(*Backup::SCALING_FACTOR.denominator())
+            * u128::from(*Primary::SCALING_FACTOR.numerator());
+
+        // the remainder is less than the denominator, itself a product of two `u32`s
+        self.residue.set((numerator % denominator) as u64);
+        u64::try_from(numerator / denominator)
+            .ok()
+            .and_then(|ticks| Primary::T::try_from(ticks).ok())
+            .unwrap_or_else(Primary::T::max_value)
+    }
+
+    /// Advance the reading by the given primary ticks
+    fn advance(&self, ticks: Primary::T) -> Instant<Self> {
+        let reading = self.last_reading.get().map_or(ticks, |last| {
+            *Instant::<Self>::new(last)
+                .wrapping_add_ticks(ticks)
+                .duration_since_epoch()
+                .integer()
+        });
+        self.last_reading.set(Option::Some(reading));
+
+        Instant::new(reading)
+    }
+
+    /// Advance the reading by the given backup ticks
+    fn bridge(&self, ticks: Option<Backup::T>) -> Instant<Self> {
+        match (self.last_reading.get(), ticks) {
+            (Option::Some(_), Option::Some(ticks)) => self.advance(self.to_primary_ticks(ticks)),
+            _ => self.advance(Primary::T::from(0)),
+        }
+    }
+}
+
+impl<Primary: crate::Clock, Backup: crate::Clock> crate::Clock for FailoverClock<Primary, Backup>
+where
+    u64: From<Backup::T>,
+    Primary::T: TryFrom<u64>,
+{
+    type T = Primary::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Primary::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Primary::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let backup = self.backup.try_now();
+        let backup_elapsed = match (&backup, self.last_backup.get()) {
+            (Ok(now), Option::Some(last)) => {
+                Option::Some(now.wrapping_ticks_since(&Instant::new(last)))
+            }
+            _ => Option::None,
+        };
+        self.last_backup.set(
+            backup
+                .as_ref()
+                .ok()
+                .map(|now| *now.duration_since_epoch().integer()),
+        );
+
+        if self.source.get() == Source::Primary {
+            if let Ok(instant) = self.primary.try_now() {
+                let now = *instant.duration_since_epoch().integer();
+                match self.last_primary.replace(Option::Some(now)) {
+                    Option::Some(last) if now != last => {
+                        self.stalled.set(Backup::T::from(0));
+                        return Ok(self.advance(instant.wrapping_ticks_since(&Instant::new(last))));
+                    }
+                    Option::Some(_) => {
+                        let stalled = self
+                            .stalled
+                            .get()
+                            .checked_add(&backup_elapsed.unwrap_or_else(|| Backup::T::from(0)))
+                            .unwrap_or_else(Backup::T::max_value);
+                        self.stalled.set(stalled);
+                        if stalled < self.stall_timeout || backup_elapsed.is_none() {
+                            return Ok(self.advance(Primary::T::from(0)));
+                        }
+
+                        // the primary stopped: account for the time since it last advanced
+                        self.source.set(Source::Backup);
+                        return Ok(self.bridge(Option::Some(stalled)));
+                    }
+                    Option::None if self.last_reading.get().is_none() => {
+                        return Ok(self.advance(now));
+                    }
+                    Option::None => return Ok(self.bridge(backup_elapsed)),
+                }
+            }
+
+            self.source.set(Source::Backup);
+        }
+
+        backup?;
+        Ok(self.bridge(backup_elapsed))
+    }
+}
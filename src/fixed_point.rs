@@ -1,7 +1,13 @@
 //! Fixed-point values
-use crate::{fraction::Fraction, time_int::TimeInt, ConversionError};
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
-use num::{Bounded, CheckedDiv, CheckedMul};
+use crate::{
+    fraction::{Fraction, Rounding},
+    time_int::TimeInt,
+    ConversionError,
+};
+#[cfg(any(feature = "duration", feature = "rate"))]
+use core::marker::PhantomData;
+use core::{convert::TryFrom, prelude::v1::*};
+use num::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
 
 /// Fixed-point value type
 ///
@@ -46,37 +52,7 @@ pub trait FixedPoint: Sized + Copy {
     where
         Self::T: TryFrom<SourceInt>,
     {
-        if size_of::<Self::T>() > size_of::<SourceInt>() {
-            // the dest integer is wider than the source, first promote the source integer to the
-            // dest type
-            let ticks = Self::T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)?;
-            let ticks =
-                Self::convert_ticks(ticks, scaling_factor).ok_or(ConversionError::Unspecified)?;
-            Ok(Self::new(ticks))
-        } else {
-            let ticks =
-                Self::convert_ticks(ticks, scaling_factor).ok_or(ConversionError::Unspecified)?;
-            let ticks = Self::T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)?;
-            Ok(Self::new(ticks))
-        }
-    }
-
-    #[doc(hidden)]
-    fn convert_ticks<T: TimeInt>(ticks: T, scaling_factor: Fraction) -> Option<T> {
-        if (scaling_factor >= Fraction::new(1, 1) && Self::SCALING_FACTOR <= Fraction::new(1, 1))
-            || (scaling_factor <= Fraction::new(1, 1)
-                && Self::SCALING_FACTOR >= Fraction::new(1, 1))
-        {
-            TimeInt::checked_div_fraction(
-                &TimeInt::checked_mul_fraction(&ticks, &scaling_factor)?,
-                &Self::SCALING_FACTOR,
-            )
-        } else {
-            TimeInt::checked_mul_fraction(
-                &ticks,
-                &scaling_factor.checked_div(&Self::SCALING_FACTOR)?,
-            )
-        }
+        scale(ticks, scaling_factor, Self::SCALING_FACTOR).map(Self::new)
     }
 
     /// Returns the _integer_ of the fixed-point value after converting to the _scaling factor_
@@ -103,46 +79,7 @@ pub trait FixedPoint: Sized + Copy {
         Self::T: TimeInt,
         T: TryFrom<Self::T>,
     {
-        if size_of::<T>() > size_of::<Self::T>() {
-            let ticks =
-                T::try_from(*self.integer()).map_err(|_| ConversionError::ConversionFailure)?;
-
-            if fraction > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(&ticks, &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)
-            } else {
-                TimeInt::checked_mul_fraction(
-                    &ticks,
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)
-            }
-        } else {
-            let ticks = if Self::SCALING_FACTOR > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(self.integer(), &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            } else {
-                TimeInt::checked_mul_fraction(
-                    self.integer(),
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            };
-
-            T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)
-        }
+        scale(*self.integer(), Self::SCALING_FACTOR, fraction)
     }
 
     /// Panicky addition
@@ -163,6 +100,77 @@ pub trait FixedPoint: Sized + Copy {
         Self::new(*self.integer() - *Self::try_from(rhs).ok().unwrap().integer())
     }
 
+    /// Add with overflow checking
+    ///
+    /// Returns [`None`] if the RHS can't be converted to the LHS type or the sum overflows.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds(1_u32).checked_add(&Seconds(1_u32)), Some(Milliseconds(1_001_u32)));
+    /// assert_eq!(Seconds(u32::MAX).checked_add(&Seconds(1_u32)), None);
+    /// ```
+    fn checked_add<Rhs: FixedPoint>(&self, rhs: &Rhs) -> Option<Self>
+    where
+        Self: TryFrom<Rhs>,
+    {
+        Some(Self::new(
+            (*self.integer()).checked_add(Self::try_from(*rhs).ok()?.integer())?,
+        ))
+    }
+
+    /// Subtract with overflow checking
+    ///
+    /// Returns [`None`] if the RHS can't be converted to the LHS type or the difference would be
+    /// negative.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds(2_001_u32).checked_sub(&Seconds(1_u32)), Some(Milliseconds(1_001_u32)));
+    /// assert_eq!(Seconds(1_u32).checked_sub(&Seconds(2_u32)), None);
+    /// ```
+    fn checked_sub<Rhs: FixedPoint>(&self, rhs: &Rhs) -> Option<Self>
+    where
+        Self: TryFrom<Rhs>,
+    {
+        Some(Self::new(
+            (*self.integer()).checked_sub(Self::try_from(*rhs).ok()?.integer())?,
+        ))
+    }
+
+    /// Add, saturating at the maximum value
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds(1_u32).saturating_add(&Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Seconds(u32::MAX).saturating_add(&Seconds(1_u32)), Seconds(u32::MAX));
+    /// ```
+    fn saturating_add<Rhs: FixedPoint>(&self, rhs: &Rhs) -> Self
+    where
+        Self: TryFrom<Rhs>,
+    {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| Self::new(Self::max_value()))
+    }
+
+    /// Subtract, saturating at `0`
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Milliseconds(2_001_u32).saturating_sub(&Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Seconds(1_u32).saturating_sub(&Seconds(2_u32)), Seconds(0_u32));
+    /// ```
+    fn saturating_sub<Rhs: FixedPoint>(&self, rhs: &Rhs) -> Self
+    where
+        Self: TryFrom<Rhs>,
+    {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|| Self::new(Self::min_value()))
+    }
+
     /// Panicky multiplication
     #[doc(hidden)]
     fn mul(self, rhs: Self::T) -> Self {
@@ -170,17 +178,44 @@ pub trait FixedPoint: Sized + Copy {
     }
 
     /// Multiply with overflow checking
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Seconds(2_u32).checked_mul(&3), Some(Seconds(6_u32)));
+    /// assert_eq!(Seconds(u32::MAX).checked_mul(&2), None);
+    /// ```
     fn checked_mul(&self, rhs: &Self::T) -> Option<Self> {
         Some(Self::new((*self.integer()).checked_mul(rhs)?))
     }
 
+    /// Multiply, saturating at the maximum value
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Seconds(2_u32).saturating_mul(&3), Seconds(6_u32));
+    /// assert_eq!(Seconds(u32::MAX).saturating_mul(&2), Seconds(u32::MAX));
+    /// ```
+    fn saturating_mul(&self, rhs: &Self::T) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| Self::new(Self::max_value()))
+    }
+
     /// Panicky division
     #[doc(hidden)]
     fn div(self, rhs: Self::T) -> Self {
         Self::new(*self.integer() / rhs)
     }
 
-    /// Multiply with overflow checking
+    /// Divide, returning [`None`] if the divisor is `0`
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// #
+    /// assert_eq!(Seconds(6_u32).checked_div(&3), Some(Seconds(2_u32)));
+    /// assert_eq!(Seconds(6_u32).checked_div(&0), None);
+    /// ```
     fn checked_div(&self, rhs: &Self::T) -> Option<Self> {
         Some(Self::new((*self.integer()).checked_div(rhs)?))
     }
@@ -214,12 +249,281 @@ pub trait FixedPoint: Sized + Copy {
     }
 }
 
+/// Convert `ticks` of the `from` _scaling factor_ to the `to` _scaling factor_ (truncated)
+///
+/// The combined _scaling factor_ is exact (`u64`/`u64`) and the intermediate math is done in
+/// `u128`, so no precision is lost and intermediate results can't overflow. Only the result must
+/// fit (with `u128` ticks, the intermediate product must fit as well):
+///
+/// - [`ConversionError::Unspecified`]: The result (or a `u128` intermediate product) doesn't fit in
+///   the wider of the two integer types (or a _scaling factor_ is `0`)
+/// - [`ConversionError::ConversionFailure`]: The result doesn't fit in the destination type
+pub(crate) fn scale<Source: TimeInt, Dest: TimeInt>(
+    ticks: Source,
+    from: Fraction,
+    to: Fraction,
+) -> Result<Dest, ConversionError> {
+    scale_rounded(ticks, from, to, Rounding::Down)
+}
+
+/// [`scale()`] with the given rounding
+pub(crate) fn scale_rounded<Source: TimeInt, Dest: TimeInt>(
+    ticks: Source,
+    from: Fraction,
+    to: Fraction,
+    rounding: Rounding,
+) -> Result<Dest, ConversionError> {
+    let ratio = (
+        u64::from(*from.numerator()) * u64::from(*to.denominator()),
+        u64::from(*from.denominator()) * u64::from(*to.numerator()),
+    );
+
+    scale_ratio(ticks, ratio, rounding)
+}
+
+/// Convert between fixed-point types (truncated), with their [`Factor`] reduced at compile time
+///
+/// See [`scale()`] for the errors.
+#[cfg(any(feature = "duration", feature = "rate"))]
+pub(crate) fn convert<Source: FixedPoint, Dest: FixedPoint>(
+    source: Source,
+) -> Result<Dest, ConversionError> {
+    convert_rounded(source, Rounding::Down)
+}
+
+/// [`convert()`] with the given rounding
+#[cfg(any(feature = "duration", feature = "rate"))]
+pub(crate) fn convert_rounded<Source: FixedPoint, Dest: FixedPoint>(
+    source: Source,
+    rounding: Rounding,
+) -> Result<Dest, ConversionError> {
+    scale_ratio(*source.integer(), Factor::<Source, Dest>::RATIO, rounding).map(Dest::new)
+}
+
+/// [`convert()`] failing with [`ConversionError::LossOfPrecision`] rather than truncating
+#[cfg(feature = "duration")]
+pub(crate) fn convert_exact<Source: FixedPoint, Dest: FixedPoint>(
+    source: Source,
+) -> Result<Dest, ConversionError> {
+    let truncated = convert_rounded::<Source, Dest>(source, Rounding::Down)?;
+    if convert_rounded::<Source, Dest>(source, Rounding::Up)?.integer() != truncated.integer() {
+        return Err(ConversionError::LossOfPrecision);
+    }
+
+    Ok(truncated)
+}
+
+/// The conversion factor between the _scaling factors_ of two fixed-point types
+///
+/// The ratio is reduced at compile time, so that (eg.) milliseconds convert to microseconds with a
+/// single multiplication by `1_000`.
+#[cfg(any(feature = "duration", feature = "rate"))]
+pub(crate) struct Factor<Source, Dest>(PhantomData<(Source, Dest)>);
+
+#[cfg(any(feature = "duration", feature = "rate"))]
+impl<Source: FixedPoint, Dest: FixedPoint> Factor<Source, Dest> {
+    /// `Source::SCALING_FACTOR` / `Dest::SCALING_FACTOR` as a reduced `(numerator, denominator)`
+    pub(crate) const RATIO: (u64, u64) =
+        reduced_ratio(Source::SCALING_FACTOR, Dest::SCALING_FACTOR);
+}
+
+/// Returns `from` / `to` as a reduced `(numerator, denominator)`
+#[cfg(any(feature = "duration", feature = "rate"))]
+pub(crate) const fn reduced_ratio(from: Fraction, to: Fraction) -> (u64, u64) {
+    let numerator = *from.numerator() as u64 * *to.denominator() as u64;
+    let denominator = *from.denominator() as u64 * *to.numerator() as u64;
+
+    let (mut a, mut b) = (numerator, denominator);
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    match a {
+        0 => (numerator, denominator),
+        divisor => (numerator / divisor, denominator / divisor),
+    }
+}
+
+/// `ticks` × `numerator` / `denominator` with the given rounding
+///
+/// Integral ratios (eg. seconds to milliseconds) skip the division, and the reciprocals of
+/// integral ratios (eg. milliseconds to seconds) skip the multiplication (so they can't overflow).
+/// Power-of-two factors (eg. of a 32.768 kHz clock) are applied with shifts. With a constant (eg.
+/// [`Factor`]) ratio, the checks compile away.
+#[allow(clippy::manual_is_multiple_of)]
+fn scale_ratio<Source: TimeInt, Dest: TimeInt>(
+    ticks: Source,
+    (numerator, denominator): (u64, u64),
+    rounding: Rounding,
+) -> Result<Dest, ConversionError> {
+    if denominator == 0 {
+        return Err(ConversionError::Unspecified);
+    }
+
+    let ticks = ticks.into_u128();
+    let ticks = if numerator % denominator == 0 {
+        multiply(ticks, u128::from(numerator / denominator))
+    } else if numerator != 0 && denominator % numerator == 0 {
+        rounding.divide(ticks, u128::from(denominator / numerator))
+    } else {
+        multiply(ticks, u128::from(numerator))
+            .and_then(|ticks| rounding.divide(ticks, u128::from(denominator)))
+    }
+    .ok_or(ConversionError::Unspecified)?;
+    if ticks
+        > Source::max_value()
+            .into_u128()
+            .max(Dest::max_value().into_u128())
+    {
+        return Err(ConversionError::Unspecified);
+    }
+
+    Dest::try_from_u128(ticks).ok_or(ConversionError::ConversionFailure)
+}
+
+/// `ticks` × `from` / `to` (truncated) or [`None`] if a _scaling factor_ is `0` or the product
+/// overflows
+///
+/// `const` so `const` conversions can share it.
+#[cfg(feature = "duration")]
+pub(crate) const fn scale_u128(ticks: u128, from: Fraction, to: Fraction) -> Option<u128> {
+    let numerator = *from.numerator() as u64 * *to.denominator() as u64;
+    let denominator = *from.denominator() as u64 * *to.numerator() as u64;
+    if denominator == 0 {
+        return None;
+    }
+
+    match multiply(ticks, numerator as u128) {
+        Some(product) if denominator.is_power_of_two() => {
+            Some(product >> denominator.trailing_zeros())
+        }
+        Some(product) => Some(product / denominator as u128),
+        None => None,
+    }
+}
+
+/// `numerator` / `denominator` (truncated) as a fixed-point value, for the conversions that
+/// compute a ratio of (checked) `u128` products
+///
+/// # Errors
+///
+/// - [`ConversionError::DivByZero`]: The denominator is `0`
+/// - [`ConversionError::ConversionFailure`]: The quotient doesn't fit in the destination type
+#[cfg(feature = "duration")]
+pub(crate) fn from_ratio<Dest: FixedPoint>(
+    numerator: u128,
+    denominator: u128,
+) -> Result<Dest, ConversionError> {
+    let integer = numerator
+        .checked_div(denominator)
+        .ok_or(ConversionError::DivByZero)?;
+
+    Dest::T::try_from_u128(integer)
+        .map(Dest::new)
+        .ok_or(ConversionError::ConversionFailure)
+}
+
+/// `ticks` × `factor` or [`None`] if the product overflows
+///
+/// A power-of-two `factor` is a shift rather than a (software) multiplication.
+const fn multiply(ticks: u128, factor: u128) -> Option<u128> {
+    if !factor.is_power_of_two() {
+        return ticks.checked_mul(factor);
+    }
+
+    let shift = factor.trailing_zeros();
+    if ticks.leading_zeros() >= shift {
+        Some(ticks << shift)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::duration::*;
     use crate::fixed_point;
 
+    #[test]
+    fn factor() {
+        assert_eq!(Factor::<Milliseconds, Microseconds>::RATIO, (1_000, 1));
+        assert_eq!(Factor::<Microseconds, Milliseconds>::RATIO, (1, 1_000));
+        assert_eq!(Factor::<Hours, Nanoseconds>::RATIO, (3_600_000_000_000, 1));
+        assert_eq!(Factor::<Seconds, Seconds<u64>>::RATIO, (1, 1));
+        assert_eq!(
+            reduced_ratio(Fraction::new(1, 32_768), Fraction::new(1, 1_000)),
+            (125, 4_096)
+        );
+        assert_eq!(
+            reduced_ratio(Fraction::new(0, 1), Fraction::new(1, 1)),
+            (0, 1)
+        );
+        assert_eq!(
+            reduced_ratio(Fraction::new(1, 1), Fraction::new(0, 1)),
+            (1, 0)
+        );
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(
+            fixed_point::convert(Milliseconds(5_u32)),
+            Ok(Microseconds(5_000_u64))
+        );
+        assert_eq!(
+            fixed_point::convert_rounded(Microseconds(1_500_u32), Rounding::Nearest),
+            Ok(Milliseconds(2_u32))
+        );
+        assert_eq!(
+            fixed_point::convert_exact::<_, Milliseconds<u32>>(Microseconds(1_500_u32)),
+            Err(ConversionError::LossOfPrecision)
+        );
+        assert_eq!(
+            fixed_point::convert::<_, Milliseconds<u32>>(Seconds(u32::MAX)),
+            Err(ConversionError::Unspecified)
+        );
+    }
+
+    #[test]
+    fn power_of_two_factors() {
+        assert_eq!(multiply(3, 512), Some(1_536));
+        assert_eq!(multiply(u128::MAX >> 9, 512), Some(u128::MAX >> 9 << 9));
+        assert_eq!(multiply((u128::MAX >> 9) + 1, 512), None);
+        assert_eq!(multiply(0, 1 << 127), Some(0));
+
+        // 32.768 kHz ticks to microseconds: × 15,625 / 512
+        let ratio = reduced_ratio(Fraction::new(1, 32_768), Fraction::new(1, 1_000_000));
+        assert_eq!(ratio, (15_625, 512));
+        assert_eq!(scale_ratio(1_u32, ratio, Rounding::Down), Ok(30_u32));
+        assert_eq!(scale_ratio(1_u32, ratio, Rounding::Nearest), Ok(31_u32));
+        assert_eq!(scale_ratio(1_u32, ratio, Rounding::Up), Ok(31_u32));
+        assert_eq!(
+            scale_ratio(32_768_u32, ratio, Rounding::Up),
+            Ok(1_000_000_u32)
+        );
+
+        // and back: × 512 / 15,625
+        assert_eq!(
+            scale_ratio(1_000_000_u32, (512, 15_625), Rounding::Down),
+            Ok(32_768_u32)
+        );
+        assert_eq!(
+            scale_ratio(u32::MAX, (1 << 20, 1), Rounding::Down),
+            Ok::<_, ConversionError>(u64::from(u32::MAX) << 20)
+        );
+        assert_eq!(
+            scale_ratio::<u128, u128>(u128::MAX, (2, 1), Rounding::Down),
+            Err(ConversionError::Unspecified)
+        );
+
+        assert_eq!(
+            scale_u128(1, Fraction::new(1, 32_768), Fraction::new(1, 1_000_000)),
+            Some(30)
+        );
+    }
+
     #[test]
     fn from_ticks() {
         assert_eq!(
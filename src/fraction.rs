@@ -1,7 +1,7 @@
 //! Fractional/Rational values
-use crate::ConversionError;
-use core::ops;
-use num::{rational::Ratio, CheckedDiv, CheckedMul, Zero};
+use crate::{time_int::TimeInt, ConversionError};
+use core::{cmp::Ordering, fmt, hash, ops};
+use num::integer::gcd;
 
 /// A fractional value
 ///
@@ -12,8 +12,13 @@ use num::{rational::Ratio, CheckedDiv, CheckedMul, Zero};
 /// [`Rate`]: rate/trait.Rate.html
 /// [`Clock`]: clock/trait.Clock.html
 /// [`Instant`]: instant/struct.Instant.html
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Fraction(Ratio<u32>);
+///
+/// Fractions compare (and hash) by value, so `Fraction::new(1, 2) == Fraction::new(2, 4)`.
+#[derive(Copy, Clone)]
+pub struct Fraction {
+    numerator: u32,
+    denominator: u32,
+}
 
 impl Fraction {
     /// Construct a new `Fraction`.
@@ -21,17 +26,28 @@ impl Fraction {
     /// A reduction is **not** performed. Also there is no check for a denominator of `0`. If these
     /// features are needed, use [`Fraction::new_reduce()`]
     pub const fn new(numerator: u32, denominator: u32) -> Self {
-        Self(Ratio::new_raw(numerator, denominator))
+        Self {
+            numerator,
+            denominator,
+        }
     }
 
     /// Return the numerator of the fraction
     pub const fn numerator(&self) -> &u32 {
-        self.0.numer()
+        &self.numerator
     }
 
     /// Return the denominator of the fraction
     pub const fn denominator(&self) -> &u32 {
-        self.0.denom()
+        &self.denominator
+    }
+
+    /// Returns the fraction in lowest terms (`0/0` is left as is)
+    fn reduced(self) -> Self {
+        match gcd(self.numerator, self.denominator) {
+            0 => self,
+            divisor => Self::new(self.numerator / divisor, self.denominator / divisor),
+        }
     }
 }
 
@@ -45,8 +61,8 @@ impl Fraction {
     /// [`ConversionError::DivByZero`] : A `0` denominator was detected
     // TODO: add example
     pub fn new_reduce(numerator: u32, denominator: u32) -> Result<Self, ConversionError> {
-        if !denominator.is_zero() {
-            Ok(Self(Ratio::new(numerator, denominator)))
+        if denominator != 0 {
+            Ok(Self::new(numerator, denominator).reduced())
         } else {
             Err(ConversionError::DivByZero)
         }
@@ -54,19 +70,67 @@ impl Fraction {
 
     /// Returns the value truncated to an integer
     pub fn to_integer(&self) -> u32 {
-        self.0.to_integer()
+        self.numerator / self.denominator
     }
 
     /// Constructs a `Fraction` from an integer.
     ///
     /// Equivalent to `Fraction::new(value,1)`.
     pub fn from_integer(value: u32) -> Self {
-        Self(Ratio::from_integer(value))
+        Self::new(value, 1)
     }
 
     /// Returns the reciprocal of the fraction
+    ///
+    /// # Panics
+    ///
+    /// The numerator is `0`
     pub fn recip(self) -> Self {
-        Self(self.0.recip())
+        assert!(self.numerator != 0, "division by zero");
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// `Fraction` × integer = integer (truncated)
+    ///
+    /// The intermediate product is widened (to `u128`), so only the result must fit. Integral
+    /// fractions skip the division.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, ConversionError};
+    /// #
+    /// assert_eq!(Fraction::new(3, 4).try_mul_integer(u32::MAX), Ok(3_221_225_471_u32));
+    /// assert_eq!(Fraction::new(1_000, 1).try_mul_integer(5_u16), Ok(5_000_u16));
+    ///
+    /// assert_eq!(
+    ///     Fraction::new(2, 1).try_mul_integer(u32::MAX),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// assert_eq!(
+    ///     Fraction::new(1, 0).try_mul_integer(5_u32),
+    ///     Err(ConversionError::DivByZero)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The denominator is `0`
+    /// - [`ConversionError::Overflow`]: The result doesn't fit in the integer type
+    pub fn try_mul_integer<T: TimeInt>(&self, integer: T) -> Result<T, ConversionError> {
+        let numerator = u128::from(*self.numerator());
+        let product = match *self.denominator() {
+            0 => return Err(ConversionError::DivByZero),
+            1 => integer.into_u128().checked_mul(numerator),
+            denominator => integer
+                .into_u128()
+                .checked_mul(numerator)
+                .map(|product| product / u128::from(denominator)),
+        };
+
+        product
+            .and_then(T::try_from_u128)
+            .ok_or(ConversionError::Overflow)
     }
 
     /// Checked `Fraction` × `Fraction` = `Fraction`
@@ -85,7 +149,22 @@ impl Fraction {
     ///     None);
     /// ```
     pub fn checked_mul(&self, v: &Self) -> Option<Self> {
-        self.0.checked_mul(&v.0).map(Self)
+        // cross-reduce first so only a product in lowest terms must fit
+        let divisor_ad = gcd(self.numerator, v.denominator).max(1);
+        let divisor_bc = gcd(self.denominator, v.numerator).max(1);
+        let denominator =
+            (self.denominator / divisor_bc).checked_mul(v.denominator / divisor_ad)?;
+        if denominator == 0 {
+            return Option::None;
+        }
+
+        Some(
+            Self::new(
+                (self.numerator / divisor_ad).checked_mul(v.numerator / divisor_bc)?,
+                denominator,
+            )
+            .reduced(),
+        )
     }
 
     /// Checked `Fraction` / `Fraction` = `Fraction`
@@ -104,47 +183,52 @@ impl Fraction {
     ///     None);
     /// ```
     pub fn checked_div(&self, v: &Self) -> Option<Self> {
-        self.0.checked_div(&v.0).map(Self)
-    }
-}
-
-impl ops::Mul<Fraction> for u32 {
-    type Output = Self;
-
-    /// Panicky u32 × `Fraction` = u32
-    fn mul(self, rhs: Fraction) -> Self::Output {
-        (rhs.0 * self).to_integer()
-    }
-}
-
-impl ops::Div<Fraction> for u32 {
-    type Output = Self;
+        if v.numerator == 0 {
+            return Option::None;
+        }
 
-    /// Panicky u32 / `Fraction` = u32
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Fraction) -> Self::Output {
-        (rhs.0.recip() * self).to_integer()
+        self.checked_mul(&Self::new(v.denominator, v.numerator))
     }
 }
 
-impl ops::Mul<Fraction> for u64 {
-    type Output = Self;
+macro_rules! impl_int_ops {
+    ( $int:ident ) => {
+        impl ops::Mul<Fraction> for $int {
+            type Output = Self;
 
-    /// Panicky u64 × `Fraction` = u64
-    fn mul(self, rhs: Fraction) -> Self::Output {
-        (Ratio::new_raw((*rhs.numerator()).into(), (*rhs.denominator()).into()) * self).to_integer()
-    }
-}
+            #[doc = concat!("Panicky ", stringify!($int), " × `Fraction` = ", stringify!($int))]
+            ///
+            /// # Panics
+            ///
+            /// If the denominator is `0` or the result overflows the type. See
+            /// [`Fraction::try_mul_integer()`] for the fallible form.
+            fn mul(self, rhs: Fraction) -> Self::Output {
+                rhs.try_mul_integer(self).unwrap()
+            }
+        }
 
-impl ops::Div<Fraction> for u64 {
-    type Output = Self;
+        impl ops::Div<Fraction> for $int {
+            type Output = Self;
 
-    /// Panicky u64 / `Fraction` = u64
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Fraction) -> Self::Output {
-        (Ratio::new_raw((*rhs.denominator()).into(), (*rhs.numerator()).into()) * self).to_integer()
-    }
+            #[doc = concat!("Panicky ", stringify!($int), " / `Fraction` = ", stringify!($int))]
+            ///
+            /// # Panics
+            ///
+            /// If the numerator is `0` or the result overflows the type. See
+            /// [`Fraction::try_mul_integer()`] (with the [reciprocal](Fraction::recip)) for the
+            /// fallible form.
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Fraction) -> Self::Output {
+                rhs.recip().try_mul_integer(self).unwrap()
+            }
+        }
+    };
 }
+impl_int_ops![u8];
+impl_int_ops![u16];
+impl_int_ops![u32];
+impl_int_ops![u64];
+impl_int_ops![u128];
 
 impl ops::Mul for Fraction {
     type Output = Self;
@@ -156,7 +240,8 @@ impl ops::Mul for Fraction {
     /// The same reason the integer operation would panic. Namely, if the
     /// result overflows the type.
     fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
+        self.checked_mul(&rhs)
+            .expect("Fraction multiplication overflowed")
     }
 }
 
@@ -170,7 +255,125 @@ impl ops::Div for Fraction {
     /// The same reason the integer operation would panic. Namely, if the
     /// result overflows the type.
     fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0)
+        self.checked_div(&rhs)
+            .expect("Fraction division overflowed")
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // cross-multiplied in `u64`, so exact
+        (u64::from(self.numerator) * u64::from(other.denominator))
+            .cmp(&(u64::from(other.numerator) * u64::from(self.denominator)))
+            // (only fractions with a `0` denominator can tie without being equal)
+            .then_with(|| {
+                let (lhs, rhs) = (self.reduced(), other.reduced());
+                (lhs.numerator, lhs.denominator).cmp(&(rhs.numerator, rhs.denominator))
+            })
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl hash::Hash for Fraction {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let reduced = self.reduced();
+        (reduced.numerator, reduced.denominator).hash(state);
+    }
+}
+
+/// How the result of a division is rounded to an integer
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Rounding {
+    /// Toward `0` (truncated)
+    #[default]
+    Down,
+    /// To the nearest integer, halves up
+    Nearest,
+    /// Away from `0`
+    Up,
+}
+
+impl Rounding {
+    /// `numerator` / `denominator`, rounded, or [`None`] if the denominator is `0`
+    ///
+    /// A power-of-two denominator (eg. of a 32.768 kHz clock) is a shift rather than a (software)
+    /// division.
+    pub(crate) fn divide(self, numerator: u128, denominator: u128) -> Option<u128> {
+        let (quotient, remainder) = if denominator.is_power_of_two() {
+            (
+                numerator >> denominator.trailing_zeros(),
+                numerator & (denominator - 1),
+            )
+        } else {
+            (numerator.checked_div(denominator)?, numerator % denominator)
+        };
+
+        Some(match self {
+            Rounding::Down => quotient,
+            Rounding::Nearest if remainder >= denominator - remainder => quotient + 1,
+            Rounding::Nearest => quotient,
+            Rounding::Up if remainder > 0 => quotient + 1,
+            Rounding::Up => quotient,
+        })
+    }
+}
+
+impl fmt::Display for Fraction {
+    /// Formats as `numerator/denominator`
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// assert_eq!(format!("{}", Fraction::new(1, 1_000)), "1/1000");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator(), self.denominator())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fraction {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=u32}/{=u32}", *self.numerator(), *self.denominator())
+    }
+}
+
+/// Serialized as a `(numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(*self.numerator(), *self.denominator()), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::new(numerator, denominator))
+    }
+}
+
+impl fmt::Debug for Fraction {
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// assert_eq!(format!("{:?}", Fraction::new(1, 1_000)), "Fraction(1/1000)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fraction({})", self)
     }
 }
 
@@ -0,0 +1,186 @@
+//! Conversions to and from the [`fugit`](::fugit) crate's types
+//!
+//! - `fugit::Duration` ↔ [`duration::Generic`] and the _named_ duration types
+//! - `fugit::Rate` ↔ [`rate::Generic`] and the _named_ hertz types ([`Hertz`](rate::Hertz),
+//!   [`Kilohertz`](rate::Kilohertz), etc.)
+//! - `fugit::Instant` ↔ [`Instant`]
+//!
+//! `fugit`'s `NOM / DENOM` parameters are its _scaling factor_. Converting into a `Generic` is
+//! lossless (the `Generic` takes the `fugit` type's _scaling factor_), all other conversions
+//! scale the value (truncating, failing if it doesn't fit).
+//!
+//! Instants convert their ticks since the epoch. Instants of a wrapping clock can only be
+//! converted meaningfully between equal _scaling factors_.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::convert::TryFrom;
+//! use embedded_time::{duration, duration::*, rate::*};
+//!
+//! let timeout = fugit::MillisDurationU32::from_ticks(1_500);
+//!
+//! assert_eq!(Microseconds::<u32>::try_from(timeout), Ok(Microseconds(1_500_000_u32)));
+//! assert_eq!(
+//!     duration::Generic::from(timeout),
+//!     duration::Generic::new(1_500_u32, Fraction::new(1, 1_000))
+//! );
+//! assert_eq!(
+//!     fugit::SecsDurationU32::try_from(Milliseconds(2_500_u32)),
+//!     Ok(fugit::SecsDurationU32::from_ticks(2))
+//! );
+//!
+//! assert_eq!(
+//!     Hertz::<u32>::try_from(fugit::KilohertzU32::from_raw(8)),
+//!     Ok(Hertz(8_000_u32))
+//! );
+//! assert_eq!(
+//!     fugit::HertzU32::try_from(Megahertz(72_u32)),
+//!     Ok(fugit::HertzU32::from_raw(72_000_000))
+//! );
+//! ```
+
+use crate::{
+    duration::{self, units::*},
+    fixed_point,
+    fraction::Fraction,
+    rate::{self, units::*},
+    ConversionError, Instant,
+};
+use core::convert::TryFrom;
+
+macro_rules! impl_generic {
+    ($i:ty) => {
+        impl<const NOM: u32, const DENOM: u32> From<::fugit::Duration<$i, NOM, DENOM>>
+            for duration::Generic<$i>
+        {
+            fn from(duration: ::fugit::Duration<$i, NOM, DENOM>) -> Self {
+                Self::new(duration.ticks(), Fraction::new(NOM, DENOM))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> TryFrom<duration::Generic<$i>>
+            for ::fugit::Duration<$i, NOM, DENOM>
+        {
+            type Error = ConversionError;
+
+            fn try_from(duration: duration::Generic<$i>) -> Result<Self, Self::Error> {
+                Ok(Self::from_ticks(fixed_point::scale(
+                    *duration.integer(),
+                    *duration.scaling_factor(),
+                    Fraction::new(NOM, DENOM),
+                )?))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> From<::fugit::Rate<$i, NOM, DENOM>>
+            for rate::Generic<$i>
+        {
+            fn from(rate: ::fugit::Rate<$i, NOM, DENOM>) -> Self {
+                Self::new(rate.raw(), Fraction::new(NOM, DENOM))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> TryFrom<rate::Generic<$i>>
+            for ::fugit::Rate<$i, NOM, DENOM>
+        {
+            type Error = ConversionError;
+
+            fn try_from(rate: rate::Generic<$i>) -> Result<Self, Self::Error> {
+                Ok(Self::from_raw(fixed_point::scale(
+                    *rate.integer(),
+                    *rate.scaling_factor(),
+                    Fraction::new(NOM, DENOM),
+                )?))
+            }
+        }
+
+        impl<Clock, const NOM: u32, const DENOM: u32> TryFrom<::fugit::Instant<$i, NOM, DENOM>>
+            for Instant<Clock>
+        where
+            Clock: crate::Clock<T = $i>,
+        {
+            type Error = ConversionError;
+
+            fn try_from(instant: ::fugit::Instant<$i, NOM, DENOM>) -> Result<Self, Self::Error> {
+                Ok(Self::new(fixed_point::scale(
+                    instant.ticks(),
+                    Fraction::new(NOM, DENOM),
+                    Clock::SCALING_FACTOR,
+                )?))
+            }
+        }
+
+        impl<Clock, const NOM: u32, const DENOM: u32> TryFrom<Instant<Clock>>
+            for ::fugit::Instant<$i, NOM, DENOM>
+        where
+            Clock: crate::Clock<T = $i>,
+        {
+            type Error = ConversionError;
+
+            fn try_from(instant: Instant<Clock>) -> Result<Self, Self::Error> {
+                Ok(Self::from_ticks(fixed_point::scale(
+                    *instant.duration_since_epoch().integer(),
+                    Clock::SCALING_FACTOR,
+                    Fraction::new(NOM, DENOM),
+                )?))
+            }
+        }
+    };
+}
+impl_generic![u32];
+impl_generic![u64];
+
+macro_rules! impl_named {
+    (@impl $fugit:ident, $get:ident, $from:ident, $name:ident, $i:ty) => {
+        impl<const NOM: u32, const DENOM: u32> TryFrom<::fugit::$fugit<$i, NOM, DENOM>>
+            for $name<$i>
+        {
+            type Error = ConversionError;
+
+            fn try_from(source: ::fugit::$fugit<$i, NOM, DENOM>) -> Result<Self, Self::Error> {
+                Ok(Self::new(fixed_point::scale(
+                    source.$get(),
+                    Fraction::new(NOM, DENOM),
+                    <Self as fixed_point::FixedPoint>::SCALING_FACTOR,
+                )?))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> TryFrom<$name<$i>>
+            for ::fugit::$fugit<$i, NOM, DENOM>
+        {
+            type Error = ConversionError;
+
+            fn try_from(source: $name<$i>) -> Result<Self, Self::Error> {
+                Ok(Self::$from(fixed_point::scale(
+                    source.0,
+                    <$name<$i> as fixed_point::FixedPoint>::SCALING_FACTOR,
+                    Fraction::new(NOM, DENOM),
+                )?))
+            }
+        }
+    };
+    (Duration, $($name:ident),+) => {
+        $(
+            impl_named![@impl Duration, ticks, from_ticks, $name, u32];
+            impl_named![@impl Duration, ticks, from_ticks, $name, u64];
+        )+
+    };
+    (Rate, $($name:ident),+) => {
+        $(
+            impl_named![@impl Rate, raw, from_raw, $name, u32];
+            impl_named![@impl Rate, raw, from_raw, $name, u64];
+        )+
+    };
+}
+impl_named![
+    Duration,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
+impl_named![Rate, Mebihertz, Megahertz, Kibihertz, Kilohertz, Hertz];
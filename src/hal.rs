@@ -0,0 +1,213 @@
+//! [`embedded-hal`](embedded_hal) `CountDown` integration
+//!
+//! - [`CountDownExt`] starts any [`CountDown`] whose `Time` is a _named_ duration with a duration
+//!   of any other unit (eg. `start_for(Milliseconds(10_u32))` on a `Microseconds`-based timer)
+//! - [`ClockCountDown`] implements [`CountDown`] (and [`Periodic`]) in software on top of a
+//!   [`Clock`](crate::Clock)
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::FixedPoint,
+    ConversionError, Instant,
+};
+use core::{convert::TryFrom, marker::PhantomData};
+use embedded_hal::timer::{CountDown, Periodic};
+use void::Void;
+
+/// Duration-unit-agnostic helpers for [`CountDown`] timers
+///
+/// Implemented for all `CountDown` types.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, hal::CountDownExt};
+/// # use embedded_hal::timer::CountDown;
+/// # struct HardwareTimer(u32);
+/// impl CountDown for HardwareTimer {
+///     type Time = Microseconds<u32>;
+///     // ...
+/// #   fn start<T: Into<Self::Time>>(&mut self, count: T) { self.0 = count.into().0 }
+/// #   fn wait(&mut self) -> nb::Result<(), void::Void> { Ok(()) }
+/// }
+///
+/// let mut timer = HardwareTimer(0);
+/// timer.start_for(Milliseconds(10_u32)).unwrap();
+/// assert_eq!(timer.0, 10_000);
+/// ```
+pub trait CountDownExt: CountDown {
+    /// Start the count down with a duration of any unit
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError`]: The duration can't be converted to the `CountDown`'s `Time` type
+    fn start_for<Dur>(&mut self, duration: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Self::Time: TryFrom<duration::Generic<Dur::T>, Error = ConversionError>,
+    {
+        let time = Self::Time::try_from(duration::Generic::new(
+            *duration.integer(),
+            Dur::SCALING_FACTOR,
+        ))?;
+        self.start(time);
+
+        Ok(())
+    }
+
+    /// Start the count down with a duration of any unit and block until it expires
+    ///
+    /// # Errors
+    ///
+    /// See [`CountDownExt::start_for()`]
+    fn wait_for<Dur>(&mut self, duration: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Self::Time: TryFrom<duration::Generic<Dur::T>, Error = ConversionError>,
+    {
+        self.start_for(duration)?;
+        nb::block!(self.wait()).unwrap_or_else(|void| match void {});
+
+        Ok(())
+    }
+}
+
+impl<Timer: CountDown> CountDownExt for Timer {}
+
+/// A periodic software [`CountDown`] driven by a [`Clock`](crate::Clock)
+///
+/// Each period is scheduled from the end of the previous one (not from when it is observed), so
+/// late calls to [`wait()`](CountDown::wait) don't accumulate drift. `wait()` must be called at
+/// least once per [valid comparison window](Instant::valid_comparison_window).
+///
+/// As `CountDown` has no means of reporting them, clock errors are treated as the period not having
+/// expired yet. A count down started while the clock can't be read starts on the first successful
+/// reading.
+///
+/// [`start()`](CountDown::start) can't report errors either: a period that can't be converted to
+/// clock ticks or is beyond the valid comparison window is limited to the valid comparison window.
+/// Use [`try_start()`](ClockCountDown::try_start) to detect such periods.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, hal::ClockCountDown, Instant};
+/// # use embedded_hal::timer::CountDown;
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(self.0.get()))}
+/// }
+///
+/// let clock = Clock(Cell::new(0));
+/// let mut count_down = ClockCountDown::<_, Milliseconds<u32>>::new(&clock);
+/// count_down.start(Milliseconds(10_u32));
+///
+/// clock.0.set(9);
+/// assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+/// clock.0.set(12);
+/// assert_eq!(count_down.wait(), Ok(()));
+/// // the next period ends at 20 ms
+/// clock.0.set(19);
+/// assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+/// ```
+#[derive(Debug)]
+pub struct ClockCountDown<'a, Clock: crate::Clock, Dur> {
+    clock: &'a Clock,
+    /// The period in clock ticks
+    period: Option<Clock::T>,
+    expiration: Option<Instant<Clock>>,
+    _duration: PhantomData<Dur>,
+}
+
+impl<'a, Clock: crate::Clock, Dur> ClockCountDown<'a, Clock, Dur> {
+    /// Construct a stopped count down
+    pub fn new(clock: &'a Clock) -> Self {
+        Self {
+            clock,
+            period: Option::None,
+            expiration: Option::None,
+            _duration: PhantomData,
+        }
+    }
+}
+
+impl<Clock: crate::Clock, Dur> ClockCountDown<'_, Clock, Dur>
+where
+    Dur: Duration + FixedPoint,
+    Clock::T: TryFrom<Dur::T>,
+{
+    /// Start the count down with the given period, reporting a period it can't time
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The period is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The period
+    ///   could not be converted to clock ticks
+    ///
+    /// The count down is unchanged upon failure.
+    pub fn try_start<T>(&mut self, count: T) -> Result<(), ConversionError>
+    where
+        T: Into<Dur>,
+    {
+        self.start_ticks(Instant::<Clock>::window_ticks(count.into())?);
+        Ok(())
+    }
+
+    fn start_ticks(&mut self, period: Clock::T) {
+        self.period = Option::Some(period);
+        self.expiration = self
+            .clock
+            .try_now()
+            .ok()
+            .map(|now| now.wrapping_add_ticks(period));
+    }
+}
+
+impl<Clock: crate::Clock, Dur> CountDown for ClockCountDown<'_, Clock, Dur>
+where
+    Dur: Duration + FixedPoint,
+    Clock::T: TryFrom<Dur::T>,
+{
+    type Time = Dur;
+
+    /// Start the count down with the given period, limited to the valid comparison window (see
+    /// [`try_start()`](ClockCountDown::try_start))
+    fn start<T>(&mut self, count: T)
+    where
+        T: Into<Self::Time>,
+    {
+        if self.try_start(count).is_err() {
+            self.start_ticks(*Instant::<Clock>::valid_comparison_window().integer());
+        }
+    }
+
+    fn wait(&mut self) -> nb::Result<(), Void> {
+        let period = self.period.ok_or(nb::Error::WouldBlock)?;
+        let now = self.clock.try_now().map_err(|_| nb::Error::WouldBlock)?;
+
+        match self.expiration {
+            Option::Some(expiration) if now >= expiration => {
+                self.expiration = Option::Some(expiration.wrapping_add_ticks(period));
+                Ok(())
+            }
+            Option::Some(_) => Err(nb::Error::WouldBlock),
+            Option::None => {
+                self.expiration = Option::Some(now.wrapping_add_ticks(period));
+                Err(nb::Error::WouldBlock)
+            }
+        }
+    }
+}
+
+impl<Clock: crate::Clock, Dur> Periodic for ClockCountDown<'_, Clock, Dur>
+where
+    Dur: Duration + FixedPoint,
+    Clock::T: TryFrom<Dur::T>,
+{
+}
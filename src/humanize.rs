@@ -0,0 +1,189 @@
+//! Coarse, human-friendly formatting of durations
+//!
+//! Intended for small displays (eg. "last sync about 5 minutes ago"). Only integer arithmetic is
+//! used. See [`Duration::humanize()`](crate::duration::Duration::humanize).
+//!
+//! | Duration            | Output               |
+//! | :------------------ | :------------------- |
+//! | < 1 s               | `less than a second` |
+//! | < 1 min             | `N seconds`          |
+//! | < 59.5 min          | `about N minutes`    |
+//! | < 23.5 h            | `about N hours`      |
+//! | otherwise           | `about N days`       |
+//!
+//! With the `locale` feature, the phrases can be rendered in another language by implementing
+//! `Locale`.
+
+use core::fmt;
+
+/// The unit of a [`Phrase`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Unit {
+    /// Seconds
+    Second,
+    /// Minutes
+    Minute,
+    /// Hours
+    Hour,
+    /// Days
+    Day,
+}
+
+/// A language-independent description of an approximate duration
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Phrase {
+    /// Less than one of the unit (eg. "less than a second")
+    LessThanOne(Unit),
+    /// An exact count of the unit (eg. "5 seconds")
+    Exactly(u64, Unit),
+    /// A rounded count of the unit (eg. "about 5 minutes")
+    About(u64, Unit),
+}
+
+impl Phrase {
+    fn from_seconds(seconds: u64) -> Self {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        let rounded = |unit: u64| seconds.saturating_add(unit / 2) / unit;
+
+        if seconds == 0 {
+            Self::LessThanOne(Unit::Second)
+        } else if seconds < MINUTE {
+            Self::Exactly(seconds, Unit::Second)
+        } else if rounded(MINUTE) < 60 {
+            Self::About(rounded(MINUTE), Unit::Minute)
+        } else if rounded(HOUR) < 24 {
+            Self::About(rounded(HOUR), Unit::Hour)
+        } else {
+            Self::About(rounded(DAY), Unit::Day)
+        }
+    }
+
+    fn fmt_english(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn name(unit: Unit) -> &'static str {
+            match unit {
+                Unit::Second => "second",
+                Unit::Minute => "minute",
+                Unit::Hour => "hour",
+                Unit::Day => "day",
+            }
+        }
+        fn plural(count: u64) -> &'static str {
+            if count == 1 {
+                ""
+            } else {
+                "s"
+            }
+        }
+
+        match self {
+            Self::LessThanOne(unit) => write!(f, "less than a {}", name(unit)),
+            Self::Exactly(count, unit) => write!(f, "{} {}{}", count, name(unit), plural(count)),
+            Self::About(count, unit) => {
+                write!(f, "about {} {}{}", count, name(unit), plural(count))
+            }
+        }
+    }
+}
+
+/// An approximate duration that formats (via [`Display`](fmt::Display)) as an English phrase
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Milliseconds(900_u32).humanize().to_string(), "less than a second");
+/// assert_eq!(Seconds(1_u32).humanize().to_string(), "1 second");
+/// assert_eq!(Seconds(45_u32).humanize().to_string(), "45 seconds");
+/// assert_eq!(Seconds(150_u32).humanize().to_string(), "about 3 minutes");
+/// assert_eq!(Minutes(59_u32).humanize().to_string(), "about 59 minutes");
+/// assert_eq!(Minutes(90_u32).humanize().to_string(), "about 2 hours");
+/// assert_eq!(Hours(36_u32).humanize().to_string(), "about 2 days");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Humanized {
+    phrase: Phrase,
+}
+
+impl Humanized {
+    /// Construct from a number of whole seconds
+    pub fn from_seconds(seconds: u64) -> Self {
+        Self {
+            phrase: Phrase::from_seconds(seconds),
+        }
+    }
+
+    /// Returns the language-independent phrase
+    pub fn phrase(&self) -> Phrase {
+        self.phrase
+    }
+
+    /// Format using the provided [`Locale`]
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, humanize::*};
+    /// use core::fmt;
+    ///
+    /// struct German;
+    /// impl Locale for German {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>, phrase: Phrase) -> fmt::Result {
+    ///         match phrase {
+    ///             Phrase::About(count, Unit::Minute) => write!(f, "etwa {} Minuten", count),
+    ///             // ...
+    /// #           _ => unimplemented!(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Minutes(5_u32).humanize().with_locale(&German).to_string(), "etwa 5 Minuten");
+    /// ```
+    #[cfg(feature = "locale")]
+    pub fn with_locale<L: Locale>(self, locale: &L) -> Localized<'_, L> {
+        Localized {
+            phrase: self.phrase,
+            locale,
+        }
+    }
+}
+
+impl fmt::Display for Humanized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.phrase.fmt_english(f)
+    }
+}
+
+/// Renders [`Phrase`]s in a particular language
+#[cfg(feature = "locale")]
+pub trait Locale {
+    /// Write the phrase
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, phrase: Phrase) -> fmt::Result;
+}
+
+/// The default (English) [`Locale`]
+#[cfg(feature = "locale")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct English;
+
+#[cfg(feature = "locale")]
+impl Locale for English {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, phrase: Phrase) -> fmt::Result {
+        phrase.fmt_english(f)
+    }
+}
+
+/// A [`Humanized`] duration formatted with a particular [`Locale`]
+#[cfg(feature = "locale")]
+pub struct Localized<'a, L: Locale> {
+    phrase: Phrase,
+    locale: &'a L,
+}
+
+#[cfg(feature = "locale")]
+impl<L: Locale> fmt::Display for Localized<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.locale.fmt(f, self.phrase)
+    }
+}
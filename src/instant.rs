@@ -2,11 +2,14 @@
 
 use crate::{
     duration::{self, Duration},
-    fixed_point::FixedPoint,
+    fixed_point::{self, FixedPoint},
+    fraction::Rounding,
+    ConversionError,
 };
 use core::{
     cmp::Ordering,
     convert::TryFrom,
+    fmt,
     hash::{Hash, Hasher},
     ops,
 };
@@ -45,7 +48,6 @@ use num::traits::{WrappingAdd, WrappingSub};
 /// # }
 /// Instant::<SomeClock>::new(23);
 /// ```
-#[derive(Debug)]
 pub struct Instant<Clock: crate::Clock> {
     ticks: Clock::T,
 }
@@ -89,7 +91,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     pub fn checked_duration_since(&self, other: &Self) -> Option<duration::Generic<Clock::T>> {
         if self >= other {
             Some(duration::Generic::new(
-                self.ticks.wrapping_sub(&other.ticks),
+                self.wrapping_ticks_since(other),
                 Clock::SCALING_FACTOR,
             ))
         } else {
@@ -130,7 +132,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     pub fn checked_duration_until(&self, other: &Self) -> Option<duration::Generic<Clock::T>> {
         if self <= other {
             Some(duration::Generic::new(
-                other.ticks.wrapping_sub(&self.ticks),
+                other.wrapping_ticks_since(self),
                 Clock::SCALING_FACTOR,
             ))
         } else {
@@ -138,6 +140,65 @@ impl<Clock: crate::Clock> Instant<Clock> {
         }
     }
 
+    /// Returns the largest [`Duration`] over which two `Instant`s of this clock can be compared
+    /// (half of the clock's [wrap horizon](crate::Clock::wrap_horizon))
+    ///
+    /// This is also the largest [`Duration`] that can be added to or subtracted from an `Instant`.
+    /// Any timeout longer than this window must be split into shorter waits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Instant};
+    /// # use core::convert::TryFrom;
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let window = Seconds::<u32>::try_from(Instant::<Clock>::valid_comparison_window()).unwrap();
+    /// assert_eq!(window, Seconds(2_147_u32));
+    ///
+    /// // assert at initialization that the longest timeout fits
+    /// assert!(Seconds(30_u32) <= window);
+    /// ```
+    pub fn valid_comparison_window() -> duration::Generic<Clock::T> {
+        duration::Generic::new(
+            *Clock::wrap_horizon().integer() / Clock::T::from(2),
+            Clock::SCALING_FACTOR,
+        )
+    }
+
+    /// Convert a duration to clock ticks, which must be within the
+    /// [valid comparison window](Self::valid_comparison_window)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The duration is longer than the valid comparison window
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The duration
+    ///   could not be converted to clock ticks
+    pub(crate) fn window_ticks<Dur>(duration: Dur) -> Result<Clock::T, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Self::within_window(duration.into_ticks(Clock::SCALING_FACTOR)?)
+    }
+
+    /// Returns the ticks or [`ConversionError::Overflow`] if they are beyond the
+    /// [valid comparison window](Self::valid_comparison_window)
+    pub(crate) fn within_window(ticks: Clock::T) -> Result<Clock::T, ConversionError> {
+        if ticks > *Self::valid_comparison_window().integer() {
+            Err(ConversionError::Overflow)
+        } else {
+            Ok(ticks)
+        }
+    }
+
     /// Returns the [`Duration`] (in the provided units) since the beginning of time (the
     /// [`Clock`](clock/trait.Clock.html)'s 0)
     ///
@@ -146,6 +207,100 @@ impl<Clock: crate::Clock> Instant<Clock> {
         duration::Generic::new(self.ticks, Clock::SCALING_FACTOR)
     }
 
+    /// Translate this instant to another clock, given a simultaneous reading of both clocks
+    ///
+    /// The offset from the `reference` reading of this clock is converted to ticks of the other
+    /// clock (rounded to the nearest tick) and applied to its reading. It must be within the
+    /// [valid comparison window](Self::valid_comparison_window) of both clocks, so the reference
+    /// pair should be refreshed regularly (which also limits the error from the clocks drifting
+    /// apart).
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The offset is beyond the other clock's valid comparison
+    ///   window
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The offset
+    ///   doesn't fit in the other clock's integer type
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Rtc;
+    /// impl embedded_time::Clock for Rtc {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    /// # #[derive(Debug)]
+    /// struct Timer;
+    /// impl embedded_time::Clock for Timer {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// // both clocks read at the same time
+    /// let reference = (Instant::<Rtc>::new(1_000), Instant::<Timer>::new(5_000_000));
+    ///
+    /// // a wake-up time of the RTC one and a half seconds later
+    /// let wake_up = Instant::<Rtc>::new(1_000 + 49_152);
+    /// assert_eq!(wake_up.convert_to(reference), Ok(Instant::<Timer>::new(6_500_000)));
+    /// ```
+    pub fn convert_to<Other: crate::Clock>(
+        self,
+        (reference, other_reference): (Self, Instant<Other>),
+    ) -> Result<Instant<Other>, ConversionError> {
+        let (offset, later) = if self >= reference {
+            (self.wrapping_ticks_since(&reference), true)
+        } else {
+            (reference.wrapping_ticks_since(&self), false)
+        };
+        let ticks: Other::T = fixed_point::scale_rounded(
+            offset,
+            Clock::SCALING_FACTOR,
+            Other::SCALING_FACTOR,
+            Rounding::Nearest,
+        )?;
+        let ticks = Instant::<Other>::within_window(ticks)?;
+
+        Ok(if later {
+            other_reference.wrapping_add_ticks(ticks)
+        } else {
+            other_reference.wrapping_sub_ticks(ticks)
+        })
+    }
+
+    /// The ticks from an earlier `Instant` to this one, modulo the range of the clock's counter
+    pub(crate) fn wrapping_ticks_since(&self, earlier: &Self) -> Clock::T {
+        Self::wrap(self.ticks.wrapping_sub(&earlier.ticks))
+    }
+
+    /// This `Instant` + ticks, modulo the range of the clock's counter
+    pub(crate) fn wrapping_add_ticks(self, ticks: Clock::T) -> Self {
+        Self::new(Self::wrap(self.ticks.wrapping_add(&ticks)))
+    }
+
+    /// This `Instant` - ticks, modulo the range of the clock's counter
+    pub(crate) fn wrapping_sub_ticks(self, ticks: Clock::T) -> Self {
+        Self::new(Self::wrap(self.ticks.wrapping_sub(&ticks)))
+    }
+
+    /// Reduce (wrapping) integer arithmetic to a counter narrower than `Clock::T`
+    ///
+    /// The counter's range is a power of 2, so this is correct for results that wrapped `Clock::T`.
+    fn wrap(ticks: Clock::T) -> Clock::T {
+        let max = *Clock::wrap_horizon().integer();
+        if max == <Clock::T as num::Bounded>::max_value() {
+            ticks
+        } else {
+            ticks % (max + Clock::T::from(1))
+        }
+    }
+
     /// This `Instant` + [`Duration`] = later (future) `Instant`
     ///
     /// Returns [`None`] if the [`Duration`] is too large
@@ -179,10 +334,8 @@ impl<Clock: crate::Clock> Instant<Clock> {
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
         let add_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
-        if add_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
-            Some(Self {
-                ticks: self.ticks.wrapping_add(&add_ticks),
-            })
+        if add_ticks <= *Self::valid_comparison_window().integer() {
+            Some(self.wrapping_add_ticks(add_ticks))
         } else {
             None
         }
@@ -217,16 +370,52 @@ impl<Clock: crate::Clock> Instant<Clock> {
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
         let sub_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
-        if sub_ticks <= (<Clock::T as num::Bounded>::max_value() / 2.into()) {
-            Some(Self {
-                ticks: self.ticks.wrapping_sub(&sub_ticks),
-            })
+        if sub_ticks <= *Self::valid_comparison_window().integer() {
+            Some(self.wrapping_sub_ticks(sub_ticks))
         } else {
             None
         }
     }
 }
 
+impl<Clock: crate::Clock> fmt::Debug for Instant<Clock> {
+    /// Shows the tick count along with the duration of one tick
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, Instant};
+    /// # struct Clock;
+    /// # impl embedded_time::Clock for Clock {
+    /// #     type T = u32;
+    /// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    /// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// # }
+    /// assert_eq!(format!("{:?}", Instant::<Clock>::new(23)), "Instant(23 ticks × 1/1000 s)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Instant({} ticks × {} s)",
+            self.ticks,
+            Clock::SCALING_FACTOR
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<Clock: crate::Clock> defmt::Format for Instant<Clock>
+where
+    Clock::T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "Instant({} ticks × {} s)",
+            self.ticks,
+            Clock::SCALING_FACTOR
+        )
+    }
+}
+
 impl<Clock: crate::Clock> Copy for Instant<Clock> {}
 
 impl<Clock: crate::Clock> Clone for Instant<Clock> {
@@ -272,9 +461,8 @@ where
     Clock::T: ops::Div<Output = Clock::T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.ticks
-            .wrapping_sub(&other.ticks)
-            .cmp(&(<Clock::T as num::Bounded>::max_value() / 2.into()))
+        self.wrapping_ticks_since(other)
+            .cmp(Instant::<Clock>::valid_comparison_window().integer())
             .reverse()
     }
 }
@@ -395,6 +583,123 @@ where
     }
 }
 
+impl<Clock: crate::Clock, Dur: Duration> ops::AddAssign<Dur> for Instant<Clock>
+where
+    Clock::T: TryFrom<Dur::T>,
+    Dur: FixedPoint,
+{
+    /// Move the `Instant` later by a [`Duration`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let mut next_wakeup = Instant::<Clock>::new(u32::MAX);
+    /// next_wakeup += Milliseconds(10_u32);
+    /// assert_eq!(next_wakeup, Instant::<Clock>::new(9));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the duration is more than half the wrap-around period of the clock (see
+    /// [`Instant::checked_add()`])
+    fn add_assign(&mut self, rhs: Dur) {
+        *self = *self + rhs;
+    }
+}
+
+impl<Clock: crate::Clock, Dur: Duration> ops::SubAssign<Dur> for Instant<Clock>
+where
+    Clock::T: TryFrom<Dur::T>,
+    Dur: FixedPoint,
+{
+    /// Move the `Instant` earlier by a [`Duration`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let mut instant = Instant::<Clock>::new(5_000);
+    /// instant -= Seconds(2_u32);
+    /// assert_eq!(instant, Instant::<Clock>::new(3_000));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the duration is more than half the wrap-around period of the clock (see
+    /// [`Instant::checked_sub()`])
+    fn sub_assign(&mut self, rhs: Dur) {
+        *self = *self - rhs;
+    }
+}
+
+impl<Clock: crate::Clock> ops::Sub for Instant<Clock> {
+    type Output = duration::Generic<Clock::T>;
+
+    /// Subtract an earlier `Instant` resulting in the [`duration::Generic`] elapsed between them
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # use core::convert::TryInto;
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let elapsed = Instant::<Clock>::new(5_001) - Instant::<Clock>::new(2_001);
+    /// assert_eq!(elapsed.try_into(), Ok(Seconds(3_u32)));
+    ///
+    /// // across a clock wrap
+    /// let elapsed = Instant::<Clock>::new(1) - Instant::<Clock>::new(u32::MAX);
+    /// assert_eq!(elapsed.try_into(), Ok(Milliseconds(2_u32)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is later than `self` (see [`Instant::checked_duration_since()`]).
+    ///
+    /// ```rust,should_panic
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let _ = Instant::<Clock>::new(2_001) - Instant::<Clock>::new(5_001);
+    /// ```
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_duration_since(&rhs).unwrap()
+    }
+}
+
 impl<Clock: crate::clock::Clock> Hash for Instant<Clock> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         Clock::SCALING_FACTOR.hash(state);
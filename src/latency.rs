@@ -0,0 +1,105 @@
+//! Instrumentation of timer-service latency
+//!
+//! The _lateness_ of a timer is the time between its expiration and the moment it was actually
+//! serviced. Recording the worst-case lateness during system test verifies real-time behavior
+//! without external tooling.
+//!
+//! [`TimerQueue::service()`](crate::queue::TimerQueue::service) records every timer it services
+//! and passes each one's lateness to its handler.
+
+use crate::{duration, Instant};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Receives the expiration and service [`Instant`]s of serviced timers
+///
+/// The `()` implementation discards everything and compiles to nothing, which makes
+/// instrumentation optional at zero cost. With the `alloc` feature, a
+/// `Vec<(Instant<Clock>, Instant<Clock>)>` keeps a trace of every `(expiration, serviced)` pair.
+pub trait Recorder<Clock: crate::Clock> {
+    /// Record that a timer expiring at `expiration` was serviced at `serviced`
+    fn record(&mut self, expiration: Instant<Clock>, serviced: Instant<Clock>);
+}
+
+impl<Clock: crate::Clock> Recorder<Clock> for () {
+    fn record(&mut self, _expiration: Instant<Clock>, _serviced: Instant<Clock>) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<Clock: crate::Clock> Recorder<Clock> for Vec<(Instant<Clock>, Instant<Clock>)> {
+    fn record(&mut self, expiration: Instant<Clock>, serviced: Instant<Clock>) {
+        self.push((expiration, serviced))
+    }
+}
+
+/// Tracks the maximum observed lateness
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, latency::*, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut lateness = MaxLateness::<Clock>::new();
+/// lateness.record(Instant::new(100), Instant::new(103));
+/// lateness.record(Instant::new(200), Instant::new(201));
+///
+/// assert_eq!(Milliseconds::<u32>::try_from(lateness.max()), Ok(Milliseconds(3_u32)));
+/// assert_eq!(lateness.samples(), 2);
+/// ```
+#[derive(Debug)]
+pub struct MaxLateness<Clock: crate::Clock> {
+    max_ticks: Clock::T,
+    samples: u32,
+}
+
+impl<Clock: crate::Clock> MaxLateness<Clock> {
+    /// Construct a new `MaxLateness` with no recorded samples
+    pub fn new() -> Self {
+        Self {
+            max_ticks: Clock::T::from(0),
+            samples: 0,
+        }
+    }
+
+    /// Returns the maximum lateness recorded so far
+    pub fn max(&self) -> duration::Generic<Clock::T> {
+        duration::Generic::new(self.max_ticks, Clock::SCALING_FACTOR)
+    }
+
+    /// Returns the number of recorded samples (saturating at `u32::MAX`)
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    /// Discard all recorded samples
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<Clock: crate::Clock> Default for MaxLateness<Clock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Clock: crate::Clock> Recorder<Clock> for MaxLateness<Clock> {
+    /// A timer serviced before its expiration has a lateness of `0`
+    fn record(&mut self, expiration: Instant<Clock>, serviced: Instant<Clock>) {
+        if let Some(lateness) = serviced.checked_duration_since(&expiration) {
+            if *lateness.integer() > self.max_ticks {
+                self.max_ticks = *lateness.integer();
+            }
+        }
+        self.samples = self.samples.saturating_add(1);
+    }
+}
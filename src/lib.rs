@@ -120,6 +120,9 @@
 //! use embedded_time::Timer;
 //! ```
 //!
+//! Alternatively, [`prelude`] imports (anonymously) just the traits needed for the methods and
+//! extensions of the enabled features.
+//!
 //! # Duration Types
 //!
 //! | Units        | Extension    |
@@ -131,6 +134,17 @@
 //! | Microseconds | microseconds |
 //! | Nanoseconds  | nanoseconds  |
 //!
+//! - Construction from integers with the [`NumericalDuration`](duration::NumericalDuration)
+//!   extensions (eg. `5_u32.seconds()`)
+//! - `u32`/`u64`/`u128`-based type aliases for short signatures (eg. `Milliseconds32`, `Seconds64`)
+//! - `u128`-based durations (and rates) for nanosecond spans beyond the ~584 years of a `u64`; the
+//!   conversion math can't widen past `u128`, so conversions of huge values fail rather than
+//!   overflow
+//! - `u8`/`u16`-based durations (and rates) to save memory on 8/16-bit MCUs
+//! - [`Ticks`](duration::Ticks) durations of any tick period fixed at compile time (eg.
+//!   `Ticks<u32, 1, 32_768>` for a 32.768 kHz RTC)
+//! - `const` construction and conversion (see
+//!   [`Duration`](duration::Duration#const-construction-and-conversion))
 //! - Conversion from `Rate` types
 //! ```rust
 //! use embedded_time::{duration::*, rate::*};
@@ -157,6 +171,7 @@
 //! ## `core` Compatibility
 //!
 //! - Conversion to/from `core::time::Duration`
+//! - Migration aids (see [`compat`]), including deprecated aliases of renamed methods
 //!
 //! ### Benchmark Comparisons to `core` duration type
 //!
@@ -190,10 +205,10 @@
 //! | Units             | Extension |
 //! | :---------------- | :-------- |
 //! | Mebihertz         | MiHz      |
-//! | Megahertz         | MHz       |
+//! | Megahertz         | MHz, mhz  |
 //! | Kibihertz         | KiHz      |
-//! | Kilohertz         | kHz       |
-//! | Hertz             | Hz        |
+//! | Kilohertz         | kHz, khz  |
+//! | Hertz             | Hz, hz    |
 //!
 //! ## Data Rate
 //! | Units             | Extension |
@@ -219,6 +234,8 @@
 //! | Kilobaud          | kBd       |
 //! | Baud              | Bd        |
 //!
+//! - Construction from integers with the [`NumericalRate`](rate::NumericalRate) extensions (eg.
+//!   `8_u32.mhz()`, `115_200_u32.bps()`)
 //! - Conversion from/to all other rate types within the same class (frequency, data rate, etc.) and
 //!   _base_ (mega, mebi, kilo, kibi). For example, MiBps (mebibytes per second) --> Kibps (kibibits
 //!   per second) and MBps (megabytes per second) --> kbps (kilobits per second).
@@ -248,17 +265,123 @@
 //! # );
 //! ```
 //!
+//! # Utilities
+//!
+//! - Sample/block timing for audio and DSP pipelines (see [`audio`])
+//! - Conversion of input-capture register values into durations and rates, and averaged
+//!   period, frequency, and duty-cycle measurement from captured instants (see [`capture`])
+//! - Saturating or wrapping arithmetic operators (see [`overflow`])
+//! - Rates of change of rates for acceleration and ramp limits (see [`ramp`])
+//! - Calculation of timer prescaler and reload values for a desired rate or period (see
+//!   [`prescaler`])
+//! - PWM duty cycles relating on-times, periods, and compare values (see [`duty::DutyCycle`])
+//! - Comparison of durations and rates within a tolerance, and clock accuracies (see [`accuracy`])
+//!
 //! # Hardware Abstraction
 //!
 //! - `Clock` trait allowing abstraction of hardware timers/clocks for timekeeping.
+//! - Counters narrower than the `Clock`'s integer type (eg. 24-bit SysTick) wrap correctly
+//! - Recording and deterministic replay of clock readings (see [`replay`])
+//! - Frequency trimming and temperature compensation of clocks (see [`calibration`])
+//! - Rate and offset correction of clocks synchronized to NTP/PTP/GNSS references (see
+//!   [`discipline`])
+//! - Failover from a primary to a backup clock (see [`failover`])
+//! - Extension of narrow counters to 64 bits by counting rollovers (see [`extended`])
+//! - Interrupt-safe sharing of instants and clocks (see [`shared`], `critical-section` feature)
+//! - Compare-match alarms for interrupt-driven waiting (see [`alarm`])
+//! - On-target conformance checks for `Clock` implementations (see [`conformance`])
+//! - Ready-made clocks over the Cortex-M SysTick and DWT cycle counters (see [`cortex`],
+//!   `cortex-m` feature)
+//! - A `Clock` backed by the host's monotonic clock (see [`system::SystemClock`], `std` feature)
+//! - A `Clock` from just a counter-read function and a `const` frequency (see
+//!   [`tick::TickClock`])
+//! - A manually advanced clock for testing timing logic on the host (see [`mock::MockClock`])
 //!
 //! # Timers
 //!
 //! - Software timers spawned from a `Clock` impl object.
-//! - One-shot or periodic/continuous
+//! - One-shot or periodic/continuous (periods are scheduled without drift)
 //! - Blocking delay
 //! - Poll for expiration
+//! - Restart from the current instant
+//! - Poll a non-blocking operation with a timeout (see [`Clock::timeout()`])
 //! - Read elapsed/remaining duration
+//! - Delays longer than the clock's valid comparison window (see [`delay::LongDelay`])
+//! - Worst-case timer-service latency instrumentation (see [`latency`])
+//! - Time budgets for cooperatively-scheduled, long-running operations (see
+//!   [`timeslice::Timeslice`])
+//! - Expiring cached values (see [`ttl::Ttl`])
+//! - Absolute deadlines to pass through layered driver calls (see [`deadline::Deadline`])
+//! - Queues of pending timeouts for cooperative schedulers and async executors (see
+//!   [`queue::TimerQueue`])
+//! - Timing wheels with _O(1)_ insertion and expiry for large numbers of timeouts (see
+//!   [`wheel::TimerWheel`])
+//! - Profiling of code sections, with laps and pauses (see [`stopwatch::Stopwatch`])
+//! - Rate limiting of events, in the crate's rate types (see [`throttle::Throttle`])
+//! - Exponential backoff of retries, with a jitter hook (see [`backoff::Backoff`])
+//! - Debouncing of buttons and switches (see [`debounce::Debouncer`])
+//! - Watchdog feeding with margin for the clocks' tolerances (see
+//!   [`watchdog::WatchdogScheduler`])
+//!
+//! # Persistence
+//!
+//! - Versioned, stable encoding of `Instant`s and `Duration`s (see [`persist`]) so they can survive
+//!   a reboot or firmware update
+//! - Boot-session tagging of `Instant`s (see [`boot`]) so timestamps from a previous session are
+//!   not mistaken for current ones
+//!
+//! # Crate Features
+//!
+//! Subsystems can be disabled (`default-features = false`) to reduce compile time and flash usage.
+//! Enabled by default:
+//!
+//! - `duration`: duration types
+//! - `rate`: rate types
+//! - `clock`: the `Clock` trait, `Instant`, and the clock adapters (implies `duration`)
+//! - `timers`: software timers and delays (implies `clock`)
+//!
+//! # Optional Features
+//!
+//! - `wallclock`: wall-clock timestamps relative to the Unix epoch (see [`wallclock`], implies
+//!   `clock`), the NTP timestamp formats (see [`ntp`]), and PTP timestamps (see [`ptp`])
+//! - `chrono`: conversions between [`wallclock::Timestamp`] and `chrono::NaiveDateTime` (implies
+//!   `wallclock`)
+//! - `locale`: localization hooks for [humanized](humanize) durations
+//! - `async`: executor-agnostic futures for delays and periodic ticks, woken by a `Clock`'s alarm
+//!   (see [`asynch`], implies `timers`)
+//! - `std`: a `Clock` backed by [`std::time::Instant`] for integration tests and host simulations
+//!   (see [`system::SystemClock`], implies `clock`)
+//! - `cortex-m`: `Clock`s over the Cortex-M SysTick and DWT cycle counters (see [`cortex`],
+//!   implies `clock` and `rate`)
+//! - `fugit`: conversions to and from the [`fugit`](::fugit) crate's durations, rates, and
+//!   instants (see [`fugit`](mod@fugit), implies `clock` and `rate`)
+//! - `alloc`: a `Vec`-backed timer queue without a capacity limit ([`queue::VecTimerQueue`]),
+//!   implementing the same [`queue::TimeoutQueue`] trait as the fixed-capacity
+//!   [`queue::TimerQueue`], and `Vec`s as unbounded traces of serviced timers
+//!   ([`latency::Recorder`]) and clock readings ([`replay::Sink`])
+//! - `embedded-hal`: [`embedded_hal::timer::CountDown`] helpers for this crate's durations and a
+//!   software `CountDown` driven by a `Clock` (see [`hal`], implies `timers`)
+//! - `defmt`: `defmt::Format` for the duration and rate types, `Fraction`, `Instant`, and the error
+//!   types, for efficient logging (eg. over RTT)
+//! - `serde`: `Serialize`/`Deserialize` for the duration and rate types (as their raw integer, eg.
+//!   `Milliseconds(5_u32)` as `5`), the `Generic` types, and `Fraction` (as a `(numerator,
+//!   denominator)` tuple)
+//! - `critical-section`: interrupt-safe shared instants and clocks (see [`shared`], implies
+//!   `clock`), guarded by the [`critical-section`](critical_section) crate
+//!
+//! # Toolchain Compatibility
+//!
+//! The minimum supported Rust version (MSRV) is **1.62** (the `rust-version` in `Cargo.toml`,
+//! checked in CI). Raising it is a breaking change.
+//!
+//! Const generics are used unconditionally (eg. by [`duration::Ticks`], [`tick::TickClock`], and
+//! [`queue::TimerQueue`]), so there are no fallbacks for compilers older than the MSRV. Projects
+//! pinned to such (eg. vendor-qualified) compilers must use a release of this crate supporting
+//! them.
+//!
+//! The MSRV covers this crate's own code. Recent releases of some optional dependencies (eg.
+//! `defmt`, `serde`) require newer compilers; pin older releases of them in `Cargo.lock` when
+//! building with the MSRV.
 //!
 //! # Reliability and Usability
 //! - Extensive tests
@@ -276,22 +399,118 @@
 #![warn(missing_docs)]
 #![deny(intra_doc_link_resolution_failure)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod accuracy;
+#[cfg(feature = "clock")]
+pub mod alarm;
+#[cfg(feature = "async")]
+pub mod asynch;
+#[cfg(all(feature = "duration", feature = "rate"))]
+pub mod audio;
+#[cfg(feature = "duration")]
+pub mod backoff;
+#[cfg(feature = "clock")]
+pub mod boot;
+#[cfg(feature = "clock")]
+pub mod calibration;
+#[cfg(all(feature = "duration", feature = "rate"))]
+pub mod capture;
+#[cfg(feature = "clock")]
 pub mod clock;
+#[cfg(feature = "duration")]
+pub mod compat;
+#[cfg(feature = "timers")]
+pub mod conformance;
+#[cfg(feature = "cortex-m")]
+pub mod cortex;
+#[cfg(feature = "clock")]
+pub mod deadline;
+#[cfg(feature = "clock")]
+pub mod debounce;
+#[cfg(feature = "timers")]
+pub mod delay;
+#[cfg(feature = "clock")]
+pub mod discipline;
+#[cfg(feature = "duration")]
 pub mod duration;
+#[cfg(all(feature = "duration", feature = "rate"))]
+pub mod duty;
+#[cfg(feature = "clock")]
+pub mod extended;
+#[cfg(feature = "clock")]
+pub mod failover;
 pub mod fixed_point;
 pub mod fraction;
+#[cfg(feature = "fugit")]
+pub mod fugit;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+#[cfg(feature = "duration")]
+pub mod humanize;
+#[cfg(feature = "clock")]
 mod instant;
+#[cfg(feature = "timers")]
+pub mod latency;
+#[cfg(feature = "clock")]
+pub mod mock;
+#[cfg(feature = "wallclock")]
+pub mod ntp;
+#[cfg(feature = "duration")]
+pub mod overflow;
+#[cfg(feature = "duration")]
+pub mod persist;
+pub mod prelude;
+#[cfg(all(feature = "duration", feature = "rate"))]
+pub mod prescaler;
+#[cfg(feature = "wallclock")]
+pub mod ptp;
+#[cfg(feature = "clock")]
+pub mod queue;
+#[cfg(all(feature = "duration", feature = "rate"))]
+pub mod ramp;
+#[cfg(feature = "rate")]
 pub mod rate;
+#[cfg(feature = "clock")]
+pub mod replay;
+#[cfg(feature = "critical-section")]
+pub mod shared;
+#[cfg(feature = "clock")]
+pub mod stopwatch;
+#[cfg(feature = "std")]
+pub mod system;
+#[cfg(all(feature = "clock", feature = "rate"))]
+pub mod throttle;
+#[cfg(feature = "clock")]
+pub mod tick;
 mod time_int;
+#[cfg(feature = "timers")]
 mod timer;
+#[cfg(feature = "clock")]
+pub mod timeslice;
+#[cfg(feature = "clock")]
+pub mod ttl;
+#[cfg(feature = "wallclock")]
+pub mod wallclock;
+#[cfg(feature = "clock")]
+pub mod watchdog;
+#[cfg(feature = "clock")]
+pub mod wheel;
 
+#[cfg(feature = "clock")]
 pub use clock::Clock;
+#[cfg(feature = "clock")]
 pub use instant::Instant;
-pub use timer::Timer;
+#[cfg(feature = "timers")]
+pub use timer::{CatchUp, Timer, TimerBuilder};
 
 /// Crate errors
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TimeError {
     /// Exact cause of failure is unknown
     Unspecified,
@@ -303,10 +522,14 @@ pub enum TimeError {
     DivByZero,
     /// Resulting [`Duration`](duration/trait.Duration.html) is negative (not allowed)
     NegDuration,
+    /// The conversion would discard a non-zero remainder
+    LossOfPrecision,
     /// [`Clock`]-implementation-specific error
+    #[cfg(feature = "clock")]
     Clock(clock::Error),
 }
 
+#[cfg(feature = "clock")]
 impl From<clock::Error> for TimeError {
     fn from(clock_error: clock::Error) -> Self {
         TimeError::Clock(clock_error)
@@ -319,9 +542,38 @@ impl Default for TimeError {
     }
 }
 
+impl core::fmt::Display for TimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            TimeError::Unspecified => "operation failed",
+            TimeError::ConversionFailure => "value doesn't fit in the destination type",
+            TimeError::Overflow => "value overflowed",
+            TimeError::DivByZero => "division by zero",
+            TimeError::NegDuration => "negative duration",
+            TimeError::LossOfPrecision => "conversion would discard a remainder",
+            #[cfg(feature = "clock")]
+            TimeError::Clock(error) => return write!(f, "clock error: {}", error),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimeError::Clock(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
 /// Conversion errors
+///
+/// Returned by all fallible conversions between integer types and _scaling factors_ (rather than
+/// panicking or silently truncating).
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ConversionError {
     /// Exact cause of failure is unknown
     Unspecified,
@@ -333,6 +585,8 @@ pub enum ConversionError {
     DivByZero,
     /// Resulting [`Duration`](duration/trait.Duration.html) is negative (not allowed)
     NegDuration,
+    /// The conversion would discard a non-zero remainder
+    LossOfPrecision,
 }
 
 impl From<ConversionError> for TimeError {
@@ -343,6 +597,7 @@ impl From<ConversionError> for TimeError {
             ConversionError::Overflow => TimeError::Overflow,
             ConversionError::DivByZero => TimeError::DivByZero,
             ConversionError::NegDuration => TimeError::NegDuration,
+            ConversionError::LossOfPrecision => TimeError::LossOfPrecision,
         }
     }
 }
@@ -353,5 +608,21 @@ impl Default for ConversionError {
     }
 }
 
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ConversionError::Unspecified => "conversion failed",
+            ConversionError::ConversionFailure => "value doesn't fit in the destination type",
+            ConversionError::Overflow => "value overflowed",
+            ConversionError::DivByZero => "division by zero",
+            ConversionError::NegDuration => "negative duration",
+            ConversionError::LossOfPrecision => "conversion would discard a remainder",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
 #[cfg(test)]
 mod tests {}
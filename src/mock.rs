@@ -0,0 +1,121 @@
+//! A manually-advanced clock for host-side tests
+//!
+//! Timeout, scheduling, and other timing logic written against the [`Clock`](crate::Clock) trait
+//! can be unit-tested deterministically (without hardware) by running it on a [`MockClock`] and
+//! advancing time explicitly.
+
+use crate::{clock, duration::Duration, fixed_point::FixedPoint, ConversionError, Instant};
+use core::{cell::Cell, convert::TryFrom, fmt, marker::PhantomData};
+
+/// A [`Clock`](crate::Clock) whose time only changes when advanced or set
+///
+/// It has the tick type, _scaling factor_, and counter width of the `Clock` it mocks, and wraps
+/// around like it. Time is changed through a shared reference, so the clock can be lent to the
+/// code under test meanwhile.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, mock::MockClock, Clock as _, Instant};
+///
+/// # #[derive(Debug)]
+/// struct SysTick;
+/// impl embedded_time::Clock for SysTick {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let clock = MockClock::<SysTick>::new();
+/// let timer = clock.new_timer(Milliseconds(10_u32)).start().unwrap();
+///
+/// clock.advance(Milliseconds(9_u32)).unwrap();
+/// assert_eq!(timer.is_expired(), Ok(false));
+///
+/// clock.advance(Milliseconds(1_u32)).unwrap();
+/// assert_eq!(timer.is_expired(), Ok(true));
+///
+/// clock.set(Instant::new(0));
+/// assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+/// ```
+pub struct MockClock<Clock: crate::Clock> {
+    ticks: Cell<Clock::T>,
+    running: Cell<bool>,
+    _clock: PhantomData<Clock>,
+}
+
+impl<Clock: crate::Clock> MockClock<Clock> {
+    /// Construct a running clock at tick `0`
+    pub fn new() -> Self {
+        Self {
+            ticks: Cell::new(Clock::T::from(0)),
+            running: Cell::new(true),
+            _clock: PhantomData,
+        }
+    }
+
+    /// Set the current time
+    pub fn set(&self, now: Instant<Self>) {
+        self.ticks.set(*now.duration_since_epoch().integer());
+    }
+
+    /// Advance the current time by the given number of ticks
+    pub fn advance_ticks(&self, ticks: Clock::T) {
+        let now = Instant::<Self>::new(self.ticks.get()).wrapping_add_ticks(ticks);
+        self.set(now);
+    }
+
+    /// Advance the current time by the given duration (truncated to whole ticks)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The duration
+    ///   could not be converted to clock ticks (the time is unchanged)
+    pub fn advance<Dur>(&self, duration: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let ticks = *duration.to_generic(Clock::SCALING_FACTOR)?.integer();
+        self.advance_ticks(ticks);
+
+        Ok(())
+    }
+
+    /// Start or stop the clock
+    ///
+    /// While stopped, reading the clock fails with [`clock::Error::NotRunning`].
+    pub fn set_running(&self, running: bool) {
+        self.running.set(running);
+    }
+}
+
+impl<Clock: crate::Clock> Default for MockClock<Clock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Clock: crate::Clock> fmt::Debug for MockClock<Clock> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClock")
+            .field("ticks", &self.ticks.get())
+            .field("running", &self.running.get())
+            .finish()
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for MockClock<Clock> {
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        if !self.running.get() {
+            return Err(clock::Error::NotRunning);
+        }
+
+        Ok(Instant::new(self.ticks.get()))
+    }
+}
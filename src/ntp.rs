@@ -0,0 +1,179 @@
+//! NTP timestamp formats (RFC 5905)
+//!
+//! - [`NtpTimestamp`]: the 64-bit format (32.32 fixed-point seconds since 1900-01-01 00:00:00
+//!   UTC) of the transmit/receive timestamps in NTP and SNTP packets, converted to and from
+//!   [`Timestamp`]s
+//! - [`NtpShort`]: the 32-bit short format (16.16 fixed-point seconds) of the root delay and
+//!   dispersion fields, converted to and from durations
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::convert::TryFrom;
+//! use embedded_time::{duration::*, ntp::*, wallclock::Timestamp};
+//!
+//! // from the transmit timestamp of a server reply
+//! let transmit = NtpTimestamp::from_be_bytes([0xE3, 0x08, 0x8E, 0x80, 0x80, 0x00, 0x00, 0x00]);
+//! assert_eq!(
+//!     Timestamp::try_from(transmit),
+//!     Ok(Timestamp::new(1_600_000_000, 500_000_000).unwrap())
+//! );
+//!
+//! let root_delay = NtpShort::from_be_bytes([0x00, 0x00, 0x80, 0x00]);
+//! assert_eq!(root_delay.to_duration(), Ok(Milliseconds(500_u32)));
+//! assert_eq!(NtpShort::from_duration(Milliseconds(500_u32)), Ok(root_delay));
+//! ```
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, time_int::TimeInt, wallclock::Timestamp,
+    ConversionError,
+};
+use core::convert::TryFrom;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+/// The seconds from 1900-01-01 (the NTP prime epoch) to 1970-01-01 (the Unix epoch)
+const UNIX_EPOCH: u64 = 2_208_988_800;
+/// The NTP seconds of the first timestamp taken as era 0 (1968-01-20 03:14:08 UTC)
+const ERA_PIVOT: u32 = 1 << 31;
+
+/// A 64-bit NTP timestamp: 32.32 fixed-point seconds since 1900-01-01 00:00:00 UTC
+///
+/// The seconds wrap every 136 years (the first time on 2036-02-07). Following RFC 4330, values
+/// with the most significant bit set are taken as era 0 (1968-2036) and the others as era 1
+/// (2036-2104), so timestamps convert correctly on both sides of the first rollover.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct NtpTimestamp(pub u64);
+
+impl NtpTimestamp {
+    /// Construct from the seconds and fraction (units of 2⁻³² s) fields
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        Self(((seconds as u64) << 32) | fraction as u64)
+    }
+
+    /// Construct from the (big-endian) wire format
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the (big-endian) wire format
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the seconds field
+    pub const fn seconds(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns the fraction field (units of 2⁻³² s)
+    pub const fn fraction(&self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl TryFrom<Timestamp> for NtpTimestamp {
+    type Error = ConversionError;
+
+    /// Convert a timestamp (the fraction is truncated)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The timestamp is beyond era 1 (2104-02-26)
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let seconds = timestamp
+            .unix_seconds()
+            .checked_add(UNIX_EPOCH)
+            .filter(|seconds| *seconds < (1 << 32) + u64::from(ERA_PIVOT))
+            .ok_or(ConversionError::Overflow)?;
+        let fraction = (u128::from(timestamp.subsec_nanoseconds()) << 32) / NANOS_PER_SECOND;
+
+        Ok(Self::new(seconds as u32, fraction as u32))
+    }
+}
+
+impl TryFrom<NtpTimestamp> for Timestamp {
+    type Error = ConversionError;
+
+    /// Convert an NTP timestamp (truncated to nanoseconds)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`]: The NTP timestamp precedes the Unix epoch
+    fn try_from(timestamp: NtpTimestamp) -> Result<Self, Self::Error> {
+        let seconds = if timestamp.seconds() >= ERA_PIVOT {
+            u64::from(timestamp.seconds())
+        } else {
+            u64::from(timestamp.seconds()) + (1 << 32)
+        };
+        let seconds = seconds
+            .checked_sub(UNIX_EPOCH)
+            .ok_or(ConversionError::ConversionFailure)?;
+        let nanoseconds = (u128::from(timestamp.fraction()) * NANOS_PER_SECOND) >> 32;
+
+        Self::new(seconds, nanoseconds as u32)
+    }
+}
+
+/// A 32-bit NTP short-format duration: 16.16 fixed-point seconds
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct NtpShort(pub u32);
+
+impl NtpShort {
+    /// Construct from the seconds and fraction (units of 2⁻¹⁶ s) fields
+    pub const fn new(seconds: u16, fraction: u16) -> Self {
+        Self(((seconds as u32) << 16) | fraction as u32)
+    }
+
+    /// Construct from the (big-endian) wire format
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Self {
+        Self(u32::from_be_bytes(bytes))
+    }
+
+    /// Returns the (big-endian) wire format
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Construct from a duration (truncated to 2⁻¹⁶ s)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The duration is 65,536 seconds or longer
+    pub fn from_duration<Dur>(duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        // duration × scaling factor × 2¹⁶
+        duration
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()) << 16)
+            .map(|integer| integer / u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .and_then(u32::try_from_u128)
+            .map(Self)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the duration (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The _scaling factor_ of the destination type is `0`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn to_duration<Dur>(&self) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        // value / 2¹⁶ / scaling factor
+        let denominator = u128::from(*Dur::SCALING_FACTOR.numerator()) << 16;
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        let integer =
+            u128::from(self.0) * u128::from(*Dur::SCALING_FACTOR.denominator()) / denominator;
+
+        Dur::T::try_from_u128(integer)
+            .map(Dur::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+}
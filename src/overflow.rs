@@ -0,0 +1,147 @@
+//! Overflow-policy wrappers for fixed-point arithmetic
+//!
+//! Like [`core::num::Wrapping`], the wrappers select the overflow behavior of the arithmetic
+//! operators for a whole block of calculations, instead of calling a method variant (eg.
+//! [`saturating_add()`](FixedPoint::saturating_add)) for each operation.
+//!
+//! ```rust
+//! use embedded_time::{duration::*, overflow::*};
+//!
+//! let timeout = Saturating(Milliseconds(u32::MAX - 10)) + Seconds(1_u32) - Milliseconds(5_u32);
+//! assert_eq!(timeout, Saturating(Milliseconds(u32::MAX - 5)));
+//!
+//! let ticks = Wrapping(Milliseconds(u32::MAX)) + Milliseconds(2_u32);
+//! assert_eq!(ticks.0, Milliseconds(1_u32));
+//! ```
+
+use crate::fixed_point::FixedPoint;
+use core::{convert::TryFrom, ops};
+use num::traits::{WrappingAdd, WrappingMul, WrappingSub};
+
+/// Arithmetic operators saturate at the bounds of the wrapped type
+///
+/// A RHS that can't be converted to the LHS type (ie. is too large) also saturates the result.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Saturating<T>(pub T);
+
+/// Arithmetic operators wrap around at the bounds of the wrapped type
+///
+/// The RHS must be of the same type as the LHS.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Wrapping<T>(pub T);
+
+impl<T: FixedPoint, Rhs: FixedPoint> ops::Add<Rhs> for Saturating<T>
+where
+    T: TryFrom<Rhs>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        Self(self.0.saturating_add(&rhs))
+    }
+}
+
+impl<T: FixedPoint, Rhs: FixedPoint> ops::Add<Saturating<Rhs>> for Saturating<T>
+where
+    T: TryFrom<Rhs>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Saturating<Rhs>) -> Self::Output {
+        self + rhs.0
+    }
+}
+
+impl<T: FixedPoint, Rhs: FixedPoint> ops::Sub<Rhs> for Saturating<T>
+where
+    T: TryFrom<Rhs>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Rhs) -> Self::Output {
+        Self(self.0.saturating_sub(&rhs))
+    }
+}
+
+impl<T: FixedPoint, Rhs: FixedPoint> ops::Sub<Saturating<Rhs>> for Saturating<T>
+where
+    T: TryFrom<Rhs>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Saturating<Rhs>) -> Self::Output {
+        self - rhs.0
+    }
+}
+
+impl<T: FixedPoint> ops::Mul<T::T> for Saturating<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T::T) -> Self::Output {
+        Self(self.0.saturating_mul(&rhs))
+    }
+}
+
+impl<T: FixedPoint> ops::Add<T> for Wrapping<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self(T::new(self.0.integer().wrapping_add(rhs.integer())))
+    }
+}
+
+impl<T: FixedPoint> ops::Add for Wrapping<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self + rhs.0
+    }
+}
+
+impl<T: FixedPoint> ops::Sub<T> for Wrapping<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Self(T::new(self.0.integer().wrapping_sub(rhs.integer())))
+    }
+}
+
+impl<T: FixedPoint> ops::Sub for Wrapping<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - rhs.0
+    }
+}
+
+impl<T: FixedPoint> ops::Mul<T::T> for Wrapping<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T::T) -> Self::Output {
+        Self(T::new(self.0.integer().wrapping_mul(&rhs)))
+    }
+}
+
+macro_rules! impl_assign {
+    ($wrapper:ident, $assign:ident, $method:ident, $op:ident, $op_method:ident, $rhs:ty) => {
+        impl<T: FixedPoint> ops::$assign<$rhs> for $wrapper<T>
+        where
+            Self: ops::$op<$rhs, Output = Self>,
+        {
+            fn $method(&mut self, rhs: $rhs) {
+                *self = ops::$op::$op_method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_assign![Saturating, AddAssign, add_assign, Add, add, T];
+impl_assign![Saturating, AddAssign, add_assign, Add, add, Self];
+impl_assign![Saturating, SubAssign, sub_assign, Sub, sub, T];
+impl_assign![Saturating, SubAssign, sub_assign, Sub, sub, Self];
+impl_assign![Saturating, MulAssign, mul_assign, Mul, mul, T::T];
+impl_assign![Wrapping, AddAssign, add_assign, Add, add, T];
+impl_assign![Wrapping, AddAssign, add_assign, Add, add, Self];
+impl_assign![Wrapping, SubAssign, sub_assign, Sub, sub, T];
+impl_assign![Wrapping, SubAssign, sub_assign, Sub, sub, Self];
+impl_assign![Wrapping, MulAssign, mul_assign, Mul, mul, T::T];
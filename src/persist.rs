@@ -0,0 +1,170 @@
+//! Versioned, stable encoding of time values for persistent storage
+//!
+//! An encoded value can be written to flash, EEPROM, or backup RAM and restored after a reboot or
+//! a firmware update. The encoding is fixed at [`ENCODED_LEN`] bytes:
+//!
+//! | Bytes   | Content                                         |
+//! | :------ | :---------------------------------------------- |
+//! | 0..2    | [`MAGIC`]                                       |
+//! | 2       | [`VERSION`]                                     |
+//! | 3       | kind (`Instant` or `Duration`)                  |
+//! | 4..8    | _scaling factor_ numerator (little-endian)      |
+//! | 8..12   | _scaling factor_ denominator (little-endian)    |
+//! | 12..20  | _integer_/tick count as a `u64` (little-endian) |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use embedded_time::{duration::*, persist::{self, Persist}};
+//!
+//! let bytes = Milliseconds(1_500_u32).persist();
+//!
+//! assert_eq!(Milliseconds::<u32>::restore(&bytes), Ok(Milliseconds(1_500_u32)));
+//!
+//! // restoring into a type with a different _scaling factor_ is an error
+//! assert_eq!(
+//!     Seconds::<u32>::restore(&bytes),
+//!     Err(persist::Error::IncompatibleScalingFactor)
+//! );
+//! ```
+
+#[cfg(feature = "clock")]
+use crate::Instant;
+use crate::{duration::Duration, fixed_point::FixedPoint, fraction::Fraction};
+use core::convert::TryFrom;
+
+/// Identifies an encoded time value
+pub const MAGIC: [u8; 2] = *b"ET";
+
+/// The version of the encoding produced by [`Persist::persist()`]
+pub const VERSION: u8 = 1;
+
+/// The length (in bytes) of an encoded value
+pub const ENCODED_LEN: usize = 20;
+
+#[cfg(feature = "clock")]
+const KIND_INSTANT: u8 = 0;
+const KIND_DURATION: u8 = 1;
+
+/// Potential restoration errors
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// Exact cause of failure is unknown
+    #[default]
+    Unspecified,
+    /// Fewer than [`ENCODED_LEN`] bytes were provided
+    Truncated,
+    /// The bytes don't start with [`MAGIC`]
+    BadMagic,
+    /// The encoding version is not supported by this version of the crate
+    UnsupportedVersion,
+    /// The bytes encode a different kind of value (eg. an `Instant` rather than a `Duration`)
+    WrongKind,
+    /// The stored _scaling factor_ (tick rate) differs from that of the destination type
+    IncompatibleScalingFactor,
+    /// The stored count doesn't fit in the destination _integer_ type
+    Overflow,
+}
+
+/// Conversion to/from the versioned persistent encoding
+pub trait Persist: Sized {
+    /// Encode the value
+    fn persist(&self) -> [u8; ENCODED_LEN];
+
+    /// Decode a value previously encoded with [`Persist::persist()`]
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Truncated`]
+    /// - [`Error::BadMagic`]
+    /// - [`Error::UnsupportedVersion`]
+    /// - [`Error::WrongKind`]
+    /// - [`Error::IncompatibleScalingFactor`]
+    /// - [`Error::Overflow`]
+    fn restore(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+fn encode(kind: u8, scaling_factor: Fraction, count: u64) -> [u8; ENCODED_LEN] {
+    let mut bytes = [0; ENCODED_LEN];
+    bytes[0..2].copy_from_slice(&MAGIC);
+    bytes[2] = VERSION;
+    bytes[3] = kind;
+    bytes[4..8].copy_from_slice(&scaling_factor.numerator().to_le_bytes());
+    bytes[8..12].copy_from_slice(&scaling_factor.denominator().to_le_bytes());
+    bytes[12..20].copy_from_slice(&count.to_le_bytes());
+    bytes
+}
+
+fn decode(bytes: &[u8], kind: u8, scaling_factor: Fraction) -> Result<u64, Error> {
+    if bytes.len() < ENCODED_LEN {
+        return Err(Error::Truncated);
+    }
+    if bytes[0..2] != MAGIC {
+        return Err(Error::BadMagic);
+    }
+    if bytes[2] != VERSION {
+        return Err(Error::UnsupportedVersion);
+    }
+    if bytes[3] != kind {
+        return Err(Error::WrongKind);
+    }
+
+    let mut word = [0; 4];
+    word.copy_from_slice(&bytes[4..8]);
+    let numerator = u32::from_le_bytes(word);
+    word.copy_from_slice(&bytes[8..12]);
+    let denominator = u32::from_le_bytes(word);
+    // equal in value (eg. `2/65_536` and `1/32_768`)
+    if denominator == 0 || Fraction::new(numerator, denominator) != scaling_factor {
+        return Err(Error::IncompatibleScalingFactor);
+    }
+
+    let mut count = [0; 8];
+    count.copy_from_slice(&bytes[12..20]);
+    Ok(u64::from_le_bytes(count))
+}
+
+#[cfg(feature = "clock")]
+impl<Clock: crate::Clock> Persist for Instant<Clock>
+where
+    u64: From<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// The tick count as well as the _scaling factor_ of the [`Clock`](crate::Clock) are stored
+    fn persist(&self) -> [u8; ENCODED_LEN] {
+        encode(
+            KIND_INSTANT,
+            Clock::SCALING_FACTOR,
+            (*self.duration_since_epoch().integer()).into(),
+        )
+    }
+
+    /// The stored _scaling factor_ must match that of the [`Clock`](crate::Clock)
+    fn restore(bytes: &[u8]) -> Result<Self, Error> {
+        let ticks = decode(bytes, KIND_INSTANT, Clock::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Clock::T::try_from(ticks).map_err(|_| Error::Overflow)?,
+        ))
+    }
+}
+
+impl<Dur: Duration + FixedPoint> Persist for Dur
+where
+    u64: From<Dur::T>,
+    Dur::T: TryFrom<u64>,
+{
+    /// The _integer_ as well as the _scaling factor_ of the duration are stored
+    fn persist(&self) -> [u8; ENCODED_LEN] {
+        encode(KIND_DURATION, Dur::SCALING_FACTOR, (*self.integer()).into())
+    }
+
+    /// The stored _scaling factor_ must match that of the duration type
+    fn restore(bytes: &[u8]) -> Result<Self, Error> {
+        let integer = decode(bytes, KIND_DURATION, Dur::SCALING_FACTOR)?;
+        Ok(Self::new(
+            Dur::T::try_from(integer).map_err(|_| Error::Overflow)?,
+        ))
+    }
+}
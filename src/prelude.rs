@@ -0,0 +1,26 @@
+//! Traits needed for the crate's methods and extensions, adapting to the enabled features
+//!
+//! The traits are imported anonymously so that they don't collide with other names in scope (eg.
+//! `core::time::Duration`).
+//!
+//! ```rust
+//! use embedded_time::{duration::Milliseconds, prelude::*, rate::Hertz};
+//!
+//! assert_eq!(500_u32.milliseconds().to_rate(), Ok(Hertz(2_u32)));
+//! assert_eq!(Milliseconds(1_000_u32).integer(), &1_000);
+//! ```
+
+#[doc(no_inline)]
+pub use crate::fixed_point::FixedPoint as _;
+
+#[cfg(feature = "duration")]
+#[doc(no_inline)]
+pub use crate::duration::{units::Extensions as _, Duration as _};
+
+#[cfg(feature = "rate")]
+#[doc(no_inline)]
+pub use crate::rate::{units::Extensions as _, Rate as _};
+
+#[cfg(feature = "clock")]
+#[doc(no_inline)]
+pub use crate::Clock as _;
@@ -0,0 +1,248 @@
+//! Timer prescaler and reload calculation
+//!
+//! Hardware timers (and UART/PWM peripherals) divide their input clock by a _prescaler_ and then
+//! by a _reload_ (period, top, or baud divisor) value. [`Prescaler`] finds the pair of dividers
+//! closest to a desired output rate or period, reporting the rate actually achieved.
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, rate::Rate, time_int::TimeInt, ConversionError,
+};
+use core::convert::TryFrom;
+use num::integer::gcd;
+
+/// A (prescaler, reload) pair of dividers and its error from the desired output
+///
+/// Both dividers are the actual division factors (`1` divides by one). Registers often take the
+/// factor minus one.
+///
+/// Among equally accurate pairs, the one with the smallest prescaler (so the finest counter
+/// resolution) is chosen.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, prescaler::Prescaler, rate::*};
+///
+/// // a 16-bit timer clocked at 72 MHz
+/// let dividers = Prescaler::for_rate(Megahertz(72_u32), Hertz(1_u32), 65_536, 65_536).unwrap();
+///
+/// assert_eq!(dividers.prescaler() as u64 * dividers.reload() as u64, 72_000_000);
+/// assert_eq!(dividers.error_ppm(), 0);
+/// assert_eq!(dividers.achieved_rate::<Hertz>(), Ok(Hertz(1_u32)));
+///
+/// // an 8-bit UART baud divisor
+/// let dividers = Prescaler::for_rate(Megahertz(16_u32), Hertz(115_200_u32), 1, 256).unwrap();
+///
+/// assert_eq!(dividers.reload(), 139);
+/// assert_eq!(dividers.error_ppm(), -799);
+/// assert_eq!(dividers.achieved_rate::<Hertz>(), Ok(Hertz(115_107_u32)));
+///
+/// let dividers =
+///     Prescaler::for_period(Megahertz(8_u32), Milliseconds(20_u32), 256, 65_536).unwrap();
+///
+/// assert_eq!(dividers.prescaler() as u64 * dividers.reload() as u64, 160_000);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Prescaler {
+    prescaler: u32,
+    reload: u32,
+    /// The input rate (Hz)
+    input: (u64, u64),
+    /// The achieved rate's error in parts per billion
+    error_ppb: i64,
+}
+
+impl Prescaler {
+    /// Find the dividers of the input rate closest to the output rate
+    ///
+    /// The prescaler is searched from `1` to `max_prescaler`, the reload from `1` to `max_reload`
+    /// (eg. `65_536` for a 16-bit counter).
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: A rate, maximum, or _scaling factor_ is `0`
+    /// - [`ConversionError::Overflow`]: The ratio of the rates can't be represented
+    pub fn for_rate<In, Out>(
+        input: In,
+        output: Out,
+        max_prescaler: u32,
+        max_reload: u32,
+    ) -> Result<Self, ConversionError>
+    where
+        In: Rate + FixedPoint,
+        Out: Rate + FixedPoint,
+    {
+        // input / output
+        let input = hertz(input)?;
+        let output = hertz(output)?;
+        let ratio = reduce(
+            u128::from(input.0) * u128::from(output.1),
+            u128::from(input.1) * u128::from(output.0),
+        )?;
+
+        Self::search(input, ratio, max_prescaler, max_reload)
+    }
+
+    /// Find the dividers of the input rate closest to the output period
+    ///
+    /// See [`Prescaler::for_rate()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The rate, the period, a maximum, or a _scaling factor_ is
+    ///   `0`
+    /// - [`ConversionError::Overflow`]: The number of input cycles in the period can't be
+    ///   represented
+    pub fn for_period<In, Dur>(
+        input: In,
+        period: Dur,
+        max_prescaler: u32,
+        max_reload: u32,
+    ) -> Result<Self, ConversionError>
+    where
+        In: Rate + FixedPoint,
+        Dur: Duration + FixedPoint,
+    {
+        // input × period
+        let input = hertz(input)?;
+        let ratio = reduce(
+            u128::from(input.0)
+                .checked_mul(period.integer().into_u128())
+                .and_then(|product| {
+                    product.checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()))
+                })
+                .ok_or(ConversionError::Overflow)?,
+            u128::from(input.1) * u128::from(*Dur::SCALING_FACTOR.denominator()),
+        )?;
+
+        Self::search(input, ratio, max_prescaler, max_reload)
+    }
+
+    /// Returns the prescaler division factor
+    pub fn prescaler(&self) -> u32 {
+        self.prescaler
+    }
+
+    /// Returns the reload division factor
+    pub fn reload(&self) -> u32 {
+        self.reload
+    }
+
+    /// Returns the error of the achieved rate from the desired one in parts per million (positive
+    /// if faster, truncated)
+    pub fn error_ppm(&self) -> i64 {
+        self.error_ppb / 1_000
+    }
+
+    /// Returns the achieved output rate (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The _scaling factor_ of the destination type is `0`
+    /// - [`ConversionError::ConversionFailure`]: The rate doesn't fit in the destination type
+    pub fn achieved_rate<R>(&self) -> Result<R, ConversionError>
+    where
+        R: Rate + FixedPoint,
+    {
+        let denominator = u128::from(self.input.1)
+            * u128::from(self.prescaler)
+            * u128::from(self.reload)
+            * u128::from(*R::SCALING_FACTOR.numerator());
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        let integer =
+            u128::from(self.input.0) * u128::from(*R::SCALING_FACTOR.denominator()) / denominator;
+
+        R::T::try_from_u128(integer)
+            .map(R::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    fn search(
+        input: (u64, u64),
+        (numerator, denominator): (u64, u64),
+        max_prescaler: u32,
+        max_reload: u32,
+    ) -> Result<Self, ConversionError> {
+        if max_prescaler == 0 || max_reload == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        let (numerator, denominator) = (u128::from(numerator), u128::from(denominator));
+
+        let mut best: Option<Self> = Option::None;
+        // ceil(ratio / max_reload), the ratio being at least `1 / denominator`
+        let first = (numerator - 1) / (denominator * u128::from(max_reload)) + 1;
+        let first = u32::try_from(first.max(1))
+            .unwrap_or(max_prescaler)
+            .min(max_prescaler);
+
+        for prescaler in first..=max_prescaler {
+            // round(ratio / prescaler)
+            let divisor = denominator * u128::from(prescaler);
+            let reload = (numerator + divisor / 2) / divisor;
+            let reload = u32::try_from(reload.max(1))
+                .unwrap_or(max_reload)
+                .min(max_reload);
+
+            // (ratio - prescaler × reload) / (prescaler × reload)
+            let divided = divisor * u128::from(reload);
+            let error = i128::try_from(numerator).ok().and_then(|numerator| {
+                let difference = numerator - i128::try_from(divided).ok()?;
+                let error =
+                    difference.checked_mul(1_000_000_000)? / i128::try_from(divided).ok()?;
+                i64::try_from(error).ok()
+            });
+            let error_ppb = match error {
+                Option::Some(error) => error,
+                Option::None => continue,
+            };
+
+            match best {
+                Option::Some(best) if best.error_ppb.abs() <= error_ppb.abs() => {}
+                _ => {
+                    best = Option::Some(Self {
+                        prescaler,
+                        reload,
+                        input,
+                        error_ppb,
+                    })
+                }
+            }
+
+            // exact, or every larger prescaler is (even) slower
+            if error_ppb == 0 || (reload == 1 && error_ppb < 0) {
+                break;
+            }
+        }
+
+        best.ok_or(ConversionError::Overflow)
+    }
+}
+
+/// Returns the rate in hertz as a reduced (numerator, denominator) pair
+fn hertz<R: Rate + FixedPoint>(rate: R) -> Result<(u64, u64), ConversionError> {
+    reduce(
+        rate.integer()
+            .into_u128()
+            .checked_mul(u128::from(*R::SCALING_FACTOR.numerator()))
+            .ok_or(ConversionError::Overflow)?,
+        u128::from(*R::SCALING_FACTOR.denominator()),
+    )
+}
+
+/// Reduce a ratio to one of `u64`s
+fn reduce(numerator: u128, denominator: u128) -> Result<(u64, u64), ConversionError> {
+    if numerator == 0 || denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+    let divisor = gcd(numerator, denominator);
+
+    match (
+        u64::try_from(numerator / divisor),
+        u64::try_from(denominator / divisor),
+    ) {
+        (Ok(numerator), Ok(denominator)) => Ok((numerator, denominator)),
+        _ => Err(ConversionError::Overflow),
+    }
+}
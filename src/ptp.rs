@@ -0,0 +1,316 @@
+//! PTP (IEEE 1588) timestamp formats
+//!
+//! - [`PtpTimestamp`]: the 80-bit format (48-bit seconds and 32-bit nanoseconds since
+//!   1970-01-01 00:00:00 TAI) of PTP messages and of the hardware timestamps of Ethernet MACs,
+//!   converted to and from [`TaiTimestamp`]s and durations
+//! - [`Correction`]: the 64-bit correction field (signed nanoseconds × 2¹⁶) accumulating residence
+//!   times and path delays
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::convert::TryFrom;
+//! use embedded_time::{duration::*, ptp::*};
+//!
+//! // the origin timestamp of a Sync message and the receive timestamp of the MAC
+//! let origin = PtpTimestamp::from_be_bytes([0, 0, 0x5F, 0x5E, 0x10, 0x25, 0, 0, 0x03, 0xE8])
+//!     .unwrap();
+//! let receive = PtpTimestamp::new(1_600_000_037, 50_000).unwrap();
+//! let correction = Correction::from_duration(Nanoseconds(2_500_u32)).unwrap();
+//!
+//! // the time from origin to receive, less the correction (eg. the residence time of a switch)
+//! assert_eq!(
+//!     receive.correction_since(&origin).and_then(|elapsed| elapsed.checked_sub(correction)),
+//!     Some(Correction::from_nanoseconds(46_500))
+//! );
+//! assert_eq!(
+//!     origin.checked_add_correction(correction),
+//!     Some(PtpTimestamp::new(1_600_000_037, 3_500).unwrap())
+//! );
+//! ```
+
+use crate::{
+    duration::Duration,
+    fixed_point::FixedPoint,
+    time_int::TimeInt,
+    wallclock::{TaiTimestamp, Timestamp},
+    ConversionError,
+};
+use core::convert::TryFrom;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+/// One past the largest value of the 48-bit seconds field
+const SECONDS_LIMIT: u64 = 1 << 48;
+
+/// A PTP timestamp: seconds and nanoseconds since 1970-01-01 00:00:00 TAI
+///
+/// The PTP timescale is TAI, so a timestamp converts to and from a [`TaiTimestamp`] without loss
+/// (and to UTC with the current [`LeapSeconds`](crate::wallclock::LeapSeconds) offset, eg. from
+/// the `currentUtcOffset` of Announce messages).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct PtpTimestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl PtpTimestamp {
+    /// Construct from the seconds and nanoseconds fields
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The seconds don't fit in 48 bits or the nanoseconds are a
+    /// second or more
+    pub fn new(seconds: u64, nanoseconds: u32) -> Result<Self, ConversionError> {
+        if seconds >= SECONDS_LIMIT || u128::from(nanoseconds) >= NANOS_PER_SECOND {
+            return Err(ConversionError::Overflow);
+        }
+
+        Ok(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Construct from the (big-endian) wire format
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The nanoseconds field is a second or more
+    pub fn from_be_bytes(bytes: [u8; 10]) -> Result<Self, ConversionError> {
+        let mut seconds = [0; 8];
+        seconds[2..].copy_from_slice(&bytes[..6]);
+        let mut nanoseconds = [0; 4];
+        nanoseconds.copy_from_slice(&bytes[6..]);
+
+        Self::new(u64::from_be_bytes(seconds), u32::from_be_bytes(nanoseconds))
+    }
+
+    /// Returns the (big-endian) wire format
+    pub fn to_be_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0; 10];
+        bytes[..6].copy_from_slice(&self.seconds.to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&self.nanoseconds.to_be_bytes());
+        bytes
+    }
+
+    /// Construct from the duration since the PTP epoch (truncated to nanoseconds)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The timestamp can't be represented
+    pub fn from_duration<Dur>(since_epoch: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        Self::try_from(TaiTimestamp(Timestamp::from_duration(since_epoch)?))
+    }
+
+    /// Returns the seconds field
+    pub const fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Returns the nanoseconds field
+    pub const fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Returns the duration since the PTP epoch (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The _scaling factor_ of the destination type is `0`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn duration_since_epoch<Dur>(&self) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        self.to_timestamp().duration_since_epoch()
+    }
+
+    /// Add a duration (truncated to nanoseconds), returning [`None`] on overflow
+    pub fn checked_add<Dur>(&self, duration: Dur) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        Self::try_from(TaiTimestamp(self.to_timestamp().checked_add(duration)?)).ok()
+    }
+
+    /// Subtract a duration (truncated to nanoseconds), returning [`None`] if the result would
+    /// precede the PTP epoch
+    pub fn checked_sub<Dur>(&self, duration: Dur) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        Self::try_from(TaiTimestamp(self.to_timestamp().checked_sub(duration)?)).ok()
+    }
+
+    /// Returns the duration since an earlier timestamp (truncated), or [`None`] if `earlier` is
+    /// later or the duration doesn't fit in the destination type
+    pub fn checked_duration_since<Dur>(&self, earlier: &Self) -> Option<Dur>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        self.to_timestamp()
+            .checked_duration_since(&earlier.to_timestamp())
+    }
+
+    /// Returns the (signed) time since another timestamp as a [`Correction`], or [`None`] if it
+    /// doesn't fit (about ±39 hours)
+    pub fn correction_since(&self, other: &Self) -> Option<Correction> {
+        let nanoseconds = i128::try_from(self.nanoseconds_since_epoch()).ok()?
+            - i128::try_from(other.nanoseconds_since_epoch()).ok()?;
+
+        nanoseconds
+            .checked_mul(1 << 16)
+            .and_then(|scaled| i64::try_from(scaled).ok())
+            .map(Correction)
+    }
+
+    /// Apply a correction (the sub-nanosecond part is truncated towards the past), returning
+    /// [`None`] if the result can't be represented
+    pub fn checked_add_correction(&self, correction: Correction) -> Option<Self> {
+        let nanoseconds =
+            i128::try_from(self.nanoseconds_since_epoch()).ok()? + i128::from(correction.0 >> 16);
+        let nanoseconds = u128::try_from(nanoseconds).ok()?;
+
+        Self::new(
+            u64::try_from_u128(nanoseconds / NANOS_PER_SECOND)?,
+            (nanoseconds % NANOS_PER_SECOND) as u32,
+        )
+        .ok()
+    }
+
+    fn to_timestamp(self) -> Timestamp {
+        // the nanoseconds were validated on construction
+        Timestamp::new(self.seconds, self.nanoseconds).unwrap()
+    }
+
+    fn nanoseconds_since_epoch(&self) -> u128 {
+        u128::from(self.seconds) * NANOS_PER_SECOND + u128::from(self.nanoseconds)
+    }
+}
+
+impl From<PtpTimestamp> for TaiTimestamp {
+    fn from(timestamp: PtpTimestamp) -> Self {
+        Self(timestamp.to_timestamp())
+    }
+}
+
+impl TryFrom<TaiTimestamp> for PtpTimestamp {
+    type Error = ConversionError;
+
+    /// Convert a TAI timestamp
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The seconds don't fit in 48 bits
+    fn try_from(timestamp: TaiTimestamp) -> Result<Self, Self::Error> {
+        Self::new(timestamp.0.unix_seconds(), timestamp.0.subsec_nanoseconds())
+    }
+}
+
+/// A PTP correction field: signed nanoseconds × 2¹⁶
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, ptp::Correction};
+///
+/// let residence_time = Correction::from_bits(0x0000_0000_0271_8000);
+/// assert_eq!(residence_time.nanoseconds(), 625);
+/// assert_eq!(residence_time.to_duration(), Ok(Nanoseconds(625_u32)));
+/// assert_eq!(
+///     Correction::from_duration(Microseconds(2_u32)).unwrap().checked_sub(residence_time),
+///     Some(Correction::from_bits(0x0000_0000_055E_8000))
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Correction(pub i64);
+
+impl Correction {
+    /// Construct from the raw (scaled nanoseconds) value
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Construct from whole nanoseconds (saturating)
+    pub const fn from_nanoseconds(nanoseconds: i64) -> Self {
+        Self(nanoseconds.saturating_mul(1 << 16))
+    }
+
+    /// Construct from the (big-endian) wire format
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self(i64::from_be_bytes(bytes))
+    }
+
+    /// Returns the (big-endian) wire format
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Construct from a (positive) duration (truncated to 2⁻¹⁶ ns)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The duration is too long (about 39 hours or more)
+    pub fn from_duration<Dur>(duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        // duration × scaling factor × 10⁹ × 2¹⁶
+        duration
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()) * (NANOS_PER_SECOND << 16))
+            .map(|integer| integer / u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .and_then(|integer| i64::try_from(integer).ok())
+            .map(Self)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the raw (scaled nanoseconds) value
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the whole nanoseconds (truncated towards zero)
+    pub const fn nanoseconds(&self) -> i64 {
+        self.0 / (1 << 16)
+    }
+
+    /// Returns the duration (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::NegDuration`]: The correction is negative
+    /// - [`ConversionError::DivByZero`]: The _scaling factor_ of the destination type is `0`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn to_duration<Dur>(&self) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let scaled = u128::try_from(self.0).map_err(|_| ConversionError::NegDuration)?;
+
+        // value / 2¹⁶ / 10⁹ / scaling factor
+        let denominator = u128::from(*Dur::SCALING_FACTOR.numerator()) * (NANOS_PER_SECOND << 16);
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        let integer = scaled * u128::from(*Dur::SCALING_FACTOR.denominator()) / denominator;
+
+        Dur::T::try_from_u128(integer)
+            .map(Dur::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    /// Add another correction, returning [`None`] on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtract another correction, returning [`None`] on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+}
@@ -0,0 +1,418 @@
+//! Queues of pending timeouts
+//!
+//! A [`TimerQueue`] keeps the expirations of many software timers ordered so that a scheduler (or
+//! an async executor) only has to wait for (eg. arm an [`Alarm`](crate::alarm::Alarm) at) the
+//! earliest one, and then collects the tokens of the expired entries.
+//!
+//! With the `alloc` feature, a [`VecTimerQueue`] grows on the heap instead. Both implement the
+//! [`TimeoutQueue`] trait, so schedulers can be written once for either.
+
+use crate::Instant;
+#[cfg(feature = "timers")]
+use crate::{duration, latency::Recorder};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A priority queue of expirations, each with a caller-defined token
+///
+/// Implemented by the fixed-capacity [`TimerQueue`] and (`alloc` feature) the heap-backed
+/// [`VecTimerQueue`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, queue::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// fn run_expired(queue: &mut impl TimeoutQueue<Clock, fn()>, now: Instant<Clock>) {
+///     while let Some(callback) = queue.pop_expired(now) {
+///         callback();
+///     }
+/// }
+///
+/// let mut queue = TimerQueue::<Clock, fn(), 2>::new();
+/// queue.push(Instant::new(100), || ()).unwrap();
+///
+/// run_expired(&mut queue, Instant::new(100));
+/// assert!(queue.is_empty());
+/// ```
+pub trait TimeoutQueue<Clock: crate::Clock, Token> {
+    /// Returns the number of queued entries
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no entries are queued
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Queue a token to expire at the given instant
+    ///
+    /// # Errors
+    ///
+    /// The queue is full, the token is returned
+    fn push(&mut self, expiration: Instant<Clock>, token: Token) -> Result<(), Token>;
+
+    /// Returns the earliest expiration or [`None`] if the queue is empty
+    ///
+    /// This is the instant to wait for (eg. to set an alarm at).
+    fn next_expiration(&self) -> Option<Instant<Clock>>;
+
+    /// Remove and return the earliest entry, expired or not, or [`None`] if the queue is empty
+    fn pop(&mut self) -> Option<(Instant<Clock>, Token)>;
+
+    /// Remove and return the token of an entry expired at `now` (the earliest one) or [`None`] if
+    /// no entry has expired
+    ///
+    /// Call repeatedly to collect all the expired entries.
+    fn pop_expired(&mut self, now: Instant<Clock>) -> Option<Token> {
+        match self.next_expiration() {
+            Option::Some(expiration) if now >= expiration => self.pop().map(|(_, token)| token),
+            _ => Option::None,
+        }
+    }
+
+    /// Remove all the entries
+    fn clear(&mut self);
+
+    /// Remove all the entries expired at `now`, passing each token and its lateness (the time
+    /// from its expiration to `now`) to `handler`
+    ///
+    /// Each serviced entry is also recorded to `recorder` (eg. a
+    /// [`MaxLateness`](crate::latency::MaxLateness) for the worst case across all the timers, or
+    /// `()` to record nothing). Per-timer worst cases can be kept by the handler from the lateness
+    /// it receives.
+    ///
+    /// Returns the number of entries serviced.
+    #[cfg(feature = "timers")]
+    fn service<R, F>(&mut self, now: Instant<Clock>, recorder: &mut R, mut handler: F) -> usize
+    where
+        R: Recorder<Clock> + ?Sized,
+        F: FnMut(Token, duration::Generic<Clock::T>),
+    {
+        let mut serviced = 0;
+        while let Option::Some((expiration, token)) = match self.next_expiration() {
+            Option::Some(expiration) if now >= expiration => self.pop(),
+            _ => Option::None,
+        } {
+            recorder.record(expiration, now);
+            let lateness = now.checked_duration_since(&expiration).unwrap_or_else(|| {
+                duration::Generic::new(Clock::T::from(0), Clock::SCALING_FACTOR)
+            });
+            handler(token, lateness);
+            serviced += 1;
+        }
+
+        serviced
+    }
+}
+
+/// A fixed-capacity priority queue of up to `N` expirations, each with a caller-defined token (eg. a
+/// task id)
+///
+/// Entries are ordered by expiration (entries expiring at the same instant are popped in no
+/// particular order). All queued expirations must be within the clock's [valid comparison
+/// window](Instant::valid_comparison_window) of each other and of the instants they are compared
+/// to.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, queue::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut queue = TimerQueue::<Clock, _, 3>::new();
+///
+/// queue.push(Instant::new(300), "c").unwrap();
+/// queue.push(Instant::new(100), "a").unwrap();
+/// queue.push(Instant::new(200), "b").unwrap();
+/// assert_eq!(queue.push(Instant::new(400), "d"), Err("d"));
+///
+/// assert_eq!(queue.next_expiration(), Some(Instant::new(100)));
+/// assert_eq!(queue.pop_expired(Instant::new(250)), Some("a"));
+/// assert_eq!(queue.pop_expired(Instant::new(250)), Some("b"));
+/// assert_eq!(queue.pop_expired(Instant::new(250)), None);
+/// assert_eq!(queue.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct TimerQueue<Clock: crate::Clock, Token, const N: usize> {
+    /// A binary min-heap in `entries[..len]`, the remaining slots are `None`
+    entries: [Option<(Instant<Clock>, Token)>; N],
+    len: usize,
+}
+
+impl<Clock: crate::Clock, Token, const N: usize> TimerQueue<Clock, Token, N> {
+    const EMPTY: Option<(Instant<Clock>, Token)> = Option::None;
+
+    /// Construct an empty queue
+    pub const fn new() -> Self {
+        Self {
+            entries: [Self::EMPTY; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of entries (`N`)
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all the entries expired at `now`, recording their lateness (see
+    /// [`TimeoutQueue::service()`])
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, latency::MaxLateness, queue::*, Instant};
+    /// # use core::convert::TryFrom;
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let mut queue = TimerQueue::<Clock, usize, 2>::new();
+    /// queue.push(Instant::new(100), 0).unwrap();
+    /// queue.push(Instant::new(103), 1).unwrap();
+    ///
+    /// let mut worst = MaxLateness::new();
+    /// let mut per_timer = [Milliseconds(0_u32); 2];
+    /// let serviced = queue.service(Instant::new(105), &mut worst, |timer, lateness| {
+    ///     per_timer[timer] = Milliseconds::try_from(lateness).unwrap();
+    /// });
+    ///
+    /// assert_eq!(serviced, 2);
+    /// assert_eq!(per_timer, [Milliseconds(5_u32), Milliseconds(2_u32)]);
+    /// assert_eq!(Milliseconds::<u32>::try_from(worst.max()), Ok(Milliseconds(5_u32)));
+    /// ```
+    #[cfg(feature = "timers")]
+    pub fn service<R, F>(&mut self, now: Instant<Clock>, recorder: &mut R, handler: F) -> usize
+    where
+        R: Recorder<Clock> + ?Sized,
+        F: FnMut(Token, duration::Generic<Clock::T>),
+    {
+        TimeoutQueue::service(self, now, recorder, handler)
+    }
+}
+
+impl<Clock: crate::Clock, Token, const N: usize> Default for TimerQueue<Clock, Token, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Clock: crate::Clock, Token, const N: usize> TimeoutQueue<Clock, Token>
+    for TimerQueue<Clock, Token, N>
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn push(&mut self, expiration: Instant<Clock>, token: Token) -> Result<(), Token> {
+        if self.len == N {
+            return Err(token);
+        }
+
+        self.entries[self.len] = Option::Some((expiration, token));
+        sift_up(&mut self.entries[..=self.len], self.len);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn next_expiration(&self) -> Option<Instant<Clock>> {
+        expiration(&self.entries, 0)
+    }
+
+    fn pop(&mut self) -> Option<(Instant<Clock>, Token)> {
+        if self.len == 0 {
+            return Option::None;
+        }
+
+        self.len -= 1;
+        self.entries.swap(0, self.len);
+        let entry = self.entries[self.len].take();
+        sift_down(&mut self.entries[..self.len], 0);
+
+        entry
+    }
+
+    fn clear(&mut self) {
+        for slot in self.entries[..self.len].iter_mut() {
+            *slot = Option::None;
+        }
+        self.len = 0;
+    }
+}
+
+/// A heap-allocated priority queue of expirations without a capacity limit (`alloc` feature)
+///
+/// Otherwise the same as [`TimerQueue`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, queue::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut queue = VecTimerQueue::<Clock, _>::new();
+///
+/// for expiration in (0..1_000).rev() {
+///     queue.push(Instant::new(expiration), expiration).unwrap();
+/// }
+/// assert_eq!(queue.pop_expired(Instant::new(1)), Some(0));
+/// assert_eq!(queue.pop_expired(Instant::new(1)), Some(1));
+/// assert_eq!(queue.pop_expired(Instant::new(1)), None);
+/// assert_eq!(queue.len(), 998);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct VecTimerQueue<Clock: crate::Clock, Token> {
+    /// A binary min-heap
+    entries: Vec<(Instant<Clock>, Token)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Clock: crate::Clock, Token> VecTimerQueue<Clock, Token> {
+    /// Construct an empty queue
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Construct an empty queue with space for at least `capacity` entries before it reallocates
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Clock: crate::Clock, Token> Default for VecTimerQueue<Clock, Token> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Clock: crate::Clock, Token> TimeoutQueue<Clock, Token> for VecTimerQueue<Clock, Token> {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Never fails
+    fn push(&mut self, expiration: Instant<Clock>, token: Token) -> Result<(), Token> {
+        let index = self.entries.len();
+        self.entries.push((expiration, token));
+        sift_up(&mut self.entries, index);
+
+        Ok(())
+    }
+
+    fn next_expiration(&self) -> Option<Instant<Clock>> {
+        expiration(&self.entries, 0)
+    }
+
+    fn pop(&mut self) -> Option<(Instant<Clock>, Token)> {
+        if self.entries.is_empty() {
+            return Option::None;
+        }
+
+        let entry = self.entries.swap_remove(0);
+        sift_down(&mut self.entries, 0);
+
+        Option::Some(entry)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+// The heap operations of both queues
+
+/// A slot of a heap, queued or not
+trait Slot<Clock: crate::Clock> {
+    /// Returns the expiration of the queued entry or `None` if the slot is empty
+    fn expiration(&self) -> Option<Instant<Clock>>;
+}
+
+impl<Clock: crate::Clock, Token> Slot<Clock> for Option<(Instant<Clock>, Token)> {
+    fn expiration(&self) -> Option<Instant<Clock>> {
+        self.as_ref().map(|(expiration, _)| *expiration)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Clock: crate::Clock, Token> Slot<Clock> for (Instant<Clock>, Token) {
+    fn expiration(&self) -> Option<Instant<Clock>> {
+        Option::Some(self.0)
+    }
+}
+
+fn expiration<Clock: crate::Clock>(
+    heap: &[impl Slot<Clock>],
+    index: usize,
+) -> Option<Instant<Clock>> {
+    heap.get(index).and_then(Slot::expiration)
+}
+
+/// Returns `true` if entry `a` expires before entry `b` (both must be queued)
+fn before<Clock: crate::Clock>(heap: &[impl Slot<Clock>], a: usize, b: usize) -> bool {
+    matches!(
+        (expiration(heap, a), expiration(heap, b)),
+        (Option::Some(a), Option::Some(b)) if a < b
+    )
+}
+
+fn sift_up<Clock: crate::Clock>(heap: &mut [impl Slot<Clock>], mut index: usize) {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if !before(heap, index, parent) {
+            break;
+        }
+        heap.swap(index, parent);
+        index = parent;
+    }
+}
+
+fn sift_down<Clock: crate::Clock>(heap: &mut [impl Slot<Clock>], mut index: usize) {
+    loop {
+        let mut earliest = index;
+        for child in [2 * index + 1, 2 * index + 2].iter().copied() {
+            if child < heap.len() && before(heap, child, earliest) {
+                earliest = child;
+            }
+        }
+        if earliest == index {
+            break;
+        }
+        heap.swap(index, earliest);
+        index = earliest;
+    }
+}
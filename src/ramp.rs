@@ -0,0 +1,127 @@
+//! Rates of change of rates (eg. Hz/s) for acceleration and ramp limits
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, rate::Rate, time_int::TimeInt, ConversionError,
+};
+use num::Bounded;
+
+/// A _named_ rate's change per second (eg. `RateOfChange(Kilohertz(2_u32))` is 2 kHz/s)
+///
+/// Used to express spindle/motor acceleration limits, PLL ramp constraints, etc. The change over a
+/// _named_ duration (of the same inner type) is returned by
+/// [`checked_change_over()`](RateOfChange::checked_change_over), there is no (panicky) `Mul`
+/// operator.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, ramp::RateOfChange, rate::*};
+///
+/// let acceleration = RateOfChange(Hertz(500_u32));
+///
+/// assert_eq!(
+///     acceleration.checked_change_over(Milliseconds(100_u32)),
+///     Some(Hertz(50_u32))
+/// );
+/// assert_eq!(
+///     acceleration.duration_of_change::<Milliseconds<u32>>(Hertz(2_000_u32)),
+///     Ok(Milliseconds(4_000_u32))
+/// );
+/// assert_eq!(
+///     RateOfChange::from_change(Hertz(300_u32), Milliseconds(600_u32)),
+///     Ok(acceleration)
+/// );
+///
+/// // limit the speed-up of a spindle to 500 Hz/s
+/// let speed = acceleration.step(Hertz(1_000_u32), Hertz(5_000_u32), Milliseconds(10_u32));
+/// assert_eq!(speed, Hertz(1_005_u32));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RateOfChange<R>(pub R);
+
+impl<R: Rate + FixedPoint> RateOfChange<R> {
+    /// Construct from the change over a duration (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The duration is `0`
+    /// - [`ConversionError::Overflow`]: The result doesn't fit in the rate type
+    pub fn from_change<Dur>(change: R, duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint<T = R::T>,
+    {
+        if *duration.integer() == R::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+
+        // change / (duration × scaling factor)
+        change
+            .integer()
+            .checked_div_fraction(&Dur::SCALING_FACTOR)
+            .map(|integer| Self(R::new(integer / *duration.integer())))
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the change per second
+    pub fn per_second(&self) -> R {
+        self.0
+    }
+
+    /// Returns the change over the given duration (truncated) or [`None`] upon overflow
+    pub fn checked_change_over<Dur>(&self, duration: Dur) -> Option<R>
+    where
+        Dur: Duration + FixedPoint<T = R::T>,
+    {
+        // widened so only the result must fit
+        let product = self
+            .0
+            .integer()
+            .into_u128()
+            .checked_mul(duration.integer().into_u128())?;
+        product
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()))?
+            .checked_div(u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .and_then(R::T::try_from_u128)
+            .map(R::new)
+    }
+
+    /// Returns the duration it takes to change by the given amount (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The rate of change is `0`
+    /// - [`ConversionError::Overflow`]: The result doesn't fit in the duration type
+    pub fn duration_of_change<Dur>(&self, change: R) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint<T = R::T>,
+    {
+        if *self.0.integer() == R::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+
+        // change / (rate of change × scaling factor)
+        change
+            .integer()
+            .checked_div_fraction(&Dur::SCALING_FACTOR)
+            .map(|integer| Dur::new(integer / *self.0.integer()))
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Move `current` toward `target` by no more than the change over `elapsed`
+    pub fn step<Dur>(&self, current: R, target: R, elapsed: Dur) -> R
+    where
+        Dur: Duration + FixedPoint<T = R::T>,
+    {
+        let current = *current.integer();
+        let target = *target.integer();
+        let limit = self
+            .checked_change_over(elapsed)
+            .map_or_else(R::T::max_value, |change| *change.integer());
+
+        R::new(if target > current {
+            current + (target - current).min(limit)
+        } else {
+            current - (current - target).min(limit)
+        })
+    }
+}
@@ -2,15 +2,16 @@
 
 pub use crate::fraction::Fraction;
 use crate::{
-    duration,
+    accuracy::{self, Ppm},
     fixed_point::{self, FixedPoint},
     time_int::TimeInt,
     ConversionError,
 };
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
+use core::{convert::TryFrom, prelude::v1::*};
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
-use num::{CheckedDiv, CheckedMul};
+#[doc(inline)]
+pub use units::Extensions as NumericalRate;
 #[doc(inline)]
 pub use units::*;
 
@@ -25,6 +26,7 @@ pub use units::*;
 ///
 /// let _ = <Kilohertz>::new(5);
 /// let _ = Kilohertz(5_u32);
+/// let _ = Kilohertz::from(5_u32);
 /// let _ = 5_u32.kHz();
 /// ```
 ///
@@ -34,8 +36,12 @@ pub use units::*;
 /// use embedded_time::rate::*;
 ///
 /// assert_eq!(Hertz(45_u32).integer(), &45_u32);
+/// assert_eq!(u32::from(Hertz(45_u32)), 45_u32);
 /// ```
 ///
+/// Prefer `new()`/`from()` and `integer()`/`into()` to the tuple field where the code should keep
+/// working if the representation of the rate types changes.
+///
 /// # Formatting
 ///
 /// Just forwards the underlying integer to [`core::fmt::Display::fmt()`]
@@ -174,6 +180,29 @@ pub use units::*;
 /// Rates may also be multiplied and divided by integers. The result is of the LHS type. Both
 /// _panicky_ and _checked_ operations are available.
 ///
+/// # Multiplying by a `Duration`
+///
+/// A frequency ([`Hertz`], [`Kilohertz`], etc.) multiplied by a _named_ duration (or vice versa)
+/// results in the (truncated) number of cycles within the duration, eg. for configuring a timer's
+/// reload value. Both operands must have the same inner type.
+///
+/// ```rust
+/// use embedded_time::{duration::*, rate::*};
+///
+/// assert_eq!(Milliseconds(10_u32) * Kilohertz(8_u32), 80);
+/// assert_eq!(Megahertz(16_u32) * Microseconds(250_u32), 4_000);
+/// assert_eq!(Hertz(3_u32) * Milliseconds(500_u32), 1);
+/// ```
+///
+/// ## Panics
+///
+/// If the number of cycles (or an intermediate product) doesn't fit in the inner type.
+///
+/// ```rust,should_panic
+/// # use embedded_time::{duration::*, rate::*};
+/// let _ = Hours(2_u32) * Megahertz(1_u32);
+/// ```
+///
 /// # Comparisons
 ///
 /// ```rust
@@ -186,6 +215,9 @@ pub use units::*;
 /// assert!(Kilohertz(2_u32) > Hertz(1_999_u32));
 /// ```
 ///
+/// Each rate type is also [`Ord`] and [`Hash`](core::hash::Hash), so it can be used as the key of
+/// sorted queues and maps.
+///
 /// # Remainder
 ///
 /// ```rust
@@ -253,7 +285,35 @@ pub trait Rate: Sized + Copy {
         ))
     }
 
-    /// Convert to _named_ [`Duration`](duration::Duration)
+    /// Returns `true` if the rate deviates from the expected one (of any unit) by no more than the
+    /// tolerance (relative to the expected rate)
+    ///
+    /// Also returns `false` if the comparison overflows (only possible with extreme _scaling
+    /// factors_).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{accuracy::Ppm, rate::*};
+    ///
+    /// // a baud rate generator achieving 115,107 Bd for 115,200 Bd is ~0.08% off
+    /// assert!(Baud(115_107_u32).within(Kilobaud(115_u32), Ppm::from_percent(1)));
+    /// assert!(Baud(115_107_u32).within(Baud(115_200_u32), Ppm(1_000)));
+    /// assert!(!Baud(115_107_u32).within(Baud(115_200_u32), Ppm(500)));
+    /// ```
+    fn within<Expected>(&self, expected: Expected, tolerance: Ppm) -> bool
+    where
+        Self: FixedPoint,
+        Expected: Rate + FixedPoint,
+    {
+        accuracy::within(
+            (self.integer().into_u128(), Self::SCALING_FACTOR),
+            (expected.integer().into_u128(), Expected::SCALING_FACTOR),
+            tolerance,
+        )
+    }
+
+    /// Convert to _named_ [`Duration`](crate::duration::Duration)
     ///
     /// (the rate is equal to the reciprocal of the duration)
     ///
@@ -297,7 +357,8 @@ pub trait Rate: Sized + Copy {
     ///     Err(ConversionError::DivByZero)
     /// );
     /// ```
-    fn to_duration<Duration: duration::Duration>(&self) -> Result<Duration, ConversionError>
+    #[cfg(feature = "duration")]
+    fn to_duration<Duration: crate::duration::Duration>(&self) -> Result<Duration, ConversionError>
     where
         Duration: FixedPoint,
         Self: FixedPoint,
@@ -308,32 +369,24 @@ pub trait Rate: Sized + Copy {
             .ok_or(ConversionError::Unspecified)?
             .recip();
 
-        if size_of::<Self::T>() >= size_of::<Duration::T>() {
-            fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &self
-                            .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Duration::SCALING_FACTOR,
-            )
-        } else {
-            fixed_point::FixedPoint::from_ticks(
-                Duration::T::from(*conversion_factor.numerator())
-                    .checked_div(
-                        &Duration::T::try_from(*self.integer())
-                            .ok()
-                            .unwrap()
-                            .checked_mul(&Duration::T::from(*conversion_factor.denominator()))
-                            .ok_or(ConversionError::Overflow)?,
-                    )
-                    .ok_or(ConversionError::DivByZero)?,
-                Duration::SCALING_FACTOR,
-            )
-        }
+        // (done in `u128`, so narrow integer types don't have to hold the conversion factor, but
+        // the product must fit in the wider of the two integer types)
+        let max = <Self as FixedPoint>::max_value()
+            .into_u128()
+            .max(<Duration as FixedPoint>::max_value().into_u128());
+        let denominator = self
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*conversion_factor.denominator()))
+            .filter(|denominator| *denominator <= max)
+            .ok_or(ConversionError::Overflow)?;
+        let integer = u128::from(*conversion_factor.numerator())
+            .checked_div(denominator)
+            .ok_or(ConversionError::DivByZero)?;
+
+        Duration::T::try_from_u128(integer)
+            .map(Duration::new)
+            .ok_or(ConversionError::ConversionFailure)
     }
 }
 
@@ -343,6 +396,7 @@ pub trait Rate: Sized + Copy {
 /// The purpose of this type is to allow a simple `Rate` object that can be defined at run-time.
 /// It does this by replacing the `const` _scaling factor_ with a struct field.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Generic<T> {
     integer: T,
     scaling_factor: Fraction,
@@ -370,6 +424,13 @@ impl<T> Generic<T> {
 
 impl<T: TimeInt> Rate for Generic<T> {}
 
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} × {} Hz", self.integer, self.scaling_factor)
+    }
+}
+
 /// Rate-type units
 #[doc(hidden)]
 pub mod units {
@@ -389,15 +450,87 @@ pub mod units {
     #[doc(hidden)]
     pub use Extensions as _;
 
+    /// Panicky frequency × duration = number of cycles
+    #[cfg(feature = "duration")]
+    fn cycles<Rate, Dur>(rate: Rate, duration: Dur) -> Rate::T
+    where
+        Rate: super::Rate + FixedPoint,
+        Dur: crate::duration::Duration + FixedPoint<T = Rate::T>,
+    {
+        // rate × duration × (rate numerator × duration numerator) / (rate denominator × duration
+        // denominator), widened so only the result must fit
+        let numerator = u128::from(*Rate::SCALING_FACTOR.numerator())
+            * u128::from(*Dur::SCALING_FACTOR.numerator());
+        let denominator = u128::from(*Rate::SCALING_FACTOR.denominator())
+            * u128::from(*Dur::SCALING_FACTOR.denominator());
+
+        rate.integer()
+            .into_u128()
+            .checked_mul(duration.integer().into_u128())
+            .and_then(|product| product.checked_mul(numerator))
+            .and_then(|product| product.checked_div(denominator))
+            .and_then(Rate::T::try_from_u128)
+            .unwrap()
+    }
+
+    #[cfg(feature = "duration")]
+    macro_rules! impl_cycles {
+        ( $name:ident ) => {
+            impl_cycles![
+                $name,
+                Hours,
+                Minutes,
+                Seconds,
+                Milliseconds,
+                Microseconds,
+                Nanoseconds
+            ];
+        };
+        ( $name:ident, $( $duration:ident ),+ ) => {
+            $(
+                impl<T: TimeInt> ops::Mul<crate::duration::$duration<T>> for $name<T> {
+                    type Output = T;
+
+                    /// See [Multiplying by a `Duration`](trait.Rate.html#multiplying-by-a-duration)
+                    fn mul(self, rhs: crate::duration::$duration<T>) -> Self::Output {
+                        cycles(self, rhs)
+                    }
+                }
+
+                impl<T: TimeInt> ops::Mul<$name<T>> for crate::duration::$duration<T> {
+                    type Output = T;
+
+                    /// See [Multiplying by a `Rate`](trait.Duration.html#multiplying-by-a-rate)
+                    fn mul(self, rhs: $name<T>) -> Self::Output {
+                        cycles(rhs, self)
+                    }
+                }
+            )+
+        };
+    }
+
     macro_rules! impl_rate {
+        ( @int $name:ident, $int:ty ) => {
+            impl From<$name<$int>> for $int {
+                /// See [Get the integer part](trait.Rate.html#get-the-integer-part)
+                fn from(rate: $name<$int>) -> Self {
+                    rate.0
+                }
+            }
+        };
         ( $name:ident, ($numer:expr, $denom:expr), $desc:literal ) => {
             #[doc = $desc]
             #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+            #[cfg_attr(
+                feature = "serde",
+                derive(serde::Serialize, serde::Deserialize),
+                serde(transparent)
+            )]
             pub struct $name<T: TimeInt = u32>(pub T);
 
             impl<T: TimeInt> $name<T> {
                 /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
-                pub fn new(value: T) -> Self {
+                pub const fn new(value: T) -> Self {
                     Self(value)
                 }
             }
@@ -426,6 +559,13 @@ pub mod units {
                 }
             }
 
+            #[cfg(feature = "defmt")]
+            impl<T: TimeInt + defmt::Format> defmt::Format for $name<T> {
+                fn format(&self, f: defmt::Formatter<'_>) {
+                    defmt::write!(f, "{} {=str}", self.0, $desc)
+                }
+            }
+
             impl<T: TimeInt, Rhs: Rate> ops::Add<Rhs> for $name<T>
             where
                 Rhs: FixedPoint,
@@ -492,7 +632,10 @@ pub mod units {
 
                 /// See [Converting from a `Generic` `Rate`](trait.Rate.html#converting-from-a-generic-rate)
                 fn try_from(generic_rate: Generic<SourceInt>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(generic_rate.integer, generic_rate.scaling_factor)
+                    fixed_point::FixedPoint::from_ticks(
+                        generic_rate.integer,
+                        generic_rate.scaling_factor,
+                    )
                 }
             }
 
@@ -502,6 +645,19 @@ pub mod units {
                     Self::new(*rate.integer(), $name::<T>::SCALING_FACTOR)
                 }
             }
+
+            impl<T: TimeInt> From<T> for $name<T> {
+                /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
+                fn from(integer: T) -> Self {
+                    Self(integer)
+                }
+            }
+
+            impl_rate![@int $name, u8];
+            impl_rate![@int $name, u16];
+            impl_rate![@int $name, u32];
+            impl_rate![@int $name, u64];
+            impl_rate![@int $name, u128];
         };
     }
     impl_rate![Mebihertz, (1_048_576, 1), "Hertz × 1,048,576"];
@@ -509,6 +665,16 @@ pub mod units {
     impl_rate![Kibihertz, (1_024, 1), "Hertz × 1,024"];
     impl_rate![Kilohertz, (1_000, 1), "Hertz × 1,000"];
     impl_rate![Hertz, (1, 1), "Hertz"];
+    #[cfg(feature = "duration")]
+    mod cycles {
+        use super::*;
+
+        impl_cycles![Mebihertz];
+        impl_cycles![Megahertz];
+        impl_cycles![Kibihertz];
+        impl_cycles![Kilohertz];
+        impl_cycles![Hertz];
+    }
     impl_rate![
         MebibytesPerSecond,
         (1_048_576 * 8, 1),
@@ -547,13 +713,20 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(source: $name<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        *source.integer(),
-                        $name::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::convert(source)
                 }
             }
 
+            impl_conversion![widen, $name, u8, u16];
+            impl_conversion![widen, $name, u8, u32];
+            impl_conversion![widen, $name, u8, u64];
+            impl_conversion![widen, $name, u8, u128];
+            impl_conversion![widen, $name, u16, u32];
+            impl_conversion![widen, $name, u16, u64];
+            impl_conversion![widen, $name, u16, u128];
+            impl_conversion![widen, $name, u32, u128];
+            impl_conversion![widen, $name, u64, u128];
+
             impl<T: TimeInt, RhsInt: TimeInt> cmp::PartialEq<$name<RhsInt>> for $name<T>
             where
                 T: TryFrom<RhsInt>,
@@ -581,12 +754,30 @@ pub mod units {
             }
         };
 
+        (widen, $name:ident, $small:ty, $big:ty) => {
+            impl From<$name<$small>> for $name<$big> {
+                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+                fn from(source: $name<$small>) -> Self {
+                    Self::new(<$big>::from(*source.integer()))
+                }
+            }
+
+            impl TryFrom<$name<$big>> for $name<$small> {
+                type Error = ConversionError;
+
+                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+                fn try_from(source: $name<$big>) -> Result<Self, Self::Error> {
+                    fixed_point::convert(source)
+                }
+            }
+        };
+
         (once, $big:ident, $small:ident) => {
             impl<T: TimeInt> From<$small<T>> for $big<T>
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(small: $small<T>) -> Self {
-                    fixed_point::FixedPoint::from_ticks(*small.integer(), $small::<T>::SCALING_FACTOR).ok().unwrap()
+                    fixed_point::convert(small).ok().unwrap()
                 }
             }
 
@@ -594,7 +785,7 @@ pub mod units {
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(small: $small<u32>) -> Self {
-                    fixed_point::FixedPoint::from_ticks(*small.integer(), $small::<u32>::SCALING_FACTOR).ok().unwrap()
+                    fixed_point::convert(small).ok().unwrap()
                 }
             }
 
@@ -604,10 +795,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(small: $small<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        *small.integer(),
-                        $small::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::convert(small)
                 }
             }
 
@@ -616,7 +804,7 @@ pub mod units {
             {
                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(big: $big<u32>) -> Self {
-                    fixed_point::FixedPoint::from_ticks(*big.integer(), $big::<u32>::SCALING_FACTOR).ok().unwrap()
+                    fixed_point::convert(big).ok().unwrap()
                 }
             }
 
@@ -626,10 +814,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(big: $big<T>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        *big.integer(),
-                        $big::<T>::SCALING_FACTOR,
-                    )
+                    fixed_point::convert(big)
                 }
             }
 
@@ -639,10 +824,7 @@ pub mod units {
 
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn try_from(big: $big<u64>) -> Result<Self, Self::Error> {
-                    fixed_point::FixedPoint::from_ticks(
-                        *big.integer(),
-                        $big::<u64>::SCALING_FACTOR,
-                    )
+                    fixed_point::convert(big)
                 }
             }
 
@@ -740,6 +922,8 @@ pub mod units {
 
     /// Create rate-based extensions from primitive numeric types.
     ///
+    /// Also available as `NumericalRate`.
+    ///
     /// ```rust
     /// # use embedded_time::{rate::*};
     /// assert_eq!(5_u32.MiHz(), Mebihertz(5_u32));
@@ -747,6 +931,9 @@ pub mod units {
     /// assert_eq!(5_u32.KiHz(), Kibihertz(5_u32));
     /// assert_eq!(5_u32.kHz(), Kilohertz(5_u32));
     /// assert_eq!(5_u32.Hz(), Hertz(5_u32));
+    /// assert_eq!(5_u32.mhz(), Megahertz(5_u32));
+    /// assert_eq!(5_u32.khz(), Kilohertz(5_u32));
+    /// assert_eq!(5_u32.hz(), Hertz(5_u32));
     /// assert_eq!(5_u32.MiBps(), MebibytesPerSecond(5_u32));
     /// assert_eq!(5_u32.MBps(), MegabytesPerSecond(5_u32));
     /// assert_eq!(5_u32.KiBps(), KibibytesPerSecond(5_u32));
@@ -790,6 +977,21 @@ pub mod units {
             Hertz::new(self)
         }
 
+        /// megahertz (lowercase alias of [`MHz()`](Extensions::MHz))
+        fn mhz(self) -> Megahertz<Self> {
+            Megahertz::new(self)
+        }
+
+        /// kilohertz (lowercase alias of [`kHz()`](Extensions::kHz))
+        fn khz(self) -> Kilohertz<Self> {
+            Kilohertz::new(self)
+        }
+
+        /// hertz (lowercase alias of [`Hz()`](Extensions::Hz))
+        fn hz(self) -> Hertz<Self> {
+            Hertz::new(self)
+        }
+
         /// mebibytes per second
         fn MiBps(self) -> MebibytesPerSecond<Self> {
             MebibytesPerSecond::new(self)
@@ -866,8 +1068,11 @@ pub mod units {
         }
     }
 
+    impl Extensions for u8 {}
+    impl Extensions for u16 {}
     impl Extensions for u32 {}
     impl Extensions for u64 {}
+    impl Extensions for u128 {}
 }
 
 #[cfg(test)]
@@ -0,0 +1,168 @@
+//! Deterministic recording and replay of clock reads
+//!
+//! Timing-dependent bugs reported from the field can be reproduced by wrapping the production
+//! clock in a [`RecordingClock`], capturing every reading, and later feeding the captured readings
+//! to the same code through a [`ReplayClock`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use embedded_time::{fraction::Fraction, replay::*, Clock as _, Instant};
+//!
+//! # #[derive(Debug)]
+//! struct Clock;
+//! impl embedded_time::Clock for Clock {
+//!     type T = u32;
+//!     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+//!     // ...
+//! # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(42))}
+//! }
+//!
+//! let mut log = Vec::new();
+//! let clock = RecordingClock::new(Clock, |ticks| log.push(ticks));
+//! clock.try_now().unwrap();
+//! drop(clock);
+//!
+//! assert_eq!(log, [42]);
+//!
+//! let clock = ReplayClock::<Clock>::new(&log);
+//! assert_eq!(clock.try_now(), Ok(Instant::new(42)));
+//! assert_eq!(clock.try_now(), Err(embedded_time::clock::Error::NotRunning));
+//! ```
+
+use crate::{clock, Instant};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+    marker::PhantomData,
+};
+
+/// Receives the tick counts read by a [`RecordingClock`]
+///
+/// Implemented for all `FnMut(T)` closures and (`alloc` feature) `Vec<T>`, which keeps every
+/// reading.
+pub trait Sink<T> {
+    /// Record a successful clock reading
+    fn record(&mut self, ticks: T);
+}
+
+impl<T, F: FnMut(T)> Sink<T> for F {
+    fn record(&mut self, ticks: T) {
+        self(ticks)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Sink<T> for Vec<T> {
+    fn record(&mut self, ticks: T) {
+        self.push(ticks)
+    }
+}
+
+/// A [`Clock`](crate::Clock) that forwards every successful reading of the wrapped clock to a
+/// [`Sink`]
+///
+/// Failed readings are returned unrecorded.
+pub struct RecordingClock<Clock, S> {
+    clock: Clock,
+    sink: RefCell<S>,
+}
+
+impl<Clock: crate::Clock, S: Sink<Clock::T>> RecordingClock<Clock, S> {
+    /// Wrap a clock, recording its readings to the provided sink
+    pub fn new(clock: Clock, sink: S) -> Self {
+        Self {
+            clock,
+            sink: RefCell::new(sink),
+        }
+    }
+
+    /// Returns a reference to the wrapped clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Consumes the `RecordingClock`, returning the wrapped clock and the sink
+    pub fn into_parts(self) -> (Clock, S) {
+        (self.clock, self.sink.into_inner())
+    }
+}
+
+impl<Clock: fmt::Debug, S> fmt::Debug for RecordingClock<Clock, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingClock")
+            .field("clock", &self.clock)
+            .finish()
+    }
+}
+
+impl<Clock: crate::Clock, S: Sink<Clock::T>> crate::Clock for RecordingClock<Clock, S> {
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let ticks = *self.clock.try_now()?.duration_since_epoch().integer();
+        self.sink.borrow_mut().record(ticks);
+
+        Ok(Instant::new(ticks))
+    }
+}
+
+/// A [`Clock`](crate::Clock) that replays a recorded sequence of readings of another clock
+///
+/// Once the sequence is exhausted, [`clock::Error::NotRunning`] is returned.
+pub struct ReplayClock<'a, Clock: crate::Clock> {
+    readings: &'a [Clock::T],
+    position: Cell<usize>,
+    _clock: PhantomData<Clock>,
+}
+
+impl<'a, Clock: crate::Clock> ReplayClock<'a, Clock> {
+    /// Construct a clock replaying the readings from the beginning
+    pub fn new(readings: &'a [Clock::T]) -> Self {
+        Self {
+            readings,
+            position: Cell::new(0),
+            _clock: PhantomData,
+        }
+    }
+
+    /// Returns the number of readings not yet replayed
+    pub fn remaining(&self) -> usize {
+        self.readings.len() - self.position.get()
+    }
+
+    /// Restart the replay from the first reading
+    pub fn rewind(&self) {
+        self.position.set(0);
+    }
+}
+
+impl<Clock: crate::Clock> fmt::Debug for ReplayClock<'_, Clock> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplayClock")
+            .field("readings", &self.readings)
+            .field("position", &self.position.get())
+            .finish()
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for ReplayClock<'_, Clock> {
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let position = self.position.get();
+        let ticks = self
+            .readings
+            .get(position)
+            .ok_or(clock::Error::NotRunning)?;
+        self.position.set(position + 1);
+
+        Ok(Instant::new(*ticks))
+    }
+}
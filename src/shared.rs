@@ -0,0 +1,156 @@
+//! Interrupt-safe sharing of instants and clocks
+//!
+//! State shared between the main loop and interrupt handlers must be held in a `static`, and so
+//! be `Sync`:
+//!
+//! - [`AtomicInstant`] holds an [`Instant`] (eg. the timestamp of an event taken in its interrupt
+//!   handler)
+//! - [`SharedClock`] makes a clock (eg. an [`ExtendedClock`](crate::extended::ExtendedClock)
+//!   counting rollovers from the overflow interrupt) usable from both
+//!
+//! Both guard their contents with a critical section of the
+//! [`critical-section`](critical_section) crate, so they are sound on single-core MCUs (and on
+//! multi-core ones with a suitable implementation) without `static mut`s.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use embedded_time::{
+//!     duration::*,
+//!     extended::ExtendedClock,
+//!     shared::{AtomicInstant, SharedClock},
+//!     Clock as _, Instant,
+//! };
+//! # use core::sync::atomic::{AtomicU32, Ordering};
+//! # static COUNTER: AtomicU32 = AtomicU32::new(0);
+//!
+//! # #[derive(Debug)]
+//! struct Timer;
+//! impl embedded_time::Clock for Timer {
+//!     type T = u32;
+//!     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+//!     const COUNTER_BITS: u32 = 16;
+//!     // ...
+//! # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(COUNTER.load(Ordering::SeqCst)))}
+//! }
+//!
+//! type Clock = SharedClock<ExtendedClock<Timer>>;
+//!
+//! static CLOCK: Clock = SharedClock::new(ExtendedClock::new(Timer));
+//! static LAST_EDGE: AtomicInstant<Clock> = AtomicInstant::new();
+//!
+//! // the timer's overflow interrupt handler
+//! fn on_overflow() {
+//!     CLOCK.with(|clock| clock.on_rollover()).unwrap();
+//! }
+//!
+//! // an input's edge interrupt handler
+//! fn on_edge() {
+//!     LAST_EDGE.store(CLOCK.try_now().unwrap());
+//! }
+//!
+//! # COUNTER.store(0xFFF0, Ordering::SeqCst);
+//! CLOCK.try_now().unwrap();
+//! # COUNTER.store(0x10, Ordering::SeqCst);
+//! on_overflow();
+//! on_edge();
+//! assert_eq!(LAST_EDGE.take(), Some(Instant::new(0x1_0010)));
+//! assert_eq!(LAST_EDGE.load(), None);
+//! ```
+
+use crate::{clock, Instant};
+use core::{cell::Cell, fmt};
+use critical_section::Mutex;
+
+/// An interrupt-safe cell holding an optional [`Instant`]
+pub struct AtomicInstant<Clock: crate::Clock> {
+    ticks: Mutex<Cell<Option<Clock::T>>>,
+}
+
+impl<Clock: crate::Clock> AtomicInstant<Clock> {
+    /// Construct an empty cell
+    pub const fn new() -> Self {
+        Self {
+            ticks: Mutex::new(Cell::new(Option::None)),
+        }
+    }
+
+    /// Returns the instant (if one has been stored)
+    pub fn load(&self) -> Option<Instant<Clock>> {
+        critical_section::with(|cs| self.ticks.borrow(cs).get()).map(Instant::new)
+    }
+
+    /// Store an instant
+    pub fn store(&self, instant: Instant<Clock>) {
+        self.swap(instant);
+    }
+
+    /// Store an instant, returning the previous one
+    pub fn swap(&self, instant: Instant<Clock>) -> Option<Instant<Clock>> {
+        let ticks = *instant.duration_since_epoch().integer();
+        critical_section::with(|cs| self.ticks.borrow(cs).replace(Option::Some(ticks)))
+            .map(Instant::new)
+    }
+
+    /// Empty the cell, returning the instant
+    pub fn take(&self) -> Option<Instant<Clock>> {
+        critical_section::with(|cs| self.ticks.borrow(cs).take()).map(Instant::new)
+    }
+}
+
+impl<Clock: crate::Clock> Default for AtomicInstant<Clock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Clock: crate::Clock> fmt::Debug for AtomicInstant<Clock> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AtomicInstant").field(&self.load()).finish()
+    }
+}
+
+/// A [`Clock`](crate::Clock) accessed within critical sections, so it can be shared with
+/// interrupt handlers
+///
+/// Readings (and [`with()`](SharedClock::with) calls) can't be interrupted by one another.
+pub struct SharedClock<Clock> {
+    clock: Mutex<Clock>,
+}
+
+impl<Clock> SharedClock<Clock> {
+    /// Wrap a clock
+    pub const fn new(clock: Clock) -> Self {
+        Self {
+            clock: Mutex::new(clock),
+        }
+    }
+
+    /// Call a function with the wrapped clock within a critical section (eg. to call
+    /// [`ExtendedClock::on_rollover()`](crate::extended::ExtendedClock::on_rollover))
+    pub fn with<R>(&self, f: impl FnOnce(&Clock) -> R) -> R {
+        critical_section::with(|cs| f(self.clock.borrow(cs)))
+    }
+
+    /// Returns the wrapped clock
+    pub fn into_inner(self) -> Clock {
+        self.clock.into_inner()
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for SharedClock<Clock> {
+    type T = Clock::T;
+    const SCALING_FACTOR: crate::fraction::Fraction = Clock::SCALING_FACTOR;
+    const COUNTER_BITS: u32 = Clock::COUNTER_BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let now = self.with(|clock| clock.try_now())?;
+        Ok(Instant::new(*now.duration_since_epoch().integer()))
+    }
+}
+
+impl<Clock: fmt::Debug> fmt::Debug for SharedClock<Clock> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with(|clock| f.debug_tuple("SharedClock").field(clock).finish())
+    }
+}
@@ -0,0 +1,121 @@
+//! Elapsed-time measurement
+
+use crate::{clock, duration, Instant};
+use num::CheckedSub;
+
+/// Measures the running time of code sections, with laps and pauses
+///
+/// The measured time (pauses excluded) must stay within the clock's [valid comparison
+/// window](Instant::valid_comparison_window).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, stopwatch::Stopwatch, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut stopwatch = Stopwatch::new(Instant::<Clock>::new(100));
+///
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(stopwatch.lap(Instant::new(130))),
+///     Ok(Milliseconds(30_u32))
+/// );
+///
+/// stopwatch.pause(Instant::new(150));
+/// stopwatch.resume(Instant::new(1_000));
+///
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(stopwatch.lap(Instant::new(1_010))),
+///     Ok(Milliseconds(30_u32))
+/// );
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(stopwatch.elapsed(Instant::new(1_010))),
+///     Ok(Milliseconds(60_u32))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Stopwatch<Clock: crate::Clock> {
+    /// The start, moved later by the paused time
+    start: Instant<Clock>,
+    /// The instant the stopwatch was paused at
+    paused: Option<Instant<Clock>>,
+    /// The elapsed ticks at the last lap
+    lap: Clock::T,
+}
+
+impl<Clock: crate::Clock> Stopwatch<Clock> {
+    /// Construct a stopwatch running from the given instant
+    pub fn new(now: Instant<Clock>) -> Self {
+        Self {
+            start: now,
+            paused: Option::None,
+            lap: Clock::T::from(0),
+        }
+    }
+
+    /// Construct a stopwatch running from now
+    ///
+    /// # Errors
+    ///
+    /// [`clock::Error`]: The clock could not be read
+    pub fn start(clock: &Clock) -> Result<Self, clock::Error> {
+        Ok(Self::new(clock.try_now()?))
+    }
+
+    /// Restart the stopwatch (running and without laps) at the given instant
+    pub fn restart(&mut self, now: Instant<Clock>) {
+        *self = Self::new(now);
+    }
+
+    /// Returns the running time since the start, pauses excluded (`0` if `now` precedes the start)
+    pub fn elapsed(&self, now: Instant<Clock>) -> duration::Generic<Clock::T> {
+        duration::Generic::new(self.elapsed_ticks(now), Clock::SCALING_FACTOR)
+    }
+
+    /// Returns the running time since the previous lap (or the start), starting a new lap
+    pub fn lap(&mut self, now: Instant<Clock>) -> duration::Generic<Clock::T> {
+        let elapsed = self.elapsed_ticks(now);
+        let lap = elapsed
+            .checked_sub(&self.lap)
+            .unwrap_or_else(|| Clock::T::from(0));
+        self.lap = elapsed;
+
+        duration::Generic::new(lap, Clock::SCALING_FACTOR)
+    }
+
+    /// Stop counting time (until [resumed](Stopwatch::resume)), no-op if already paused
+    pub fn pause(&mut self, now: Instant<Clock>) {
+        if self.paused.is_none() {
+            self.paused = Option::Some(now);
+        }
+    }
+
+    /// Continue counting time, no-op if not paused
+    pub fn resume(&mut self, now: Instant<Clock>) {
+        if let Option::Some(paused) = self.paused.take() {
+            if let Option::Some(pause) = now.checked_duration_since(&paused) {
+                self.start = self.start.wrapping_add_ticks(*pause.integer());
+            }
+        }
+    }
+
+    /// Returns `true` if paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    fn elapsed_ticks(&self, now: Instant<Clock>) -> Clock::T {
+        self.paused
+            .unwrap_or(now)
+            .checked_duration_since(&self.start)
+            .map_or_else(|| Clock::T::from(0), |elapsed| *elapsed.integer())
+    }
+}
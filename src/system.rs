@@ -0,0 +1,63 @@
+//! A `Clock` backed by the host's monotonic clock
+//!
+//! Lets the same driver code run in integration tests and host simulations.
+
+use crate::{clock, fraction::Fraction, Instant};
+use std::time;
+
+/// A [`Clock`](crate::Clock) reading [`std::time::Instant`] in nanoseconds
+///
+/// Its ticks count from the construction of the clock, wrapping after about 584 years.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{duration::*, system::SystemClock, Clock as _};
+///
+/// let clock = SystemClock::new();
+///
+/// clock.new_timer(Milliseconds(1_u32)).start().unwrap().wait().unwrap();
+///
+/// let elapsed = clock.try_now().unwrap().duration_since_epoch();
+/// assert!(Milliseconds::<u64>::try_from(elapsed).unwrap() >= Milliseconds(1_u64));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SystemClock {
+    epoch: time::Instant,
+}
+
+impl SystemClock {
+    /// Construct a clock starting now
+    pub fn new() -> Self {
+        Self::with_epoch(time::Instant::now())
+    }
+
+    /// Construct a clock counting from the given [`std::time::Instant`]
+    pub fn with_epoch(epoch: time::Instant) -> Self {
+        Self { epoch }
+    }
+
+    /// Returns the [`std::time::Instant`] of tick `0`
+    pub fn epoch(&self) -> time::Instant {
+        self.epoch
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Clock for SystemClock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        // an epoch in the future reads as `0`
+        let elapsed = time::Instant::now().saturating_duration_since(self.epoch);
+
+        Ok(Instant::new(elapsed.as_nanos() as u64))
+    }
+}
@@ -0,0 +1,146 @@
+//! Rate limiting
+
+use crate::{
+    clock, fixed_point::FixedPoint, rate::Rate, time_int::TimeInt, ConversionError, Instant,
+    TimeError,
+};
+
+/// A token-bucket rate limiter capping how often an event (eg. sending a CAN frame or a log line)
+/// may happen
+///
+/// Tokens accrue at the given rate, up to the _burst_ size (the bucket starts full). Each allowed
+/// event takes a token.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, rate::*, throttle::Throttle, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // 10 events per second, in bursts of up to 2
+/// let mut throttle = Throttle::new(Instant::<Clock>::new(0), Hertz(10_u32), 2).unwrap();
+///
+/// assert!(throttle.ready(Instant::new(0)));
+/// assert!(throttle.ready(Instant::new(0)));
+/// assert!(!throttle.ready(Instant::new(99)));
+/// assert!(throttle.ready(Instant::new(100)));
+/// ```
+#[derive(Debug)]
+pub struct Throttle<Clock: crate::Clock> {
+    /// The ticks per token
+    interval: Clock::T,
+    burst: u32,
+    tokens: u32,
+    /// The instant the last token accrued at
+    refilled: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Throttle<Clock> {
+    /// Construct a full throttle at the given instant
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The rate is `0`
+    /// - [`ConversionError::Overflow`]: The rate is faster than the clock's ticks, or its period
+    ///   is longer than the clock's [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::Unspecified`]: The rate's _scaling factor_ is `0`
+    pub fn new<R>(now: Instant<Clock>, rate: R, burst: u32) -> Result<Self, ConversionError>
+    where
+        R: Rate + FixedPoint,
+    {
+        // 1 / (rate × rate scaling factor) / clock scaling factor (a rate too fast to compute is
+        // also faster than the clock's ticks)
+        let denominator = rate
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*R::SCALING_FACTOR.numerator()))
+            .and_then(|product| product.checked_mul(u128::from(*Clock::SCALING_FACTOR.numerator())))
+            .ok_or(ConversionError::Overflow)?;
+        if denominator == 0 {
+            return Err(if *rate.integer() == R::T::from(0) {
+                ConversionError::DivByZero
+            } else {
+                ConversionError::Unspecified
+            });
+        }
+        let interval = u128::from(*R::SCALING_FACTOR.denominator())
+            * u128::from(*Clock::SCALING_FACTOR.denominator())
+            / denominator;
+
+        let interval = Clock::T::try_from_u128(interval)
+            .filter(|interval| *interval > Clock::T::from(0))
+            .ok_or(ConversionError::Overflow)
+            .and_then(Instant::<Clock>::within_window)?;
+
+        Ok(Self {
+            interval,
+            burst,
+            tokens: burst,
+            refilled: now,
+        })
+    }
+
+    /// Construct a full throttle now
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - See [`Throttle::new()`]
+    pub fn start<R>(clock: &Clock, rate: R, burst: u32) -> Result<Self, TimeError>
+    where
+        R: Rate + FixedPoint,
+    {
+        Ok(Self::new(clock.try_now()?, rate, burst)?)
+    }
+
+    /// Returns `true` if the event may happen now, taking a token
+    pub fn ready(&mut self, now: Instant<Clock>) -> bool {
+        if self.available(now) == 0 {
+            return false;
+        }
+
+        self.tokens -= 1;
+        true
+    }
+
+    /// Block until the event may happen, taking a token
+    ///
+    /// # Errors
+    ///
+    /// [`clock::Error`]: The clock could not be read
+    pub fn wait(&mut self, clock: &Clock) -> Result<(), clock::Error> {
+        while !self.ready(clock.try_now()?) {}
+
+        Ok(())
+    }
+
+    /// Returns the number of events that may happen now
+    pub fn available(&mut self, now: Instant<Clock>) -> u32 {
+        self.refill(now);
+        self.tokens
+    }
+
+    fn refill(&mut self, now: Instant<Clock>) {
+        // `now` can't precede the last refill
+        let elapsed = now.wrapping_ticks_since(&self.refilled).into_u128();
+        let accrued = elapsed / self.interval.into_u128();
+
+        if accrued >= u128::from(self.burst - self.tokens) {
+            self.tokens = self.burst;
+            self.refilled = now;
+        } else if let Option::Some(ticks) =
+            Clock::T::try_from_u128(accrued * self.interval.into_u128())
+        {
+            // accrued < burst
+            self.tokens += accrued as u32;
+            self.refilled = self.refilled.wrapping_add_ticks(ticks);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! A [`Clock`](crate::Clock) from a counter-read function
+//!
+//! A HAL whose timer is a plain free-running counter doesn't need its own `Clock` implementation:
+//! [`TickClock`] takes the function reading the counter, and the frequency (and width) as `const`
+//! parameters.
+
+use crate::{clock, fraction::Fraction, Instant};
+use core::{cell::RefCell, fmt};
+
+/// A [`Clock`](crate::Clock) reading a free-running, `BITS`-wide (default: 32) counter
+/// incrementing at `HZ` (which must not be `0`)
+///
+/// The counter-read function may be stateful (`FnMut`, eg. owning the timer peripheral or
+/// extending a narrower counter in software), but must not read the `TickClock` itself. Its
+/// values must be less than 2<sup>`BITS`</sup>.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::{
+///     convert::TryFrom,
+///     sync::atomic::{AtomicU32, Ordering},
+/// };
+/// use embedded_time::{duration::*, tick::TickClock, Clock as _, Instant};
+///
+/// // stands in for a timer's counter register
+/// static COUNTER: AtomicU32 = AtomicU32::new(0);
+///
+/// let rtc = TickClock::<32_768, _>::new(|| COUNTER.load(Ordering::Relaxed));
+/// let start = rtc.try_now().unwrap();
+///
+/// COUNTER.store(49_152, Ordering::Relaxed);
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(rtc.try_now().unwrap() - start),
+///     Ok(Milliseconds(1_500))
+/// );
+///
+/// // a 24-bit counter at 16 MHz (eg. SysTick counting up)
+/// let systick = TickClock::<16_000_000, _, 24>::new(|| COUNTER.load(Ordering::Relaxed));
+/// assert_eq!(systick.try_now(), Ok(Instant::new(49_152)));
+/// ```
+pub struct TickClock<const HZ: u32, F, const BITS: u32 = 32> {
+    read: RefCell<F>,
+}
+
+impl<const HZ: u32, F: FnMut() -> u32, const BITS: u32> TickClock<HZ, F, BITS> {
+    /// Construct from the function returning the counter value
+    pub const fn new(read: F) -> Self {
+        Self {
+            read: RefCell::new(read),
+        }
+    }
+
+    /// Returns the counter-read function
+    pub fn into_inner(self) -> F {
+        self.read.into_inner()
+    }
+}
+
+impl<const HZ: u32, F: FnMut() -> u32, const BITS: u32> crate::Clock for TickClock<HZ, F, BITS> {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, HZ);
+    const COUNTER_BITS: u32 = BITS;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new((self.read.borrow_mut())()))
+    }
+}
+
+impl<const HZ: u32, F, const BITS: u32> fmt::Debug for TickClock<HZ, F, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TickClock")
+            .field("hz", &HZ)
+            .field("bits", &BITS)
+            .finish()
+    }
+}
@@ -1,7 +1,10 @@
 use crate::fraction::Fraction;
-use core::{fmt, ops};
+use core::{convert::TryFrom, fmt, ops};
 
 /// The core inner-type trait for time-related types
+///
+/// Implemented for `u8` through `u128`. Signed integers (including `i128`) are not: durations,
+/// rates, and tick counts are unsigned throughout, and the math widens into `u128`.
 #[doc(hidden)]
 pub trait TimeInt:
     Copy
@@ -9,11 +12,12 @@ pub trait TimeInt:
     + num::Bounded
     + num::traits::WrappingAdd
     + num::traits::WrappingSub
+    + num::traits::WrappingMul
     + num::CheckedAdd
     + num::CheckedSub
     + num::CheckedMul
     + num::CheckedDiv
-    + From<u32>
+    + From<u8>
     + ops::Mul<Fraction, Output = Self>
     + ops::Div<Fraction, Output = Self>
     + fmt::Display
@@ -21,10 +25,11 @@ pub trait TimeInt:
 {
     /// Checked integer × [`Fraction`] = integer
     ///
-    /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure
+    /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure. The intermediate
+    /// product is widened, so only the result must fit (except for `u128`, where the product must
+    /// fit as well).
     fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
-        self.checked_mul(&(*fraction.numerator()).into())?
-            .checked_div(&(*fraction.denominator()).into())
+        fraction.try_mul_integer(*self).ok()
     }
 
     /// Checked integer / [`Fraction`] = integer
@@ -33,10 +38,34 @@ pub trait TimeInt:
     fn checked_div_fraction(&self, fraction: &Fraction) -> Option<Self> {
         self.checked_mul_fraction(&fraction.recip())
     }
+
+    /// Widen to `u128` (for lossless intermediate math)
+    fn into_u128(self) -> u128;
+
+    /// Narrow from `u128`
+    ///
+    /// Returns [`None`] if the value doesn't fit
+    fn try_from_u128(value: u128) -> Option<Self>;
 }
 
-impl TimeInt for u32 {}
-impl TimeInt for u64 {}
+macro_rules! impl_time_int {
+    ( $int:ty ) => {
+        impl TimeInt for $int {
+            fn into_u128(self) -> u128 {
+                self.into()
+            }
+
+            fn try_from_u128(value: u128) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+        }
+    };
+}
+impl_time_int![u8];
+impl_time_int![u16];
+impl_time_int![u32];
+impl_time_int![u64];
+impl_time_int![u128];
 
 #[cfg(test)]
 mod tests {
@@ -54,6 +83,27 @@ mod tests {
             8_u32.checked_mul_fraction(&Fraction::new(1, 3)),
             Some(2_u32)
         );
+
+        // the intermediate product doesn't overflow
+        assert_eq!(
+            u32::MAX.checked_mul_fraction(&Fraction::new(3, 4)),
+            Some(3_221_225_471_u32)
+        );
+        assert_eq!(
+            u64::MAX.checked_mul_fraction(&Fraction::new(1_000, 1_024)),
+            Some(18_014_398_509_481_983_999_u64)
+        );
+        assert_eq!(u32::MAX.checked_mul_fraction(&Fraction::new(2, 1)), None);
+
+        // a `u128` product can't be widened
+        assert_eq!(
+            (u128::MAX / 2).checked_mul_fraction(&Fraction::new(1, 1)),
+            Some(u128::MAX / 2)
+        );
+        assert_eq!(
+            (u128::MAX / 2).checked_mul_fraction(&Fraction::new(3, 4)),
+            None
+        );
     }
 
     #[test]
@@ -5,7 +5,7 @@ use crate::{
     timer::param::*,
     ConversionError, Instant, TimeError,
 };
-use core::{convert::TryFrom, marker::PhantomData, ops::Add, prelude::v1::*};
+use core::{convert::TryFrom, fmt, marker::PhantomData, ops::Add, prelude::v1::*};
 
 pub(crate) mod param {
     #[derive(Debug, Hash)]
@@ -26,15 +26,28 @@ pub(crate) mod param {
 
 /// A `Timer` counts toward an expiration, can be polled for elapsed and remaining time, and can be
 /// one-shot or continuous/periodic.
-#[derive(Debug, Hash)]
+#[derive(Hash)]
 pub struct Timer<'a, Type, State, Clock: crate::Clock, Dur: Duration> {
     clock: &'a Clock,
     duration: Dur,
     expiration: Instant<Clock>,
+    slack: Clock::T,
+    catch_up: CatchUp,
     _type: PhantomData<Type>,
     _state: PhantomData<State>,
 }
 
+/// What a periodic [`Timer`] does about the periods missed when it is polled (or waited on) late
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CatchUp {
+    /// Complete each missed period in turn, one per poll, until caught up (keeps the count of
+    /// completed periods)
+    #[default]
+    Burst,
+    /// Skip the missed periods, the next expiration being the first one after the late poll
+    Skip,
+}
+
 impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'_, param::None, param::None, Clock, Dur> {
     /// Construct a new, `OneShot` `Timer`
     #[allow(clippy::new_ret_no_self)]
@@ -43,12 +56,177 @@ impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'_, param::None, param::None,
             clock,
             duration,
             expiration: Instant::new(Clock::T::from(0)),
+            slack: Clock::T::from(0),
+            catch_up: CatchUp::Burst,
             _type: PhantomData,
             _state: PhantomData,
         }
     }
 }
 
+impl<Type, State, Clock: crate::Clock, Dur: Duration + fmt::Debug> fmt::Debug
+    for Timer<'_, Type, State, Clock, Dur>
+{
+    /// Shows the timer type and state along with its duration and expiration
+    ///
+    /// The clock is not read, so the remaining time is not shown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn name<T>() -> &'static str {
+            let path = core::any::type_name::<T>();
+            path.rsplit("::").next().unwrap_or(path)
+        }
+
+        f.debug_struct("Timer")
+            .field("type", &format_args!("{}", name::<Type>()))
+            .field("state", &format_args!("{}", name::<State>()))
+            .field("duration", &self.duration)
+            .field("expiration", &self.expiration)
+            .field("slack_ticks", &self.slack)
+            .field("catch_up", &self.catch_up)
+            .finish()
+    }
+}
+
+/// Builds a [`Timer`] from a set of options
+///
+/// Obtained from [`Clock::timer_builder()`](crate::Clock::timer_builder).
+///
+/// There is no priority option: a `Timer` is polled by its owner, so it has nothing to be
+/// prioritized against. Order the servicing of many timers with a
+/// [`TimerQueue`](crate::queue::TimerQueue) instead (its tokens can carry a priority).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, CatchUp, Clock as _, Instant};
+/// # #[derive(Debug)]
+/// # struct Clock;
+/// # impl embedded_time::Clock for Clock {
+/// #     type T = u32;
+/// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+/// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(0))}
+/// # }
+/// let clock = Clock;
+///
+/// let mut timer = clock
+///     .timer_builder()
+///     .periodic(10_u32.milliseconds())
+///     .tolerance(1_u32.milliseconds())
+///     .catch_up(CatchUp::Skip)
+///     .start()
+///     .unwrap();
+/// # assert!(!timer.period_complete().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct TimerBuilder<'a, Type, Clock: crate::Clock, Dur> {
+    clock: &'a Clock,
+    duration: Dur,
+    tolerance: Option<Dur>,
+    catch_up: CatchUp,
+    _type: PhantomData<Type>,
+}
+
+impl<'a, Clock: crate::Clock> TimerBuilder<'a, param::None, Clock, ()> {
+    pub(crate) fn new(clock: &'a Clock) -> Self {
+        Self {
+            clock,
+            duration: (),
+            tolerance: Option::None,
+            catch_up: CatchUp::Burst,
+            _type: PhantomData,
+        }
+    }
+
+    /// Configure a one-shot timer expiring after the given duration
+    pub fn oneshot<Dur: Duration>(self, duration: Dur) -> TimerBuilder<'a, OneShot, Clock, Dur> {
+        TimerBuilder {
+            clock: self.clock,
+            duration,
+            tolerance: Option::None,
+            catch_up: CatchUp::Burst,
+            _type: PhantomData,
+        }
+    }
+
+    /// Configure a periodic timer with the given period
+    pub fn periodic<Dur: Duration>(self, period: Dur) -> TimerBuilder<'a, Periodic, Clock, Dur> {
+        TimerBuilder {
+            clock: self.clock,
+            duration: period,
+            tolerance: Option::None,
+            catch_up: CatchUp::Burst,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<'a, Type, Clock: crate::Clock, Dur: Duration> TimerBuilder<'a, Type, Clock, Dur> {
+    /// Allow the timer to be considered expired up to `tolerance` before its actual expiration
+    ///
+    /// This allows several timers with nearby expirations to be serviced together (coalesced). A
+    /// non-zero tolerance must be shorter than the duration (or period).
+    pub fn tolerance(self, tolerance: Dur) -> Self {
+        Self {
+            tolerance: Option::Some(tolerance),
+            ..self
+        }
+    }
+
+    /// Set what a periodic timer does about the periods missed when polled late (default:
+    /// [`CatchUp::Burst`])
+    pub fn catch_up(self, catch_up: CatchUp) -> Self {
+        Self { catch_up, ..self }
+    }
+
+    /// Build the (not yet started) timer
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Overflow`]: The (non-zero) tolerance isn't shorter than the duration, the
+    ///   timer would expire as soon as it's started
+    /// - [`TimeError::ConversionFailure`], [`TimeError::Unspecified`]: The tolerance cannot be
+    ///   converted to clock ticks
+    pub fn build(self) -> Result<Timer<'a, Type, Armed, Clock, Dur>, TimeError>
+    where
+        Dur: FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let slack = match self.tolerance {
+            Option::Some(tolerance)
+                if *tolerance.integer() > Dur::T::from(0)
+                    && tolerance.integer() >= self.duration.integer() =>
+            {
+                return Err(TimeError::Overflow)
+            }
+            Option::Some(tolerance) => tolerance.into_ticks(Clock::SCALING_FACTOR)?,
+            Option::None => Clock::T::from(0),
+        };
+
+        Ok(Timer {
+            clock: self.clock,
+            duration: self.duration,
+            expiration: Instant::new(Clock::T::from(0)),
+            slack,
+            catch_up: self.catch_up,
+            _type: PhantomData,
+            _state: PhantomData,
+        })
+    }
+
+    /// Build and start the timer
+    ///
+    /// # Errors
+    ///
+    /// See [`TimerBuilder::build()`] and [`Timer::start()`]
+    pub fn start(self) -> Result<Timer<'a, Type, Running, Clock, Dur>, TimeError>
+    where
+        Dur: FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        self.build()?.start()
+    }
+}
+
 impl<'a, Type, State, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, State, Clock, Dur> {
     /// Change timer type to one-shot
     pub fn into_oneshot(self) -> Timer<'a, OneShot, State, Clock, Dur> {
@@ -56,6 +234,8 @@ impl<'a, Type, State, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, State,
             clock: self.clock,
             duration: self.duration,
             expiration: self.expiration,
+            slack: self.slack,
+            catch_up: self.catch_up,
             _type: PhantomData,
             _state: PhantomData,
         }
@@ -67,6 +247,8 @@ impl<'a, Type, State, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, State,
             clock: self.clock,
             duration: self.duration,
             expiration: self.expiration,
+            slack: self.slack,
+            catch_up: self.catch_up,
             _type: PhantomData,
             _state: PhantomData,
         }
@@ -88,6 +270,8 @@ impl<'a, Type, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, Armed, Clock,
                 .try_now()?
                 .checked_add(self.duration)
                 .ok_or(ConversionError::Overflow)?,
+            slack: self.slack,
+            catch_up: self.catch_up,
             _type: PhantomData,
             _state: PhantomData,
         })
@@ -95,8 +279,31 @@ impl<'a, Type, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, Armed, Clock,
 }
 
 impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, Dur> {
+    fn _is_expired_at(&self, now: Instant<Clock>) -> bool {
+        now >= self.expiration.wrapping_sub_ticks(self.slack)
+    }
+
     fn _is_expired(&self) -> Result<bool, TimeError> {
-        Ok(self.clock.try_now()? >= self.expiration)
+        Ok(self._is_expired_at(self.clock.try_now()?))
+    }
+
+    /// Restart the timer from this instant, discarding any progress toward the current expiration
+    ///
+    /// # Errors
+    ///
+    /// See [`Timer::start()`]
+    pub fn restart(&mut self) -> Result<(), TimeError>
+    where
+        Clock::T: TryFrom<Dur::T>,
+        Dur: FixedPoint,
+    {
+        self.expiration = self
+            .clock
+            .try_now()?
+            .checked_add(self.duration)
+            .ok_or(ConversionError::Overflow)?;
+
+        Ok(())
     }
 
     /// Returns the [`Duration`] of time elapsed since it was started
@@ -156,10 +363,15 @@ impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'a, OneShot, Running, Clock,
         // since the timer is running, _is_expired() will return a value
         while !self._is_expired()? {}
 
-        Ok(Timer::<param::None, param::None, Clock, Dur>::new(
-            self.clock,
-            self.duration,
-        ))
+        Ok(Timer::<OneShot, Armed, Clock, Dur> {
+            clock: self.clock,
+            duration: self.duration,
+            expiration: Instant::new(Clock::T::from(0)),
+            slack: self.slack,
+            catch_up: self.catch_up,
+            _type: PhantomData,
+            _state: PhantomData,
+        })
     }
 
     /// Check whether the timer has expired
@@ -173,43 +385,67 @@ impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'a, OneShot, Running, Clock,
 impl<Clock: crate::Clock, Dur: Duration> Timer<'_, Periodic, Running, Clock, Dur> {
     /// Block until the timer has expired
     ///
-    /// The timer is restarted
-    pub fn wait(self) -> Result<Self, TimeError>
+    /// The timer is restarted. The next period is scheduled from the previous expiration (rather
+    /// than from when the expiration is observed), so periods don't drift. Periods missed by
+    /// waiting late are handled according to the timer's [`CatchUp`] policy.
+    pub fn wait(mut self) -> Result<Self, TimeError>
     where
         Instant<Clock>: Add<Dur, Output = Instant<Clock>>,
+        Clock::T: TryFrom<Dur::T>,
+        Dur: FixedPoint,
     {
-        // since the timer is running, _is_expired() will return a value
-        while !self._is_expired()? {}
+        let now = loop {
+            let now = self.clock.try_now()?;
+            if self._is_expired_at(now) {
+                break now;
+            }
+        };
+        self.next_period(now);
 
-        Ok(Self {
-            clock: self.clock,
-            duration: self.duration,
-            // The `+` will never panic since this duration has already applied to the same
-            // `Instant` type without a problem
-            expiration: self.expiration + self.duration,
-            _type: PhantomData,
-            _state: PhantomData,
-        })
+        Ok(self)
     }
 
     /// Check whether a _periodic_ timer has elapsed
     ///
-    /// The timer is restarted if it has elapsed.
+    /// The timer is restarted if it has elapsed. As with [`wait()`](Timer::wait), the next period is
+    /// scheduled from the previous expiration, so late polling doesn't accumulate drift, and
+    /// missed periods are handled according to the timer's [`CatchUp`] policy.
     pub fn period_complete(&mut self) -> Result<bool, TimeError>
     where
         Instant<Clock>: Add<Dur, Output = Instant<Clock>>,
+        Clock::T: TryFrom<Dur::T>,
+        Dur: FixedPoint,
     {
-        // since the timer is running, _is_expired() will return a value
-        if self._is_expired()? {
-            // The `+` will never panic since this duration has already applied to the same
-            // `Instant` type without a problem
-            self.expiration = self.expiration + self.duration;
+        let now = self.clock.try_now()?;
+        if self._is_expired_at(now) {
+            self.next_period(now);
 
             Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    /// Schedule the expiration following the one observed (at `now`)
+    fn next_period(&mut self, now: Instant<Clock>)
+    where
+        Clock::T: TryFrom<Dur::T>,
+        Dur: FixedPoint,
+    {
+        // The `+=` will never panic since this duration has already applied to the same
+        // `Instant` type without a problem
+        self.expiration += self.duration;
+
+        if self.catch_up == CatchUp::Skip && now >= self.expiration {
+            if let Ok(period) = self.duration.into_ticks::<Clock::T>(Clock::SCALING_FACTOR) {
+                if period > Clock::T::from(0) {
+                    // the lateness is within the comparison window, so this can't overflow
+                    let missed = now.wrapping_ticks_since(&self.expiration) / period + 1.into();
+                    self.expiration = self.expiration.wrapping_add_ticks(missed * period);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,119 @@
+//! Cooperative time budgets
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::FixedPoint,
+    ConversionError, Instant, TimeError,
+};
+use core::convert::TryFrom;
+use num::CheckedSub;
+
+/// A time budget for a long-running operation that must periodically yield
+///
+/// Long-running loops (flash erase, crypto, etc.) check [`Timeslice::should_yield()`] between
+/// steps and return control to the scheduler once the budget is spent, bounding their latency
+/// impact. [`Timeslice::overrun()`] reports by how much a step overshot the budget.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, timeslice::Timeslice, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let slice = Timeslice::new(Instant::<Clock>::new(100), Milliseconds(5_u32)).unwrap();
+///
+/// assert!(!slice.should_yield(Instant::new(104)));
+/// assert!(slice.should_yield(Instant::new(105)));
+/// assert_eq!(slice.overrun(Instant::new(105)), None);
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(slice.overrun(Instant::new(107)).unwrap()),
+///     Ok(Milliseconds(2_u32))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Timeslice<Clock: crate::Clock> {
+    start: Instant<Clock>,
+    budget: Clock::T,
+}
+
+impl<Clock: crate::Clock> Timeslice<Clock> {
+    /// Start a timeslice at the given instant
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The budget is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The budget
+    ///   could not be converted to clock ticks
+    pub fn new<Dur>(start: Instant<Clock>, budget: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            start,
+            budget: Instant::<Clock>::window_ticks(budget)?,
+        })
+    }
+
+    /// Start a timeslice now
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - See [`Timeslice::new()`]
+    pub fn start<Dur>(clock: &Clock, budget: Dur) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self::new(clock.try_now()?, budget)?)
+    }
+
+    /// Restart the timeslice (with the same budget) at the given instant
+    pub fn restart(&mut self, now: Instant<Clock>) {
+        self.start = now;
+    }
+
+    /// Returns the time elapsed since the start (`0` if `now` precedes the start)
+    pub fn elapsed(&self, now: Instant<Clock>) -> duration::Generic<Clock::T> {
+        now.checked_duration_since(&self.start)
+            .unwrap_or_else(|| duration::Generic::new(Clock::T::from(0), Clock::SCALING_FACTOR))
+    }
+
+    /// Returns `true` once the budget is spent
+    pub fn should_yield(&self, now: Instant<Clock>) -> bool {
+        *self.elapsed(now).integer() >= self.budget
+    }
+
+    /// Returns the remaining budget or [`None`] if it is spent
+    pub fn remaining(&self, now: Instant<Clock>) -> Option<duration::Generic<Clock::T>> {
+        match self.budget.checked_sub(self.elapsed(now).integer()) {
+            Some(remaining) if remaining > Clock::T::from(0) => {
+                Some(duration::Generic::new(remaining, Clock::SCALING_FACTOR))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the amount of time beyond the budget or [`None`] if the budget isn't exceeded
+    pub fn overrun(&self, now: Instant<Clock>) -> Option<duration::Generic<Clock::T>> {
+        let elapsed = *self.elapsed(now).integer();
+        if elapsed > self.budget {
+            Some(duration::Generic::new(
+                elapsed - self.budget,
+                Clock::SCALING_FACTOR,
+            ))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Age-based expiration of cached values
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::FixedPoint,
+    ConversionError, Instant,
+};
+use core::convert::TryFrom;
+
+/// A value that expires a fixed time-to-live after it was stored
+///
+/// Useful for caching sensor calibrations, DNS results, etc.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, ttl::Ttl, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let calibration = Ttl::new(1.0023_f32, Instant::<Clock>::new(0), Seconds(5_u32)).unwrap();
+///
+/// assert_eq!(calibration.get(Instant::new(4_999)), Some(&1.0023));
+/// assert_eq!(calibration.get(Instant::new(5_000)), None);
+/// ```
+#[derive(Debug)]
+pub struct Ttl<T, Clock: crate::Clock> {
+    value: T,
+    stored: Instant<Clock>,
+    ttl: duration::Generic<Clock::T>,
+}
+
+impl<T, Clock: crate::Clock> Ttl<T, Clock> {
+    /// Store a value at the given instant
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The time-to-live is longer than the clock's
+    ///   [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The time-to-live
+    ///   could not be converted to clock ticks
+    pub fn new<Dur>(value: T, now: Instant<Clock>, ttl: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            value,
+            stored: now,
+            ttl: duration::Generic::new(
+                Instant::<Clock>::window_ticks(ttl)?,
+                Clock::SCALING_FACTOR,
+            ),
+        })
+    }
+
+    /// Returns the value if it hasn't expired
+    ///
+    /// The value is considered expired if `now` is before the instant it was stored.
+    pub fn get(&self, now: Instant<Clock>) -> Option<&T> {
+        if self.is_expired(now) {
+            None
+        } else {
+            Some(&self.value)
+        }
+    }
+
+    /// Returns the value mutably if it hasn't expired
+    pub fn get_mut(&mut self, now: Instant<Clock>) -> Option<&mut T> {
+        if self.is_expired(now) {
+            None
+        } else {
+            Some(&mut self.value)
+        }
+    }
+
+    /// Returns `true` if the time-to-live has elapsed
+    pub fn is_expired(&self, now: Instant<Clock>) -> bool {
+        match now.checked_duration_since(&self.stored) {
+            Some(age) => age.integer() >= self.ttl.integer(),
+            None => true,
+        }
+    }
+
+    /// Replace the value, restarting the time-to-live from `now`
+    pub fn refresh(&mut self, value: T, now: Instant<Clock>) {
+        self.value = value;
+        self.stored = now;
+    }
+
+    /// Returns the instant at which the value was stored
+    pub fn stored_at(&self) -> Instant<Clock> {
+        self.stored
+    }
+
+    /// Consumes the `Ttl`, returning the value regardless of expiration
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
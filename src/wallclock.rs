@@ -0,0 +1,578 @@
+//! Wall-clock (calendar) time
+//!
+//! A [`Timestamp`] is a point in time relative to the Unix epoch (1970-01-01 00:00:00 UTC), as
+//! handed out by an RTC or a network/GNSS time source. Unlike an [`Instant`](crate::Instant), it
+//! doesn't depend on a [`Clock`](crate::Clock) or the boot session it was read in.
+//!
+//! A [`DateTime`] breaks a timestamp down into its calendar date and time of day (in UTC, eg. for
+//! the registers of an RTC), and back.
+//!
+//! Timestamps are in UTC. A [`TaiTimestamp`] is a separate type (so that the two time scales can't
+//! be compared by mistake), converted to and from UTC with the current [`LeapSeconds`] offset (eg.
+//! as broadcast by GNSS satellites).
+//!
+//! A [`GpsTime`] is the week number and time of week reported by GNSS receivers, converted to and
+//! from TAI exactly (GPS time is a fixed 19 s behind TAI) and to and from UTC with a
+//! [`LeapSeconds`] offset.
+//!
+//! With the `chrono` feature, timestamps convert to and from `chrono::NaiveDateTime` (in UTC).
+
+use crate::{
+    duration::{Duration, Milliseconds, Seconds},
+    fixed_point::FixedPoint,
+    time_int::TimeInt,
+    ConversionError,
+};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const SECONDS_PER_DAY: u64 = 86_400;
+/// The days from 0000-03-01 (the start of a proleptic Gregorian 400-year era) to the Unix epoch
+const EPOCH_DAYS: u64 = 719_468;
+const DAYS_PER_ERA: u64 = 146_097;
+/// The Unix timestamp of the GPS epoch, 1980-01-06 00:00:00 UTC
+const GPS_EPOCH: u64 = 315_964_800;
+/// The TAI timestamp of the GPS epoch (TAI − UTC was 19 s)
+const GPS_EPOCH_TAI: u64 = GPS_EPOCH + 19;
+const SECONDS_PER_WEEK: u64 = 604_800;
+
+/// A point in time since the Unix epoch, with nanosecond resolution
+///
+/// Like Unix time, leap seconds are not counted (every day is 86,400 seconds long).
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, wallclock::Timestamp};
+///
+/// let timestamp = Timestamp::from_unix_seconds(1_600_000_000);
+///
+/// assert_eq!(
+///     timestamp.checked_add(Milliseconds(1_500_u32)),
+///     Some(Timestamp::new(1_600_000_001, 500_000_000).unwrap())
+/// );
+/// assert_eq!(
+///     timestamp.duration_since_epoch(),
+///     Ok(Seconds(1_600_000_000_u32))
+/// );
+/// assert_eq!(
+///     Timestamp::from_duration(Milliseconds(1_500_u32)),
+///     Ok(Timestamp::new(1, 500_000_000).unwrap())
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Timestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl Timestamp {
+    /// The Unix epoch, 1970-01-01 00:00:00 UTC
+    pub const UNIX_EPOCH: Self = Self::from_unix_seconds(0);
+
+    /// Construct from whole seconds and the nanoseconds within the second
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The nanoseconds are a second or more
+    pub fn new(seconds: u64, nanoseconds: u32) -> Result<Self, ConversionError> {
+        if u128::from(nanoseconds) >= NANOS_PER_SECOND {
+            return Err(ConversionError::Overflow);
+        }
+
+        Ok(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Construct from whole seconds since the Unix epoch
+    pub const fn from_unix_seconds(seconds: u64) -> Self {
+        Self {
+            seconds,
+            nanoseconds: 0,
+        }
+    }
+
+    /// Construct from the duration since the Unix epoch (truncated to nanoseconds)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The timestamp can't be represented
+    pub fn from_duration<Dur>(since_epoch: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        since_epoch
+            .integer()
+            .into_u128()
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.numerator()) * NANOS_PER_SECOND)
+            .map(|nanoseconds| nanoseconds / u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .and_then(Self::from_nanoseconds)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the whole seconds since the Unix epoch
+    pub const fn unix_seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Returns the nanoseconds within the second
+    pub const fn subsec_nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Returns the duration since the Unix epoch (truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`]: The _scaling factor_ of the destination type is `0`
+    /// - [`ConversionError::ConversionFailure`]: The duration doesn't fit in the destination type
+    pub fn duration_since_epoch<Dur>(&self) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let denominator = u128::from(*Dur::SCALING_FACTOR.numerator()) * NANOS_PER_SECOND;
+        if denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+
+        self.nanoseconds()
+            .checked_mul(u128::from(*Dur::SCALING_FACTOR.denominator()))
+            .map(|integer| integer / denominator)
+            .and_then(Dur::T::try_from_u128)
+            .map(Dur::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    /// Add a duration (truncated to nanoseconds), returning [`None`] on overflow
+    pub fn checked_add<Dur>(&self, duration: Dur) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let duration = Self::from_duration(duration).ok()?;
+        Self::from_nanoseconds(self.nanoseconds().checked_add(duration.nanoseconds())?)
+    }
+
+    /// Subtract a duration (truncated to nanoseconds), returning [`None`] if the result would
+    /// precede the Unix epoch
+    pub fn checked_sub<Dur>(&self, duration: Dur) -> Option<Self>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let duration = Self::from_duration(duration).ok()?;
+        Self::from_nanoseconds(self.nanoseconds().checked_sub(duration.nanoseconds())?)
+    }
+
+    /// Returns the duration since an earlier timestamp (truncated), or [`None`] if `earlier` is
+    /// later or the duration doesn't fit in the destination type
+    pub fn checked_duration_since<Dur>(&self, earlier: &Self) -> Option<Dur>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        let elapsed = self.nanoseconds().checked_sub(earlier.nanoseconds())?;
+        Self::from_nanoseconds(elapsed)?.duration_since_epoch().ok()
+    }
+
+    /// Convert to TAI, returning [`None`] on overflow
+    pub fn to_tai(&self, leap_seconds: LeapSeconds) -> Option<TaiTimestamp> {
+        self.checked_add(Seconds(leap_seconds.0)).map(TaiTimestamp)
+    }
+
+    /// Returns the nanoseconds since the Unix epoch
+    fn nanoseconds(&self) -> u128 {
+        u128::from(self.seconds) * NANOS_PER_SECOND + u128::from(self.nanoseconds)
+    }
+
+    fn from_nanoseconds(nanoseconds: u128) -> Option<Self> {
+        Option::Some(Self {
+            seconds: u64::try_from_u128(nanoseconds / NANOS_PER_SECOND)?,
+            nanoseconds: (nanoseconds % NANOS_PER_SECOND) as u32,
+        })
+    }
+}
+
+/// The offset of TAI from UTC (TAI − UTC) in whole seconds, ie. the leap seconds inserted since
+/// 1972 plus the initial 10 seconds
+///
+/// The offset changes whenever a leap second is inserted (it was `37` as of 2017), so it's
+/// provided by the application (eg. from a GNSS receiver's almanac) rather than built in.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct LeapSeconds(pub u32);
+
+/// A point in TAI (International Atomic Time), relative to 1970-01-01 00:00:00 TAI
+///
+/// Unlike (UTC) [`Timestamp`]s, TAI counts every second, so durations between TAI timestamps
+/// include any leap seconds inserted meanwhile.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, wallclock::*};
+///
+/// let leap_seconds = LeapSeconds(37);
+/// let utc = Timestamp::from_unix_seconds(1_600_000_000);
+/// let tai = utc.to_tai(leap_seconds).unwrap();
+///
+/// assert_eq!(tai, TaiTimestamp(Timestamp::from_unix_seconds(1_600_000_037)));
+/// assert_eq!(tai.to_utc(leap_seconds), Some(utc));
+///
+/// // the 2016-12-31 leap second
+/// let before = Timestamp::from_unix_seconds(1_483_228_799).to_tai(LeapSeconds(36)).unwrap();
+/// let after = Timestamp::from_unix_seconds(1_483_228_800).to_tai(LeapSeconds(37)).unwrap();
+/// assert_eq!(after.checked_duration_since(&before), Some(Seconds(2_u32)));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct TaiTimestamp(pub Timestamp);
+
+impl TaiTimestamp {
+    /// Convert to UTC, returning [`None`] if the result would precede the Unix epoch
+    pub fn to_utc(&self, leap_seconds: LeapSeconds) -> Option<Timestamp> {
+        self.0.checked_sub(Seconds(leap_seconds.0))
+    }
+
+    /// Returns the duration since an earlier TAI timestamp (see
+    /// [`Timestamp::checked_duration_since()`])
+    pub fn checked_duration_since<Dur>(&self, earlier: &Self) -> Option<Dur>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        self.0.checked_duration_since(&earlier.0)
+    }
+}
+
+/// A GPS week number and time of week
+///
+/// GPS time runs from 1980-01-06 00:00:00 UTC without counting leap seconds. Receivers often
+/// broadcast the week number truncated to 10 (or 13) bits, so it rolls over every 1,024 (8,192)
+/// weeks; [`GpsTime::from_truncated_week()`] recovers the full week number.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, wallclock::*};
+///
+/// // week 2,148 broadcast as 100 (since the 2019 rollover)
+/// let build_date = Timestamp::from_unix_seconds(1_600_000_000);
+/// let gps_time =
+///     GpsTime::from_truncated_week(100, 10, Milliseconds(86_400_000_u32), build_date).unwrap();
+/// assert_eq!(
+///     gps_time,
+///     GpsTime {
+///         week: 2_148,
+///         tow: Milliseconds(86_400_000_u32)
+///     }
+/// );
+///
+/// // GPS − UTC was 18 s (TAI − UTC 37 s) in 2021
+/// assert_eq!(
+///     gps_time.to_utc(LeapSeconds(37)),
+///     Ok(Timestamp::from_unix_seconds(1_615_161_582))
+/// );
+/// assert_eq!(
+///     GpsTime::from_utc(Timestamp::from_unix_seconds(1_615_161_582), LeapSeconds(37)),
+///     Ok(gps_time)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct GpsTime {
+    /// The (full) week number since the GPS epoch
+    pub week: u32,
+    /// The time since the start of the week (Sunday 00:00:00 GPS time), less than a week
+    pub tow: Milliseconds<u32>,
+}
+
+impl GpsTime {
+    /// Construct from a truncated (rolled over) week number
+    ///
+    /// `week_bits` is the number of bits the week number was truncated to (eg. `10` for the
+    /// legacy navigation message, `13` for CNAV). The full week number is taken as the first
+    /// matching one at or after the week of `pivot`, a UTC time known to precede the current time
+    /// by less than one rollover period (eg. the firmware's build date).
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The full week number doesn't fit in a `u32`
+    pub fn from_truncated_week(
+        week: u32,
+        week_bits: u32,
+        tow: Milliseconds<u32>,
+        pivot: Timestamp,
+    ) -> Result<Self, ConversionError> {
+        let pivot_week = pivot.seconds.saturating_sub(GPS_EPOCH) / SECONDS_PER_WEEK;
+        let period = 1_u64.checked_shl(week_bits).unwrap_or(0);
+        let week = match period {
+            // not truncated
+            0 => u64::from(week),
+            period => {
+                let week = u64::from(week) % period;
+                let offset = (week + period - pivot_week % period) % period;
+                pivot_week + offset
+            }
+        };
+
+        Ok(Self {
+            week: u32::try_from_u128(u128::from(week)).ok_or(ConversionError::Overflow)?,
+            tow,
+        })
+    }
+
+    /// Construct from a TAI timestamp (the fraction of a millisecond is truncated)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`]: The timestamp precedes the GPS epoch
+    /// - [`ConversionError::Overflow`]: The week number doesn't fit in a `u32`
+    pub fn from_tai(tai: TaiTimestamp) -> Result<Self, ConversionError> {
+        let seconds = tai
+            .0
+            .seconds
+            .checked_sub(GPS_EPOCH_TAI)
+            .ok_or(ConversionError::ConversionFailure)?;
+        let week = u32::try_from_u128(u128::from(seconds / SECONDS_PER_WEEK))
+            .ok_or(ConversionError::Overflow)?;
+        let tow = (seconds % SECONDS_PER_WEEK) * 1_000 + u64::from(tai.0.nanoseconds / 1_000_000);
+
+        Ok(Self {
+            week,
+            tow: Milliseconds(tow as u32),
+        })
+    }
+
+    /// Construct from a UTC timestamp
+    ///
+    /// # Errors
+    ///
+    /// See [`GpsTime::from_tai()`], [`ConversionError::Overflow`]: The TAI timestamp can't be
+    /// represented
+    pub fn from_utc(utc: Timestamp, leap_seconds: LeapSeconds) -> Result<Self, ConversionError> {
+        Self::from_tai(utc.to_tai(leap_seconds).ok_or(ConversionError::Overflow)?)
+    }
+
+    /// Returns the TAI timestamp
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`]: The time of week is a week or more
+    pub fn to_tai(&self) -> Result<TaiTimestamp, ConversionError> {
+        if u64::from(self.tow.0) >= SECONDS_PER_WEEK * 1_000 {
+            return Err(ConversionError::Overflow);
+        }
+
+        Timestamp::from_unix_seconds(GPS_EPOCH_TAI + u64::from(self.week) * SECONDS_PER_WEEK)
+            .checked_add(self.tow)
+            .map(TaiTimestamp)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns the UTC timestamp
+    ///
+    /// # Errors
+    ///
+    /// See [`GpsTime::to_tai()`]
+    pub fn to_utc(&self, leap_seconds: LeapSeconds) -> Result<Timestamp, ConversionError> {
+        // GPS time never precedes UTC by more than the leap seconds since 1980
+        self.to_tai()?
+            .to_utc(leap_seconds)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+}
+
+/// A (proleptic Gregorian) calendar date and time of day in UTC, to the second
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::wallclock::{DateTime, Timestamp};
+///
+/// let date_time = DateTime {
+///     year: 2024,
+///     month: 2,
+///     day: 29,
+///     hour: 13,
+///     minute: 5,
+///     second: 9,
+/// };
+///
+/// assert_eq!(date_time.to_timestamp(), Ok(Timestamp::from_unix_seconds(1_709_211_909)));
+/// assert_eq!(
+///     DateTime::from_timestamp(Timestamp::from_unix_seconds(1_709_211_909)),
+///     Ok(date_time)
+/// );
+/// assert_eq!(date_time.weekday(), 4); // Thursday
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DateTime {
+    /// The year (`1970` or later)
+    pub year: u32,
+    /// The month, from `1` (January) to `12`
+    pub month: u8,
+    /// The day of the month, from `1`
+    pub day: u8,
+    /// The hour, from `0` to `23`
+    pub hour: u8,
+    /// The minute, from `0` to `59`
+    pub minute: u8,
+    /// The second, from `0` to `59`
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Break a timestamp down into its date and time of day (the fraction of a second is
+    /// truncated)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`]: The year doesn't fit in a `u32`
+    pub fn from_timestamp(timestamp: Timestamp) -> Result<Self, ConversionError> {
+        let days = timestamp.seconds / SECONDS_PER_DAY;
+        let seconds = timestamp.seconds % SECONDS_PER_DAY;
+
+        // days since 0000-03-01, in 400-year eras (March-based years put the leap day last)
+        let days = u128::from(days) + u128::from(EPOCH_DAYS);
+        let era = days / u128::from(DAYS_PER_ERA);
+        let day_of_era = days % u128::from(DAYS_PER_ERA);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let (month, year) = if month < 10 {
+            (month + 3, era * 400 + year_of_era)
+        } else {
+            (month - 9, era * 400 + year_of_era + 1)
+        };
+
+        Ok(Self {
+            year: u32::try_from_u128(year).ok_or(ConversionError::ConversionFailure)?,
+            month: month as u8,
+            day: day as u8,
+            hour: (seconds / 3_600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        })
+    }
+
+    /// Returns the timestamp of the date and time
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`]: The date precedes the Unix epoch
+    /// - [`ConversionError::Overflow`]: A field is out of range (eg. February 30th)
+    pub fn to_timestamp(&self) -> Result<Timestamp, ConversionError> {
+        if self.month == 0
+            || self.month > 12
+            || self.day == 0
+            || self.day > days_in_month(self.year, self.month)
+            || self.hour > 23
+            || self.minute > 59
+            || self.second > 59
+        {
+            return Err(ConversionError::Overflow);
+        }
+        if self.year < 1970 {
+            return Err(ConversionError::ConversionFailure);
+        }
+
+        // March-based year (see `from_timestamp()`)
+        let month = u64::from(self.month);
+        let (year, month) = if month > 2 {
+            (u64::from(self.year), month - 3)
+        } else {
+            (u64::from(self.year) - 1, month + 9)
+        };
+        let year_of_era = year % 400;
+        let day_of_year = (153 * month + 2) / 5 + u64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = year / 400 * DAYS_PER_ERA + day_of_era - EPOCH_DAYS;
+
+        Ok(Timestamp::from_unix_seconds(
+            days * SECONDS_PER_DAY
+                + u64::from(self.hour) * 3_600
+                + u64::from(self.minute) * 60
+                + u64::from(self.second),
+        ))
+    }
+
+    /// Returns the ISO 8601 day of the week, from `1` (Monday) to `7` (Sunday)
+    ///
+    /// The date must be valid (see [`DateTime::to_timestamp()`]).
+    pub fn weekday(&self) -> u8 {
+        // Sakamoto's method (`0` is Sunday)
+        const OFFSETS: [u64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let month = usize::from(self.month.clamp(1, 12));
+        let year = if month < 3 {
+            u64::from(self.year.saturating_sub(1))
+        } else {
+            u64::from(self.year)
+        };
+        let weekday =
+            (year + year / 4 - year / 100 + year / 400 + OFFSETS[month - 1] + u64::from(self.day))
+                % 7;
+
+        match weekday {
+            0 => 7,
+            weekday => weekday as u8,
+        }
+    }
+}
+
+/// Returns `true` if the (Gregorian) year has a February 29th
+#[allow(clippy::manual_is_multiple_of)]
+pub const fn is_leap_year(year: u32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the month (from `1` to `12`) of the year, or `0` for an invalid
+/// month
+pub const fn days_in_month(year: u32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl core::convert::TryFrom<Timestamp> for chrono::NaiveDateTime {
+    type Error = ConversionError;
+
+    /// Convert to a UTC date and time
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`]: The timestamp is beyond `chrono`'s range
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let seconds = core::convert::TryFrom::try_from(timestamp.seconds)
+            .map_err(|_| ConversionError::ConversionFailure)?;
+
+        chrono::DateTime::from_timestamp(seconds, timestamp.nanoseconds)
+            .map(|date_time| date_time.naive_utc())
+            .ok_or(ConversionError::ConversionFailure)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl core::convert::TryFrom<chrono::NaiveDateTime> for Timestamp {
+    type Error = ConversionError;
+
+    /// Convert from a UTC date and time
+    ///
+    /// A leap second (`chrono`'s nanoseconds of a second or more) is folded into the last
+    /// nanosecond of the preceding second.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`]: The date and time precede the Unix epoch
+    fn try_from(date_time: chrono::NaiveDateTime) -> Result<Self, Self::Error> {
+        let date_time = date_time.and_utc();
+        let seconds = core::convert::TryFrom::try_from(date_time.timestamp())
+            .map_err(|_| ConversionError::ConversionFailure)?;
+        let nanoseconds = date_time
+            .timestamp_subsec_nanos()
+            .min(NANOS_PER_SECOND as u32 - 1);
+
+        Self::new(seconds, nanoseconds)
+    }
+}
@@ -0,0 +1,173 @@
+//! Watchdog feeding
+
+use crate::{
+    accuracy::Ppm,
+    duration::{self, Duration},
+    fixed_point::FixedPoint,
+    time_int::TimeInt,
+    ConversionError, Instant, TimeError,
+};
+use core::{convert::TryFrom, fmt};
+
+/// Feeds a watchdog often enough to never let its timeout elapse
+///
+/// The feed interval is the watchdog timeout shortened by the tolerance (the combined error of the
+/// watchdog's oscillator and the clock, see [`Ppm::combined()`]), rounded down to whole clock
+/// ticks, less one tick for the resolution of the readings. A feed due at exactly the timeout (eg.
+/// a 100 ms timeout fed every 100 ticks of a 1 kHz clock) is never scheduled: it would reset a
+/// watchdog running slightly fast, or be late by up to a tick.
+///
+/// [`poll()`](WatchdogScheduler::poll) must be called at least once per interval (by
+/// [`next_feed()`](WatchdogScheduler::next_feed) at the latest).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{accuracy::Ppm, duration::*, watchdog::WatchdogScheduler, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let feeds = Cell::new(0);
+///
+/// // a 100 ms watchdog, with ±2% for its RC oscillator
+/// let mut watchdog = WatchdogScheduler::new(
+///     Instant::<Clock>::new(0),
+///     Milliseconds(100_u32),
+///     Ppm::from_percent(2),
+///     || feeds.set(feeds.get() + 1),
+/// )
+/// .unwrap();
+/// assert_eq!(feeds.get(), 1);
+///
+/// // 100 ms - 2%, less a tick
+/// assert_eq!(watchdog.next_feed(), Instant::new(97));
+/// assert!(!watchdog.poll(Instant::new(96)));
+/// assert!(watchdog.poll(Instant::new(97)));
+/// assert_eq!(feeds.get(), 2);
+/// assert_eq!(watchdog.next_feed(), Instant::new(194));
+/// ```
+pub struct WatchdogScheduler<Clock: crate::Clock, F: FnMut()> {
+    feed: F,
+    /// The ticks between feeds
+    interval: Clock::T,
+    last_fed: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock, F: FnMut()> WatchdogScheduler<Clock, F> {
+    /// Construct from the watchdog's timeout and the function feeding it, feeding it at the given
+    /// instant
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`]: The feed interval is shorter than a tick (the timeout is
+    ///   too short for the clock's resolution, or the tolerance is 100% or more), or longer than
+    ///   the clock's [valid comparison window](Instant::valid_comparison_window)
+    /// - [`ConversionError::ConversionFailure`], [`ConversionError::Unspecified`]: The timeout
+    ///   could not be converted to clock ticks
+    pub fn new<Dur>(
+        now: Instant<Clock>,
+        timeout: Dur,
+        tolerance: Ppm,
+        feed: F,
+    ) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let ticks: Clock::T = timeout.into_ticks(Clock::SCALING_FACTOR)?;
+
+        // timeout × (1 - tolerance) - 1 tick, rounded down
+        let margin = 1_000_000_u128.saturating_sub(u128::from(tolerance.0));
+        let interval = ticks
+            .into_u128()
+            .checked_mul(margin)
+            .and_then(|product| (product / 1_000_000).checked_sub(1))
+            .and_then(Clock::T::try_from_u128)
+            .filter(|interval| *interval > Clock::T::from(0))
+            .ok_or(ConversionError::Overflow)
+            .and_then(Instant::<Clock>::within_window)?;
+
+        let mut scheduler = Self {
+            feed,
+            interval,
+            last_fed: now,
+        };
+        scheduler.feed(now);
+
+        Ok(scheduler)
+    }
+
+    /// Construct from the watchdog's timeout and the function feeding it, feeding it now
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`]: The clock could not be read
+    /// - See [`WatchdogScheduler::new()`]
+    pub fn start<Dur>(
+        clock: &Clock,
+        timeout: Dur,
+        tolerance: Ppm,
+        feed: F,
+    ) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self::new(clock.try_now()?, timeout, tolerance, feed)?)
+    }
+
+    /// Feed the watchdog if a feed is due, returning `true` if it was fed
+    pub fn poll(&mut self, now: Instant<Clock>) -> bool {
+        // `now` can't precede the last feed
+        if now.wrapping_ticks_since(&self.last_fed) < self.interval {
+            return false;
+        }
+
+        self.feed(now);
+        true
+    }
+
+    /// Feed the watchdog regardless of when it was last fed, restarting the interval from `now`
+    pub fn feed(&mut self, now: Instant<Clock>) {
+        (self.feed)();
+        self.last_fed = now;
+    }
+
+    /// Returns the instant of the next feed (the latest instant to [`poll()`] at)
+    ///
+    /// [`poll()`]: WatchdogScheduler::poll
+    pub fn next_feed(&self) -> Instant<Clock> {
+        self.last_fed.wrapping_add_ticks(self.interval)
+    }
+
+    /// Returns the feed interval
+    pub fn interval(&self) -> duration::Generic<Clock::T> {
+        duration::Generic::new(self.interval, Clock::SCALING_FACTOR)
+    }
+
+    /// Returns the instant the watchdog was last fed at
+    pub fn last_fed(&self) -> Instant<Clock> {
+        self.last_fed
+    }
+
+    /// Returns the function feeding the watchdog
+    pub fn into_inner(self) -> F {
+        self.feed
+    }
+}
+
+impl<Clock: crate::Clock, F: FnMut()> fmt::Debug for WatchdogScheduler<Clock, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchdogScheduler")
+            .field("interval", &self.interval())
+            .field("last_fed", &self.last_fed)
+            .finish()
+    }
+}
@@ -0,0 +1,308 @@
+//! Timing wheels for large numbers of timeouts
+//!
+//! A [`TimerWheel`] hashes timeouts into buckets by expiration, making both inserting and
+//! expiring a timeout _O(1)_ (a [`TimerQueue`](crate::queue::TimerQueue) is _O(log n)_). This
+//! suits eg. network stacks running hundreds of retransmission timers, at the cost of only
+//! ordering expirations to the wheel's resolution.
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, time_int::TimeInt, ConversionError, Instant,
+};
+use core::convert::TryFrom;
+
+/// Storage for one bucket of a [`TimerWheel`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bucket {
+    /// The first entry of the bucket
+    head: Option<usize>,
+}
+
+impl Bucket {
+    /// Construct an empty bucket
+    pub const fn new() -> Self {
+        Self { head: Option::None }
+    }
+}
+
+/// Storage for one timeout of a [`TimerWheel`]
+#[derive(Debug)]
+pub struct Entry<Clock: crate::Clock, Token> {
+    /// The expiration, token, and bucket of a queued timeout
+    timeout: Option<(Instant<Clock>, Token, usize)>,
+    /// The next entry of the same bucket (or of the free list)
+    next: Option<usize>,
+}
+
+impl<Clock: crate::Clock, Token> Entry<Clock, Token> {
+    /// Construct an unused entry
+    pub fn new() -> Self {
+        Self {
+            timeout: Option::None,
+            next: Option::None,
+        }
+    }
+}
+
+impl<Clock: crate::Clock, Token> Default for Entry<Clock, Token> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Clock: crate::Clock, Token: Copy> Copy for Entry<Clock, Token> {}
+
+impl<Clock: crate::Clock, Token: Copy> Clone for Entry<Clock, Token> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Identifies a timeout queued in a [`TimerWheel`] (to cancel it)
+///
+/// A handle is only valid until its timeout expires or is cancelled. It may then identify a
+/// timeout queued later.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Handle(usize);
+
+/// A fixed-capacity hashed timing wheel, each timeout with a caller-defined token (eg. a
+/// connection id)
+///
+/// The wheel's buckets each span one _resolution_ of time. A timeout is placed in the bucket its
+/// expiration falls in (timeouts more than one revolution away share the bucket with nearer ones
+/// and are skipped until due). [`TimerWheel::pop_expired()`] walks the buckets up to the current
+/// time.
+///
+/// Timeouts are expired in the order of their buckets (timeouts within the same bucket in no
+/// particular order). The storage is provided by the caller, the number of buckets and entries
+/// being the lengths of the slices. [`TimerWheel::pop_expired()`] must be called at least once
+/// per [valid comparison window](Instant::valid_comparison_window) (typically on every tick), and
+/// timeouts must expire within that window.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, wheel::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut buckets = [Bucket::new(); 16];
+/// let mut entries = [Entry::new(); 64];
+/// let mut wheel = TimerWheel::<Clock, u8>::new(
+///     &mut buckets,
+///     &mut entries,
+///     Instant::new(0),
+///     Milliseconds(10_u32),
+/// )
+/// .unwrap();
+///
+/// wheel.insert(Instant::new(25), 1).unwrap();
+/// let retransmit = wheel.insert(Instant::new(200), 2).unwrap();
+/// wheel.insert(Instant::new(1_000), 3).unwrap();
+///
+/// assert_eq!(wheel.pop_expired(Instant::new(30)), Some(1));
+/// assert_eq!(wheel.pop_expired(Instant::new(30)), None);
+///
+/// // acknowledged
+/// assert_eq!(wheel.cancel(retransmit), Some(2));
+///
+/// assert_eq!(wheel.pop_expired(Instant::new(999)), None);
+/// assert_eq!(wheel.pop_expired(Instant::new(1_000)), Some(3));
+/// ```
+#[derive(Debug)]
+pub struct TimerWheel<'a, Clock: crate::Clock, Token> {
+    buckets: &'a mut [Bucket],
+    entries: &'a mut [Entry<Clock, Token>],
+    /// The first unused entry
+    free: Option<usize>,
+    /// The ticks spanned by a bucket
+    resolution: Clock::T,
+    /// The bucket being expired
+    cursor: usize,
+    /// The start of the cursor's bucket
+    time: Instant<Clock>,
+    len: usize,
+}
+
+impl<'a, Clock: crate::Clock, Token> TimerWheel<'a, Clock, Token> {
+    /// Construct an empty wheel using the provided storage, starting at `now`
+    ///
+    /// Any timeouts in the storage are dropped.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`]: No buckets were provided
+    /// - [`ConversionError::DivByZero`]: The resolution is shorter than one clock tick
+    /// - [`ConversionError::Overflow`], [`ConversionError::Unspecified`]: The resolution could not
+    ///   be converted to clock ticks
+    pub fn new<Dur>(
+        buckets: &'a mut [Bucket],
+        entries: &'a mut [Entry<Clock, Token>],
+        now: Instant<Clock>,
+        resolution: Dur,
+    ) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        if buckets.is_empty() {
+            return Err(ConversionError::ConversionFailure);
+        }
+        let resolution = *resolution.to_generic(Clock::SCALING_FACTOR)?.integer();
+        if resolution == Clock::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = Bucket::new();
+        }
+        let count = entries.len();
+        for (index, entry) in entries.iter_mut().enumerate() {
+            *entry = Entry {
+                timeout: Option::None,
+                next: Option::Some(index + 1).filter(|next| *next < count),
+            };
+        }
+
+        Ok(Self {
+            buckets,
+            entries,
+            free: Option::Some(0).filter(|_| count > 0),
+            resolution,
+            cursor: 0,
+            time: now,
+            len: 0,
+        })
+    }
+
+    /// Returns the number of queued timeouts
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no timeouts are queued
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of timeouts
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Queue a token to expire at the given instant
+    ///
+    /// An expiration already passed expires on the next [`TimerWheel::pop_expired()`].
+    ///
+    /// # Errors
+    ///
+    /// The wheel is full, the token is returned
+    pub fn insert(&mut self, expiration: Instant<Clock>, token: Token) -> Result<Handle, Token> {
+        let index = match self.free {
+            Option::Some(index) => index,
+            Option::None => return Err(token),
+        };
+
+        let offset = if expiration > self.time {
+            expiration.wrapping_ticks_since(&self.time).into_u128() / self.resolution.into_u128()
+        } else {
+            0
+        };
+        let bucket = ((self.cursor as u128 + offset) % self.buckets.len() as u128) as usize;
+
+        let entry = &mut self.entries[index];
+        self.free = entry.next;
+        entry.timeout = Option::Some((expiration, token, bucket));
+        entry.next = self.buckets[bucket].head.replace(index);
+        self.len += 1;
+
+        Ok(Handle(index))
+    }
+
+    /// Remove a queued timeout, returning its token or [`None`] if it is no longer queued
+    pub fn cancel(&mut self, handle: Handle) -> Option<Token> {
+        let bucket = match self.entries.get(handle.0) {
+            Option::Some(Entry {
+                timeout: Option::Some((_, _, bucket)),
+                ..
+            }) => *bucket,
+            _ => return Option::None,
+        };
+
+        let mut previous = Option::None;
+        let mut current = self.buckets[bucket].head;
+        while let Option::Some(index) = current {
+            if index == handle.0 {
+                return self.remove(bucket, previous, index);
+            }
+            previous = current;
+            current = self.entries[index].next;
+        }
+
+        Option::None
+    }
+
+    /// Remove and return the token of a timeout expired at `now` or [`None`] if no timeout has
+    /// expired
+    ///
+    /// Call repeatedly to collect all the expired timeouts.
+    pub fn pop_expired(&mut self, now: Instant<Clock>) -> Option<Token> {
+        if self.is_empty() {
+            // skip the passed buckets at once
+            if now > self.time {
+                let elapsed = now.wrapping_ticks_since(&self.time).into_u128();
+                let passed = elapsed / self.resolution.into_u128();
+                if let Option::Some(ticks) =
+                    Clock::T::try_from_u128(passed * self.resolution.into_u128())
+                {
+                    self.time = self.time.wrapping_add_ticks(ticks);
+                    self.cursor =
+                        ((self.cursor as u128 + passed) % self.buckets.len() as u128) as usize;
+                }
+            }
+            return Option::None;
+        }
+
+        loop {
+            let mut previous = Option::None;
+            let mut current = self.buckets[self.cursor].head;
+            while let Option::Some(index) = current {
+                if let Option::Some((expiration, _, _)) = &self.entries[index].timeout {
+                    if now >= *expiration {
+                        return self.remove(self.cursor, previous, index);
+                    }
+                }
+                previous = current;
+                current = self.entries[index].next;
+            }
+
+            // move on once the cursor's bucket has passed
+            if now < self.time || now.wrapping_ticks_since(&self.time) < self.resolution {
+                return Option::None;
+            }
+            self.time = self.time.wrapping_add_ticks(self.resolution);
+            self.cursor = (self.cursor + 1) % self.buckets.len();
+        }
+    }
+
+    /// Unlink an entry from its bucket and return it to the free list
+    fn remove(&mut self, bucket: usize, previous: Option<usize>, index: usize) -> Option<Token> {
+        let next = self.entries[index].next;
+        match previous {
+            Option::Some(previous) => self.entries[previous].next = next,
+            Option::None => self.buckets[bucket].head = next,
+        }
+
+        let entry = &mut self.entries[index];
+        entry.next = self.free;
+        self.free = Option::Some(index);
+        self.len -= 1;
+
+        entry.timeout.take().map(|(_, token, _)| token)
+    }
+}
@@ -0,0 +1,68 @@
+use embedded_time::{
+    self as time,
+    accuracy::{ClockAccuracy, Ppm},
+    duration::*,
+    rate::*,
+    Instant,
+};
+
+#[derive(Debug)]
+struct Rtc;
+
+impl time::Clock for Rtc {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+impl ClockAccuracy for Rtc {
+    const ACCURACY: Ppm = Ppm(20);
+}
+
+#[test]
+fn ppm() {
+    assert_eq!(Ppm::default(), Ppm(0));
+    assert_eq!(Ppm::from_percent(5), Ppm(50_000));
+    assert_eq!(Ppm::from_percent(u32::MAX), Ppm(u32::MAX));
+    assert_eq!(Ppm(u32::MAX).combined(Ppm(1)), Ppm(u32::MAX));
+    assert!(Ppm(10) < Ppm(20));
+}
+
+#[test]
+fn durations() {
+    assert!(Seconds(1_u32).within(Seconds(1_u32), Ppm(0)));
+    assert!(!Milliseconds(1_001_u32).within(Seconds(1_u32), Ppm(0)));
+    assert!(Milliseconds(1_001_u32).within(Seconds(1_u32), Ppm(1_000)));
+    assert!(Milliseconds(999_u32).within(Seconds(1_u32), Ppm(1_000)));
+    assert!(!Milliseconds(998_u32).within(Seconds(1_u32), Ppm(1_000)));
+
+    // relative to the expected duration
+    assert!(Seconds(2_u32).within(Seconds(1_u32), Ppm::from_percent(100)));
+    assert!(!Seconds(1_u32).within(Seconds(2_u32), Ppm::from_percent(49)));
+
+    assert!(!Seconds(1_u32).within(Seconds(0_u32), Ppm(u32::MAX)));
+    assert!(Seconds(0_u32).within(Seconds(0_u32), Ppm(0)));
+
+    // across integer types and extreme values
+    assert!(Nanoseconds(u64::MAX).within(Hours(5_124_095_u64), Ppm(1)));
+    assert!(!Nanoseconds(u64::MAX).within(Hours(5_124_095_u64), Ppm(0)));
+    assert!(Hours(u64::MAX).within(Hours(u64::MAX), Ppm(u32::MAX)));
+
+    assert!(Microseconds(999_980_u32).within(Seconds(1_u32), Rtc::ACCURACY));
+    assert!(!Microseconds(999_979_u32).within(Seconds(1_u32), Rtc::ACCURACY));
+}
+
+#[test]
+fn rates() {
+    assert!(Hertz(32_768_u32).within(Kibihertz(32_u32), Ppm(0)));
+    assert!(Hertz(1_001_000_u32).within(Megahertz(1_u32), Ppm(1_000)));
+    assert!(!Hertz(1_001_001_u32).within(Megahertz(1_u32), Ppm(1_000)));
+
+    // a 16 MHz UART clock divided by 139 for 115,200 Bd, within the 2% receiver tolerance
+    let achieved = Hertz(16_000_000_u32 / 139);
+    assert!(achieved.within(Hertz(115_200_u32), Ppm::from_percent(2)));
+    assert!(!Hertz(16_000_000_u32 / 135).within(Hertz(115_200_u32), Ppm::from_percent(2)));
+}
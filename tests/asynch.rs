@@ -0,0 +1,265 @@
+#![cfg(feature = "async")]
+
+use core::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use embedded_time::{
+    self as time, alarm::Alarm, asynch::*, delay::LongDelay, duration::*, Instant, TimeError,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Wake,
+};
+
+/// Reads `None` as a failure
+#[derive(Debug, Default)]
+struct Clock {
+    ticks: Cell<Option<u32>>,
+    alarm_at: Cell<Option<u32>>,
+    alarm: AlarmWaker,
+}
+
+impl Clock {
+    fn new(ticks: u32) -> Self {
+        let clock = Self::default();
+        clock.ticks.set(Some(ticks));
+        clock
+    }
+
+    /// Advance to the given ticks, firing the alarm if due
+    fn set(&self, ticks: u32) {
+        self.ticks.set(Some(ticks));
+        if matches!(self.alarm_at.get(), Some(at) if ticks >= at) {
+            self.alarm_at.set(None);
+            self.alarm.wake();
+        }
+    }
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.ticks
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+impl AsyncClock for Clock {
+    fn schedule_wake(&self, at: Instant<Self>, waker: &Waker) {
+        self.alarm.register(waker);
+        self.alarm_at
+            .set(Some(*at.duration_since_epoch().integer()));
+    }
+}
+
+/// Counts its wake-ups
+#[derive(Default)]
+struct Wakes(AtomicUsize);
+
+impl Wake for Wakes {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn poll<F: Future + Unpin>(future: &mut F, waker: &Waker) -> Poll<F::Output> {
+    Pin::new(future).poll(&mut Context::from_waker(waker))
+}
+
+#[test]
+fn delay() {
+    let wakes = Arc::new(Wakes::default());
+    let waker = Waker::from(wakes.clone());
+    let clock = Clock::new(100);
+
+    let mut delay = clock.delay(Milliseconds(10_u32));
+    assert_eq!(poll(&mut delay, &waker), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(110));
+
+    clock.set(109);
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+    clock.set(110);
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+    assert_eq!(poll(&mut delay, &waker), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn delay_errors() {
+    let waker = Waker::from(Arc::new(Wakes::default()));
+    let clock = Clock::new(0);
+
+    let mut delay = clock.delay(Hours(1_000_u32));
+    assert_eq!(
+        poll(&mut delay, &waker),
+        Poll::Ready(Err(TimeError::Overflow))
+    );
+
+    let mut delay = clock.delay(Milliseconds(10_u32));
+    clock.ticks.set(None);
+    assert_eq!(
+        poll(&mut delay, &waker),
+        Poll::Ready(Err(TimeError::Clock(time::clock::Error::NotRunning)))
+    );
+}
+
+#[test]
+fn long_delay() {
+    let wakes = Arc::new(Wakes::default());
+    let waker = Waker::from(wakes.clone());
+    let clock = Clock::new(0);
+
+    // beyond the ~24.9-day comparison window of the 32-bit, 1 kHz clock
+    let delay = LongDelay::new(&clock, Hours(1_000_u32)).unwrap();
+    let first_chunk = *delay.next_deadline().duration_since_epoch().integer();
+    assert_eq!(delay.remaining_chunks(), 1);
+
+    let mut wait = Box::pin(delay.wait_async());
+    let mut context = Context::from_waker(&waker);
+    assert_eq!(wait.as_mut().poll(&mut context), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(first_chunk));
+
+    clock.set(first_chunk);
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+    assert_eq!(wait.as_mut().poll(&mut context), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(3_600_000_000));
+
+    clock.set(3_600_000_000);
+    assert_eq!(wait.as_mut().poll(&mut context), Poll::Ready(Ok(())));
+
+    let mut wait = Box::pin(LongDelay::new(&clock, Hours(1_u32)).unwrap().wait_async());
+    clock.ticks.set(None);
+    assert_eq!(
+        wait.as_mut().poll(&mut context),
+        Poll::Ready(Err(TimeError::Clock(time::clock::Error::NotRunning)))
+    );
+}
+
+#[test]
+fn ticker() {
+    let waker = Waker::from(Arc::new(Wakes::default()));
+    let clock = Clock::new(0);
+    let mut ticker = Ticker::new(&clock, Milliseconds(100_u32)).unwrap();
+
+    let mut tick = ticker.tick();
+    assert_eq!(poll(&mut tick, &waker), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(100));
+
+    // completing late doesn't delay the next tick
+    clock.set(130);
+    assert_eq!(poll(&mut tick, &waker), Poll::Ready(Ok(())));
+    let mut tick = ticker.tick();
+    assert_eq!(poll(&mut tick, &waker), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(200));
+
+    // missed ticks are caught up
+    clock.set(450);
+    assert_eq!(poll(&mut tick, &waker), Poll::Ready(Ok(())));
+    assert_eq!(poll(&mut ticker.tick(), &waker), Poll::Ready(Ok(())));
+    assert_eq!(poll(&mut ticker.tick(), &waker), Poll::Ready(Ok(())));
+    assert_eq!(poll(&mut ticker.tick(), &waker), Poll::Pending);
+    assert_eq!(clock.alarm_at.get(), Some(500));
+}
+
+#[test]
+fn alarm_waker() {
+    let wakes = Arc::new(Wakes::default());
+    let waker = Waker::from(wakes.clone());
+    let alarm = AlarmWaker::new();
+
+    alarm.wake();
+    alarm.register(&waker);
+    alarm.register(&waker);
+    assert_eq!(format!("{:?}", alarm), "AlarmWaker { registered: true }");
+    alarm.wake();
+    alarm.wake();
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+    assert_eq!(format!("{:?}", alarm), "AlarmWaker { registered: false }");
+}
+
+/// A compare-match timer, reading `None` as a failure
+#[derive(Debug, Default)]
+struct Hardware {
+    ticks: Cell<Option<u32>>,
+    compare: Cell<Option<u32>>,
+}
+
+impl time::Clock for Hardware {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.ticks
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+impl Alarm for Hardware {
+    fn set_alarm(&self, at: Instant<Self>) -> Result<(), time::clock::Error> {
+        time::Clock::try_now(self)?;
+        self.compare.set(Some(*at.duration_since_epoch().integer()));
+        Ok(())
+    }
+
+    fn clear_alarm(&self) {
+        self.compare.set(None);
+    }
+
+    fn alarm_fired(&self) -> bool {
+        let fired = matches!(
+            (self.ticks.get(), self.compare.get()),
+            (Some(now), Some(at)) if now >= at
+        );
+        if fired {
+            self.compare.set(None);
+        }
+        fired
+    }
+}
+
+#[test]
+fn alarm_clock() {
+    let wakes = Arc::new(Wakes::default());
+    let waker = Waker::from(wakes.clone());
+    let clock = AlarmClock::new(Hardware::default());
+    clock.alarm().ticks.set(Some(1_000));
+
+    let mut delay = clock.delay(Milliseconds(5_u32));
+    assert_eq!(poll(&mut delay, &waker), Poll::Pending);
+    assert_eq!(clock.alarm().compare.get(), Some(1_005));
+
+    // spurious interrupt
+    clock.alarm().ticks.set(Some(1_004));
+    clock.on_interrupt();
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+
+    clock.alarm().ticks.set(Some(1_005));
+    clock.on_interrupt();
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+    assert_eq!(poll(&mut delay, &waker), Poll::Ready(Ok(())));
+
+    // the alarm can't be set
+    let mut delay = clock.delay(Milliseconds(5_u32));
+    clock.alarm().ticks.set(None);
+    assert_eq!(
+        poll(&mut delay, &waker),
+        Poll::Ready(Err(TimeError::Clock(time::clock::Error::NotRunning)))
+    );
+
+    clock.alarm().ticks.set(Some(2_000));
+    clock.alarm().set_alarm(Instant::new(2_001)).unwrap();
+    clock.alarm().clear_alarm();
+    clock.alarm().ticks.set(Some(2_001));
+    assert!(!clock.alarm().alarm_fired());
+}
@@ -0,0 +1,121 @@
+use embedded_time::{
+    audio::BlockTiming, duration::*, fixed_point::FixedPoint, rate::*, ConversionError,
+};
+
+#[test]
+fn conversions() {
+    let timing = BlockTiming::new(Hertz(44_100_u32), 128).unwrap();
+    assert_eq!(timing.sample_rate(), Hertz(44_100_u32));
+    assert_eq!(timing.block_size(), 128);
+
+    assert_eq!(timing.samples_to_duration(44_100), Ok(Seconds(1_u32)));
+    assert_eq!(
+        timing.samples_to_duration(441),
+        Ok(Microseconds(10_000_u32))
+    );
+    assert_eq!(timing.block_duration(), Ok(Nanoseconds(2_902_494_u32)));
+    assert_eq!(timing.blocks_to_duration(10_000), Ok(Seconds(29_u32)));
+
+    assert_eq!(timing.duration_to_samples(Minutes(1_u32)), Ok(2_646_000));
+    assert_eq!(timing.duration_to_blocks(Minutes(1_u32)), Ok(20_671));
+
+    assert_eq!(
+        timing.samples_to_duration::<Nanoseconds<u32>>(44_100 * 5),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        BlockTiming::new(Hertz(44_100_u32), 0),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn counter_without_drift() {
+    let timing = BlockTiming::new(Hertz(44_100_u32), 64).unwrap();
+    let mut counter = timing.counter(Milliseconds(1_u32)).unwrap();
+
+    let mut total = 0;
+    for _ in 0..60_000 {
+        let due = counter.tick();
+        assert!(due == 44 || due == 45);
+        total += due;
+    }
+
+    // exactly one minute of samples
+    assert_eq!(total, 2_646_000);
+    assert_eq!(counter.samples(), 2_646_000);
+    assert_eq!(counter.blocks(), 41_343);
+    assert_eq!(counter.partial_block(), 48);
+    assert_eq!(counter.drift(), (0, 10));
+
+    assert_eq!(counter.advance(3), 132);
+    assert_eq!(counter.drift(), (3, 10));
+
+    assert_eq!(
+        timing.counter(Milliseconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+/// A sample rate with a fractional _scaling factor_
+#[derive(Copy, Clone, Debug)]
+struct Decihertz(u32);
+
+impl Rate for Decihertz {}
+
+impl FixedPoint for Decihertz {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 10);
+
+    fn new(value: Self::T) -> Self {
+        Self(value)
+    }
+
+    fn integer(&self) -> &Self::T {
+        &self.0
+    }
+}
+
+#[test]
+fn fractional_sample_rate() {
+    // 44_100.5 Hz
+    let timing = BlockTiming::new(Decihertz(441_005), 1).unwrap();
+    assert_eq!(timing.sample_rate(), Hertz(44_100_u32));
+
+    assert_eq!(timing.duration_to_samples(Seconds(2_u32)), Ok(88_201));
+    assert_eq!(timing.samples_to_duration(88_201), Ok(Seconds(2_u32)));
+    assert_eq!(timing.samples_to_duration(88_200), Ok(Seconds(1_u32)));
+
+    let mut counter = timing.counter(Seconds(1_u32)).unwrap();
+    assert_eq!(counter.tick(), 44_100);
+    assert_eq!(counter.tick(), 44_101);
+    assert_eq!(counter.drift(), (0, 2));
+}
+
+#[test]
+fn overflow() {
+    let timing = BlockTiming::new(Hertz(44_100_u32), 64).unwrap();
+
+    assert_eq!(
+        BlockTiming::new(Hertz(u128::MAX), 64),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        BlockTiming::new(Kilohertz(u32::MAX), 64),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        timing.duration_to_samples(Seconds(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        timing.counter(Seconds(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+
+    // wider than `u64`
+    assert_eq!(
+        timing.samples_to_duration(u64::MAX),
+        Ok(Nanoseconds(u128::from(u64::MAX) * 1_000_000_000 / 44_100))
+    );
+}
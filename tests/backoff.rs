@@ -0,0 +1,89 @@
+use core::cell::Cell;
+use embedded_time::{backoff::Backoff, duration::*};
+
+#[test]
+fn exponential() {
+    let backoff = Backoff::new(
+        Milliseconds(10_u32),
+        Fraction::new(2, 1),
+        Milliseconds(100_u32),
+    );
+
+    assert_eq!(
+        backoff.take(6).collect::<Vec<_>>(),
+        [10_u32, 20, 40, 80, 100, 100]
+            .iter()
+            .map(|delay| Milliseconds(*delay))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn rounds_up() {
+    let mut backoff = Backoff::new(Seconds(1_u64), Fraction::new(11, 10), Seconds(5));
+
+    assert_eq!(backoff.next(), Some(Seconds(1)));
+    assert_eq!(backoff.next(), Some(Seconds(2)));
+    assert_eq!(backoff.next(), Some(Seconds(3)));
+    assert_eq!(backoff.peek(), Seconds(4_u64));
+    assert_eq!(backoff.next(), Some(Seconds(4)));
+    assert_eq!(backoff.next(), Some(Seconds(5)));
+
+    // constant
+    let mut backoff = Backoff::new(Seconds(1_u64), Fraction::new(1, 1), Seconds(5));
+    assert_eq!(backoff.nth(10), Some(Seconds(1)));
+}
+
+#[test]
+fn capped() {
+    // the initial delay
+    let mut backoff = Backoff::new(Seconds(10_u32), Fraction::new(2, 1), Seconds(5));
+    assert_eq!(backoff.next(), Some(Seconds(5)));
+    assert_eq!(backoff.next(), Some(Seconds(5)));
+
+    // an overflowing product
+    let mut backoff = Backoff::new(
+        Seconds(u32::MAX - 1),
+        Fraction::new(2, 1),
+        Seconds(u32::MAX),
+    );
+    assert_eq!(backoff.nth(1), Some(Seconds(u32::MAX)));
+
+    // a `0` denominator
+    let mut backoff = Backoff::new(Seconds(1_u32), Fraction::new(2, 0), Seconds(5));
+    assert_eq!(backoff.nth(1), Some(Seconds(5)));
+}
+
+#[test]
+fn reset() {
+    let mut backoff = Backoff::new(
+        Milliseconds(10_u32),
+        Fraction::new(3, 1),
+        Milliseconds(1_000),
+    );
+
+    assert_eq!(backoff.nth(2), Some(Milliseconds(90)));
+    backoff.reset();
+    assert_eq!(backoff.next(), Some(Milliseconds(10)));
+}
+
+#[test]
+fn jitter() {
+    let calls = Cell::new(0_u32);
+    let mut backoff = Backoff::new(
+        Milliseconds(10_u32),
+        Fraction::new(2, 1),
+        Milliseconds(1_000),
+    )
+    .with_jitter(|delay| {
+        calls.set(calls.get() + 1);
+        delay + Milliseconds(calls.get())
+    });
+
+    // the jitter doesn't compound
+    assert_eq!(backoff.next(), Some(Milliseconds(11)));
+    assert_eq!(backoff.next(), Some(Milliseconds(22)));
+    assert_eq!(backoff.peek(), Milliseconds(40_u32));
+    backoff.reset();
+    assert_eq!(backoff.next(), Some(Milliseconds(13)));
+}
@@ -0,0 +1,60 @@
+use embedded_time::{self as time, boot::*, duration::*, Instant};
+
+#[derive(Debug)]
+struct Clock {
+    boot_id: BootId,
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(10))
+    }
+}
+
+impl BootSession for Clock {
+    fn boot_id(&self) -> BootId {
+        self.boot_id
+    }
+}
+
+#[test]
+fn tagged_now() {
+    let clock = Clock { boot_id: BootId(3) };
+    let tagged = clock.try_now_tagged().unwrap();
+
+    assert_eq!(tagged.boot_id(), BootId(3));
+    assert_eq!(tagged.instant_unchecked(), Instant::new(10));
+    assert!(tagged.is_current(&clock));
+}
+
+#[test]
+fn previous_session() {
+    let stale = TaggedInstant::<Clock>::new(BootId(u32::MAX), Instant::new(2));
+    let clock = Clock {
+        boot_id: BootId(u32::MAX).next(),
+    };
+
+    assert_eq!(clock.boot_id(), BootId(0));
+    assert!(!stale.is_current(&clock));
+    assert_eq!(stale.instant_if_current(&clock), None);
+}
+
+#[test]
+fn duration_since() {
+    let earlier = TaggedInstant::<Clock>::new(BootId(1), Instant::new(2));
+    let later = TaggedInstant::<Clock>::new(BootId(1), Instant::new(7));
+
+    assert_eq!(
+        later.checked_duration_since(&earlier),
+        Some(Generic::new(5_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(earlier.checked_duration_since(&later), None);
+
+    // instants from different sessions cannot be compared
+    let other_session = TaggedInstant::<Clock>::new(BootId(2), Instant::new(7));
+    assert_eq!(other_session.checked_duration_since(&earlier), None);
+    assert_ne!(other_session, later);
+}
@@ -0,0 +1,62 @@
+use core::cell::Cell;
+use embedded_time::{self as time, calibration::*, duration::*, Clock as _, Instant};
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+fn advance(clock: &CalibratedClock<Clock, Linear>, ticks: u32) {
+    let raw = clock.inner();
+    raw.0.set(raw.0.get() + ticks);
+}
+
+struct Linear;
+
+impl CrystalModel for Linear {
+    fn frequency_error_ppb(&self, temperature_millicelsius: i32) -> i32 {
+        temperature_millicelsius
+    }
+}
+
+#[test]
+fn trim() {
+    let clock = CalibratedClock::with_model(Clock(Cell::new(0)), Linear);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    // 1 ppm fast, remainders carried over between readings
+    clock.set_calibration(1_000);
+    for _ in 0..1_000 {
+        advance(&clock, 1_000);
+        clock.try_now().unwrap();
+    }
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_000_001)));
+
+    // compensation for a crystal running 3 ppm fast
+    clock.update_temperature(3_000);
+    assert_eq!(clock.trim_ppb(), -2_000);
+    advance(&clock, 1_000_000);
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_999_999)));
+
+    // limited to ±100%
+    clock.set_calibration(i32::MIN);
+    assert_eq!(clock.trim_ppb(), -MAX_TRIM_PPB);
+    advance(&clock, 1_000_000);
+    assert!(clock.try_now().unwrap() >= Instant::new(1_999_999));
+}
+
+#[test]
+fn without_model() {
+    let clock = CalibratedClock::new(Clock(Cell::new(0)));
+    clock.update_temperature(-40_000);
+
+    assert_eq!(clock.trim_ppb(), 0);
+    assert_eq!(clock.model(), &());
+}
@@ -0,0 +1,156 @@
+use embedded_time::{
+    self as time,
+    capture::{CaptureConverter, PeriodMeasurement},
+    duration::*,
+    duty::DutyCycle,
+    rate::{self, *},
+    ConversionError, Instant,
+};
+
+/// A 16-bit counter at 1 MHz
+#[derive(Debug)]
+struct Timer;
+
+impl time::Clock for Timer {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+    const COUNTER_BITS: u32 = 16;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn captures() {
+    // 32-bit counter at 72 MHz
+    let mut converter = CaptureConverter::new(Megahertz(72_u32), 1, 32).unwrap();
+    assert_eq!(
+        converter.tick_rate(),
+        rate::Generic::new(72_000_000_u32, Fraction::new(1, 1))
+    );
+
+    assert_eq!(converter.capture(u32::MAX - 35_999, 0), Ok(None));
+    assert_eq!(converter.capture(36_000, 1), Ok(Some(72_000)));
+    assert_eq!(converter.capture(108_000, 0), Ok(Some(72_000)));
+    assert_eq!(converter.capture(0, 0), Err(ConversionError::NegDuration));
+
+    converter.reset();
+    assert_eq!(converter.capture(5, 3), Ok(None));
+
+    assert_eq!(converter.ticks_to_duration(72_000), Ok(Milliseconds(1_u32)));
+    assert_eq!(converter.ticks_to_rate(72_000), Ok(Kilohertz(1_u32)));
+    assert_eq!(
+        converter.ticks_to_rate::<Hertz<u32>>(0),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn prescaler_and_width() {
+    // 12-bit counter at 1 MHz / 4
+    let converter = CaptureConverter::new(Megahertz(1_u32), 4, 12).unwrap();
+
+    // bits beyond the counter width are ignored
+    assert_eq!(converter.elapsed_ticks(0xF_0FFF, 0x1_0009, 2), Ok(4_106));
+    assert_eq!(
+        converter.ticks_to_duration(4_106),
+        Ok(Microseconds(16_424_u32))
+    );
+    assert_eq!(converter.ticks_to_rate(250), Ok(Hertz(1_000_u32)));
+    // wider than `u64`
+    assert_eq!(
+        converter.ticks_to_duration(u64::MAX),
+        Ok(Nanoseconds(u128::from(u64::MAX) * 4_000))
+    );
+
+    assert_eq!(
+        CaptureConverter::new(Megahertz(1_u32), 0, 16),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        CaptureConverter::new(Megahertz(1_u32), 1, 33),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn period_measurement() {
+    let mut measurement = PeriodMeasurement::<Timer>::new(4).unwrap();
+
+    // a 2.5 kHz, 30% signal, across a counter wrap
+    assert_eq!(measurement.rising(Instant::new(65_000)), None);
+    for period in 1..4 {
+        measurement.falling(Instant::new((65_000 + 400 * period - 280) % 65_536));
+        assert_eq!(
+            measurement.rising(Instant::new((65_000 + 400 * period) % 65_536)),
+            None
+        );
+    }
+    assert_eq!(measurement.last(), None);
+    measurement.falling(Instant::new(784));
+    let measured = measurement.rising(Instant::new(1_064)).unwrap();
+
+    assert_eq!(measurement.last(), Some(measured));
+    assert_eq!(measured.samples(), 4);
+    assert_eq!(measured.period(), Ok(Microseconds(400_u32)));
+    assert_eq!(measured.period(), Ok(Nanoseconds(400_000_u64)));
+    assert_eq!(measured.frequency(), Ok(Hertz(2_500_u32)));
+    assert_eq!(measured.frequency(), Ok(Kilohertz(2_u32)));
+    assert_eq!(
+        measured.duty_cycle(),
+        Ok(Some(DutyCycle::from_percent(30).unwrap()))
+    );
+
+    // the next measurement starts at the last rising edge, without falling edges
+    for period in 1..=3 {
+        assert_eq!(measurement.rising(Instant::new(1_064 + 500 * period)), None);
+    }
+    let measured = measurement.rising(Instant::new(3_064)).unwrap();
+    assert_eq!(measured.period(), Ok(Microseconds(500_u32)));
+    assert_eq!(measured.duty_cycle(), Ok(None));
+
+    measurement.reset();
+    assert_eq!(measurement.last(), None);
+    // ignored
+    measurement.falling(Instant::new(4_000));
+    assert_eq!(measurement.rising(Instant::new(4_100)), None);
+}
+
+#[test]
+fn period_measurement_averaging() {
+    let mut measurement = PeriodMeasurement::<Timer>::new(3).unwrap();
+
+    measurement.rising(Instant::new(0));
+    measurement.rising(Instant::new(99));
+    measurement.rising(Instant::new(201));
+    let measured = measurement.rising(Instant::new(301)).unwrap();
+
+    // 301 µs / 3, truncated
+    assert_eq!(measured.period(), Ok(Microseconds(100_u32)));
+    assert_eq!(measured.period(), Ok(Nanoseconds(100_333_u32)));
+    assert_eq!(measured.frequency(), Ok(Hertz(9_966_u32)));
+}
+
+#[test]
+fn period_measurement_errors() {
+    assert_eq!(
+        PeriodMeasurement::<Timer>::new(0).map(|_| ()),
+        Err(ConversionError::DivByZero)
+    );
+
+    let mut measurement = PeriodMeasurement::<Timer>::new(1).unwrap();
+    measurement.rising(Instant::new(10));
+    let measured = measurement.rising(Instant::new(10)).unwrap();
+    assert_eq!(
+        measured.frequency::<Hertz>(),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(measured.duty_cycle(), Ok(None));
+
+    let measured = measurement.rising(Instant::new(60_010)).unwrap();
+    assert_eq!(
+        measured.period::<Nanoseconds<u16>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+}
@@ -0,0 +1,64 @@
+#![allow(deprecated)]
+
+use core::time::Duration as CoreDuration;
+use embedded_time::{
+    compat::*,
+    duration::*,
+    rate::{self, *},
+    ConversionError,
+};
+
+#[test]
+fn core_duration() {
+    assert_eq!(
+        CoreDuration::new(5, 730_023_852).to_duration(),
+        Ok(Milliseconds(5_730_u32))
+    );
+    assert_eq!(
+        CoreDuration::from_secs(7_200).to_duration(),
+        Ok(Hours(2_u64))
+    );
+    assert_eq!(
+        CoreDuration::from_secs(u64::MAX).to_duration(),
+        Ok(Seconds(u64::MAX))
+    );
+    assert_eq!(
+        CoreDuration::from_secs(u64::MAX).to_duration::<Milliseconds<u64>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    // wider than `u64`
+    assert_eq!(
+        CoreDuration::new(u64::MAX, 999_999_999).to_duration(),
+        Ok(Nanoseconds(
+            u128::from(u64::MAX) * 1_000_000_000 + 999_999_999
+        ))
+    );
+
+    assert_eq!(
+        Microseconds(1_500_u32).to_core(),
+        Ok(CoreDuration::from_micros(1_500))
+    );
+    assert_eq!(
+        Nanoseconds(u64::MAX).to_core(),
+        Ok(CoreDuration::from_nanos(u64::MAX))
+    );
+    assert_eq!(Hours(u64::MAX).to_core(), Err(ConversionError::Overflow));
+}
+
+#[test]
+fn deprecated_aliases() {
+    let _: Period = Fraction::new(1, 1_000);
+
+    assert_eq!(
+        Microseconds::<u32>::try_from_rate(Kilohertz(2_u32)),
+        Ok(Microseconds(500_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::try_from_duration(Microseconds(2_u32)),
+        Ok(Kilohertz(500_u32))
+    );
+    assert_eq!(
+        Hertz(2_u64).try_into_generic(Fraction::new(1, 2_000)),
+        Ok(rate::Generic::new(4_000_u32, Fraction::new(1, 2_000)))
+    );
+}
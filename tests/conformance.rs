@@ -0,0 +1,150 @@
+use core::cell::Cell;
+use embedded_time::{self as time, conformance::*, duration::*, Instant};
+
+/// Advances by `step` ticks on every reading
+#[derive(Debug)]
+struct Clock {
+    ticks: Cell<u32>,
+    step: u32,
+}
+
+impl Clock {
+    fn new(start: u32, step: u32) -> Self {
+        Self {
+            ticks: Cell::new(start),
+            step,
+        }
+    }
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let now = self.ticks.get();
+        self.ticks.set(now.wrapping_add(self.step));
+        Ok(Instant::new(now))
+    }
+}
+
+/// Reads the ticks of a `Clock` in microseconds, scaled by `per_mille` / 1000
+#[derive(Debug)]
+struct Reference<'a> {
+    clock: &'a Clock,
+    per_mille: u64,
+}
+
+impl time::Clock for Reference<'_> {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.clock.ticks.get() as u64 * self.per_mille))
+    }
+}
+
+#[derive(Debug)]
+struct Broken;
+
+impl time::Clock for Broken {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 0);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+#[test]
+fn monotonic() {
+    assert_eq!(monotonicity(&Clock::new(0, 1), 100), Ok(()));
+    // across the wrap
+    assert_eq!(monotonicity(&Clock::new(u32::MAX - 10, 1), 100), Ok(()));
+
+    assert_eq!(
+        monotonicity(&Clock::new(1_000, u32::MAX), 100),
+        Err(Failure::NotMonotonic)
+    );
+    assert_eq!(
+        monotonicity(&Clock::new(1_000, 0), 100),
+        Err(Failure::Stalled)
+    );
+    assert_eq!(
+        monotonicity(&Broken, 100),
+        Err(Failure::Time(time::TimeError::Clock(
+            time::clock::Error::NotRunning
+        )))
+    );
+}
+
+/// A 24-bit counter
+#[derive(Debug)]
+struct SysTick;
+
+impl time::Clock for SysTick {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 24;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+/// A counter declared wider than its integer type
+#[derive(Debug)]
+struct Misdeclared;
+
+impl time::Clock for Misdeclared {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 40;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn wrap() {
+    assert_eq!(wrap_handling::<Clock>(), Ok(()));
+    assert_eq!(wrap_handling::<Reference>(), Ok(()));
+    assert_eq!(wrap_handling::<SysTick>(), Ok(()));
+    assert_eq!(wrap_handling::<Misdeclared>(), Err(Failure::WrapHandling));
+}
+
+#[test]
+fn conversion() {
+    assert_eq!(conversions::<Clock>(), Ok(()));
+    assert_eq!(conversions::<Reference>(), Ok(()));
+    assert_eq!(conversions::<Broken>(), Err(Failure::Conversion));
+}
+
+#[test]
+fn accuracy() {
+    let clock = Clock::new(0, 1);
+
+    let reference = Reference {
+        clock: &clock,
+        per_mille: 1_000,
+    };
+    assert_eq!(
+        timer_accuracy(&clock, &reference, Milliseconds(100_u32), 0),
+        Ok(0)
+    );
+
+    // the timer takes 1% too long according to the reference
+    let reference = Reference {
+        clock: &clock,
+        per_mille: 1_010,
+    };
+    assert_eq!(
+        timer_accuracy(&clock, &reference, Milliseconds(100_u32), 10_000),
+        Ok(10_000)
+    );
+    assert_eq!(
+        timer_accuracy(&clock, &reference, Milliseconds(100_u32), 1_000),
+        Err(Failure::Inaccurate { error_ppm: 10_000 })
+    );
+}
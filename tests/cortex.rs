@@ -0,0 +1,29 @@
+#![cfg(feature = "cortex-m")]
+
+use embedded_time::{
+    cortex::{DwtClock, Frequency, SysTickClock},
+    duration::*,
+    rate::*,
+    Clock,
+};
+
+struct CoreClock;
+
+impl Frequency for CoreClock {
+    const FREQUENCY: Hertz<u32> = Hertz(64_000_000);
+}
+
+#[test]
+fn scaling_factors() {
+    assert_eq!(
+        SysTickClock::<CoreClock>::SCALING_FACTOR,
+        Fraction::new(1, 64_000_000)
+    );
+    assert_eq!(SysTickClock::<CoreClock>::COUNTER_BITS, 24);
+
+    assert_eq!(
+        DwtClock::<CoreClock>::SCALING_FACTOR,
+        Fraction::new(1, 64_000_000)
+    );
+    assert_eq!(DwtClock::<CoreClock>::COUNTER_BITS, 32);
+}
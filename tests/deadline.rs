@@ -0,0 +1,57 @@
+use core::convert::TryFrom;
+use embedded_time::{self as time, deadline::Deadline, duration::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(u32::MAX - 1))
+    }
+}
+
+#[test]
+fn expiration() {
+    let deadline = Deadline::start(&Clock, Milliseconds(10_u32)).unwrap();
+    assert_eq!(deadline.expiration(), Instant::new(8));
+
+    // across a clock wrap
+    assert!(!deadline.is_expired(Instant::new(u32::MAX)));
+    assert!(!deadline.is_expired(Instant::new(7)));
+    assert_eq!(
+        Milliseconds::<u32>::try_from(deadline.remaining(Instant::new(7)).unwrap()),
+        Ok(Milliseconds(1_u32))
+    );
+    assert!(deadline.is_expired(Instant::new(8)));
+    assert_eq!(deadline.remaining(Instant::new(8)), None);
+    assert_eq!(deadline.remaining(Instant::new(100)), None);
+
+    assert_eq!(Deadline::at(Instant::<Clock>::new(8)), deadline);
+}
+
+#[test]
+fn extend() {
+    let mut deadline = Deadline::new(Instant::<Clock>::new(0), Seconds(1_u32)).unwrap();
+    let copy = deadline;
+
+    deadline.extend(Milliseconds(500_u32)).unwrap();
+    assert_eq!(deadline.expiration(), Instant::new(1_500));
+    assert_ne!(deadline, copy);
+
+    assert_eq!(
+        deadline.extend(Hours(1_000_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(deadline.expiration(), Instant::new(1_500));
+}
+
+#[test]
+fn duration_beyond_comparison_window() {
+    assert_eq!(
+        Deadline::new(Instant::<Clock>::new(0), Hours(1_000_u32)).unwrap_err(),
+        ConversionError::Overflow
+    );
+}
@@ -0,0 +1,90 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time,
+    debounce::{Debouncer, Edge},
+    duration::*,
+    ConversionError, Instant,
+};
+
+/// Advances by 1 ms per reading
+#[derive(Debug, Default)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let ticks = self.0.get();
+        self.0.set(ticks.wrapping_add(1));
+        Ok(Instant::new(ticks))
+    }
+}
+
+#[test]
+fn press_and_release() {
+    let mut button =
+        Debouncer::<Clock>::new(false, Milliseconds(10_u32), Milliseconds(30_u32)).unwrap();
+
+    // bounces restart the count
+    assert_eq!(button.update(Instant::new(u32::MAX - 5), true), None);
+    assert!(button.is_changing());
+    assert_eq!(button.update(Instant::new(u32::MAX - 1), false), None);
+    assert!(!button.is_changing());
+
+    // across a clock wrap
+    assert_eq!(button.update(Instant::new(u32::MAX), true), None);
+    assert_eq!(button.update(Instant::new(8), true), None);
+    assert_eq!(button.update(Instant::new(9), true), Some(Edge::Pressed));
+    assert!(button.is_pressed());
+    assert_eq!(button.update(Instant::new(50), true), None);
+
+    // the release duration applies
+    assert_eq!(button.update(Instant::new(60), false), None);
+    assert_eq!(button.update(Instant::new(89), false), None);
+    assert_eq!(button.update(Instant::new(90), false), Some(Edge::Released));
+    assert!(!button.is_pressed());
+    assert!(!button.is_changing());
+}
+
+#[test]
+fn reset() {
+    let mut switch =
+        Debouncer::<Clock>::new(true, Milliseconds(10_u32), Milliseconds(10_u32)).unwrap();
+
+    assert_eq!(switch.update(Instant::new(0), false), None);
+    switch.reset(false);
+    assert!(!switch.is_pressed());
+    assert!(!switch.is_changing());
+    assert_eq!(switch.update(Instant::new(20), false), None);
+}
+
+#[test]
+fn without_debouncing() {
+    let mut input =
+        Debouncer::<Clock>::new(false, Milliseconds(0_u32), Milliseconds(0_u32)).unwrap();
+
+    assert_eq!(input.update(Instant::new(0), true), Some(Edge::Pressed));
+    assert_eq!(input.update(Instant::new(0), false), Some(Edge::Released));
+}
+
+#[test]
+fn sample() {
+    let clock = Clock::default();
+    let mut button = Debouncer::new(false, Milliseconds(3_u32), Milliseconds(3_u32)).unwrap();
+
+    while button.sample(&clock, true) != Ok(Some(Edge::Pressed)) {}
+    assert_eq!(clock.0.get(), 4);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Debouncer::<Clock>::new(false, Milliseconds(u32::MAX), Milliseconds(10)).err(),
+        Some(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Debouncer::<Clock>::new(false, Milliseconds(10_u32), Milliseconds(u32::MAX)).err(),
+        Some(ConversionError::Overflow)
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "defmt")]
+
+use embedded_time::{self as time, duration, duration::*, rate, rate::*, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn is_format<T: defmt::Format>() {}
+
+#[test]
+fn format() {
+    is_format::<Milliseconds<u32>>();
+    is_format::<Hours<u64>>();
+    is_format::<duration::Generic<u32>>();
+    is_format::<Kilohertz<u32>>();
+    is_format::<BitsPerSecond<u64>>();
+    is_format::<rate::Generic<u64>>();
+    is_format::<Fraction>();
+    is_format::<Instant<Clock>>();
+
+    is_format::<time::TimeError>();
+    is_format::<time::ConversionError>();
+    is_format::<time::clock::Error>();
+    is_format::<time::clock::TimeoutError<u8>>();
+    is_format::<time::conformance::Failure>();
+    is_format::<time::persist::Error>();
+}
@@ -0,0 +1,59 @@
+use embedded_time::{self as time, delay::LongDelay, duration::*, Instant};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static TICKS: AtomicU32 = AtomicU32::new(u32::MAX - 1_000);
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(TICKS.load(Ordering::SeqCst)))
+    }
+}
+
+fn advance(ticks: u32) {
+    let now = TICKS.load(Ordering::SeqCst);
+    TICKS.store(now.wrapping_add(ticks), Ordering::SeqCst);
+}
+
+#[test]
+fn long_delay_across_wraps() {
+    let start = TICKS.load(Ordering::SeqCst);
+    let mut delay = LongDelay::new(&Clock, Hours(2_u32)).unwrap();
+
+    assert_eq!(delay.remaining_chunks(), 3);
+    assert_eq!(
+        delay.next_deadline(),
+        Instant::new(start.wrapping_add(u32::MAX / 2))
+    );
+
+    // advance in steps of 10 minutes (less than the comparison window)
+    for _ in 0..11 {
+        advance(600_000_000);
+        assert!(!delay.is_complete().unwrap());
+    }
+
+    assert_eq!(delay.remaining_chunks(), 0);
+
+    // 2 hours minus one tick
+    advance(600_000_000 - 1);
+    assert!(!delay.is_complete().unwrap());
+
+    advance(1);
+    assert!(delay.is_complete().unwrap());
+    assert_eq!(
+        delay.next_deadline(),
+        Instant::new(start.wrapping_add((7_200_000_000_u64 % (1 << 32)) as u32))
+    );
+}
+
+#[test]
+fn short_delay() {
+    let delay = LongDelay::new(&Clock, Milliseconds(0_u32)).unwrap();
+    assert_eq!(delay.remaining_chunks(), 0);
+    assert!(delay.wait().is_ok());
+}
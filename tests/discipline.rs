@@ -0,0 +1,70 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time, discipline::DisciplinedClock, duration::*, Clock as _, ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 24;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+fn advance(clock: &DisciplinedClock<Clock>, ticks: u32) {
+    let raw = clock.inner();
+    raw.0.set(raw.0.get().wrapping_add(ticks) & 0x00FF_FFFF);
+}
+
+#[test]
+fn rate() {
+    let clock = DisciplinedClock::new(Clock(Cell::new(100)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+
+    clock.set_rate_ppm(-500);
+    assert_eq!(clock.rate_ppb(), -500_000);
+
+    // sub-tick corrections carry over
+    for _ in 0..3 {
+        advance(&clock, 1_000);
+    }
+    assert_eq!(clock.try_now(), Ok(Instant::new(3_099)));
+    advance(&clock, 1_000);
+    assert_eq!(clock.try_now(), Ok(Instant::new(4_098)));
+    advance(&clock, 1_000);
+    assert_eq!(clock.try_now(), Ok(Instant::new(5_098)));
+
+    clock.set_rate_ppb(i32::MAX);
+    assert_eq!(clock.rate_ppb(), 999_999_999);
+    clock.set_rate_ppm(i32::MIN);
+    assert_eq!(clock.rate_ppb(), -999_999_999);
+}
+
+#[test]
+fn offset() {
+    let clock = DisciplinedClock::new(Clock(Cell::new(0x00FF_FFF0)));
+
+    // applied on the first reading too, wrapping like the wrapped clock
+    clock.step_forward(Milliseconds(20_u32)).unwrap();
+    assert_eq!(clock.try_now(), Ok(Instant::new(4)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(4)));
+
+    clock.step_forward(Seconds(1_u32)).unwrap();
+    clock.step_backward(Milliseconds(300_u32)).unwrap();
+    advance(&clock, 10);
+    assert_eq!(clock.try_now(), Ok(Instant::new(714)));
+
+    clock.step_backward(Milliseconds(714_u32)).unwrap();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    assert_eq!(
+        clock.step_forward(Seconds(u32::MAX)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+}
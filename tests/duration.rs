@@ -1,5 +1,6 @@
 use core::convert::{TryFrom, TryInto};
 use embedded_time::{duration, duration::*, fraction::Fraction, rate::*, ConversionError};
+use test_case::test_case;
 
 #[test]
 fn construction() {
@@ -14,6 +15,81 @@ fn construction() {
     assert_eq!(5_u32.hours(), Hours(5_u32));
 }
 
+#[test]
+fn const_construction_and_conversion() {
+    const PERIOD: Milliseconds<u32> = Milliseconds::new(1_500);
+    const TICKS: Option<u32> = PERIOD.to_ticks(Fraction::new(1, 32_768));
+    const HOURS: Option<u64> = Hours(2_u64).to_ticks(Fraction::new(1, 1_000_000_000));
+    const RATE: Hertz<u32> = Hertz::new(50);
+
+    assert_eq!(TICKS, Some(49_152));
+    assert_eq!(HOURS, Some(7_200_000_000_000));
+    assert_eq!(RATE, Hertz(50_u32));
+
+    assert_eq!(
+        Nanoseconds(999_u32).to_ticks(Fraction::new(1, 1_000_000)),
+        Some(0)
+    );
+    assert_eq!(Hours(u64::MAX).to_ticks(Fraction::new(1, 1)), None);
+    assert_eq!(Seconds(1_u32).to_ticks(Fraction::new(0, 1)), None);
+}
+
+#[test]
+fn limits() {
+    assert_eq!(Milliseconds::<u32>::MAX, Milliseconds(u32::MAX));
+    assert_eq!(Milliseconds::<u32>::MIN, Milliseconds(0_u32));
+    assert_eq!(Hours::<u64>::MAX, Hours(u64::MAX));
+    assert_eq!(Hours::<u64>::MIN, Hours(0_u64));
+}
+
+#[test]
+fn representable_range_in() {
+    assert_eq!(
+        Minutes::<u32>::representable_range_in::<Nanoseconds<u64>>(),
+        Minutes(0_u32)..=Minutes(307_445_734_u32)
+    );
+    assert_eq!(
+        Seconds::<u64>::representable_range_in::<Seconds<u32>>(),
+        Seconds(0_u64)..=Seconds(u32::MAX as u64)
+    );
+    assert_eq!(
+        Milliseconds::<u64>::representable_range_in::<Seconds<u32>>(),
+        Milliseconds(0_u64)..=Milliseconds(u32::MAX as u64 * 1_000 + 999)
+    );
+    assert_eq!(
+        Nanoseconds::<u32>::representable_range_in::<Hours<u32>>(),
+        Nanoseconds::<u32>::MIN..=Nanoseconds::<u32>::MAX
+    );
+
+    // the bounds convert
+    let range = Hours::<u64>::representable_range_in::<Microseconds<u64>>();
+    assert!(Microseconds::<u64>::try_from(*range.end()).is_ok());
+    assert!(Microseconds::<u64>::try_from(*range.end() + Hours(1_u64)).is_err());
+}
+
+#[test]
+fn aliases() {
+    fn timeout(duration: Milliseconds32) -> Microseconds64 {
+        duration.into()
+    }
+
+    assert_eq!(timeout(Milliseconds(5)), Microseconds(5_000_u64));
+    assert_eq!(Seconds32::MAX, Seconds(u32::MAX));
+    assert_eq!(Hours64::new(1), Hours(1_u64));
+}
+
+#[test]
+fn numerical_duration() {
+    fn construct<T: NumericalDuration>(value: T) -> Milliseconds<T> {
+        value.milliseconds()
+    }
+
+    assert_eq!(construct(100_u32), Milliseconds(100_u32));
+    assert_eq!(construct(100_u64), Milliseconds(100_u64));
+    assert_eq!(10_u64.microseconds(), Microseconds(10_u64));
+    assert_eq!(2_u64.hours(), Minutes(120_u64));
+}
+
 #[test]
 fn comparisons() {
     assert_ne!(Seconds(5_u32), Nanoseconds(u32::MAX));
@@ -50,6 +126,20 @@ fn comparisons() {
     assert!(Microseconds(u64::MAX) > Microseconds(5_u32));
 }
 
+#[test]
+fn mixed_unit_equality() {
+    assert_eq!(Hours(1_u32), Minutes(60_u32));
+    assert_eq!(Minutes(1_u32), Seconds(60_u32));
+    assert_eq!(Seconds(1_u32), Milliseconds(1_000_u32));
+    assert_eq!(Milliseconds(1_u32), Microseconds(1_000_u64));
+    assert_eq!(Microseconds(1_u64), Nanoseconds(1_000_u32));
+    assert_eq!(Nanoseconds(3_600_000_000_000_u64), Hours(1_u32));
+
+    assert!(Minutes(59_u32) < Hours(1_u32));
+    assert!(Milliseconds(1_001_u32) > Seconds(1_u64));
+    assert!(Seconds(1_u32) <= Milliseconds(1_000_u32));
+}
+
 #[test]
 fn add() {
     assert_eq!(
@@ -68,6 +158,77 @@ fn sub() {
     assert_eq!(Minutes(u32::MAX) - Hours(1_u32), Minutes(u32::MAX - 60));
 }
 
+#[test]
+fn mixed_unit_add_sub() {
+    // the result is of the LHS unit (truncated)
+    assert_eq!(Seconds(1_u32) + Milliseconds(500_u32), Seconds(1_u32));
+    assert_eq!(
+        Milliseconds(500_u32) + Seconds(1_u32),
+        Milliseconds(1_500_u32)
+    );
+    assert_eq!(Minutes(2_u32) - Seconds(60_u32), Minutes(1_u32));
+    assert_eq!(Hours(1_u32) + Minutes(120_u32), Hours(3_u32));
+    assert_eq!(
+        Microseconds(1_u64) + Milliseconds(1_u32),
+        Microseconds(1_001_u64)
+    );
+    assert_eq!(
+        Nanoseconds(2_000_u64) - Microseconds(1_u32),
+        Nanoseconds(1_000_u64)
+    );
+}
+
+#[test]
+fn checked_add() {
+    assert_eq!(
+        Milliseconds(1_u32).checked_add(&Seconds(1_u32)),
+        Some(Milliseconds(1_001_u32))
+    );
+
+    assert_eq!(
+        Milliseconds(u32::MAX).checked_add(&Milliseconds(1_u32)),
+        None
+    );
+    // the RHS doesn't fit in the LHS type
+    assert_eq!(Milliseconds(0_u32).checked_add(&Seconds(u32::MAX)), None);
+}
+
+#[test]
+fn checked_sub() {
+    assert_eq!(
+        Milliseconds(2_001_u32).checked_sub(&Seconds(1_u32)),
+        Some(Milliseconds(1_001_u32))
+    );
+
+    assert_eq!(Milliseconds(1_u32).checked_sub(&Milliseconds(2_u32)), None);
+    assert_eq!(Milliseconds(0_u32).checked_sub(&Seconds(u32::MAX)), None);
+}
+
+#[test]
+fn saturating_add_sub() {
+    assert_eq!(
+        Milliseconds(1_u32).saturating_add(&Seconds(1_u32)),
+        Milliseconds(1_001_u32)
+    );
+    assert_eq!(
+        Milliseconds(u32::MAX - 1).saturating_add(&Milliseconds(2_u32)),
+        Milliseconds(u32::MAX)
+    );
+    assert_eq!(
+        Milliseconds(0_u32).saturating_add(&Seconds(u32::MAX)),
+        Milliseconds(u32::MAX)
+    );
+
+    assert_eq!(
+        Milliseconds(1_u32).saturating_sub(&Milliseconds(2_u32)),
+        Milliseconds(0_u32)
+    );
+    assert_eq!(
+        Milliseconds(5_u32).saturating_sub(&Seconds(u32::MAX)),
+        Milliseconds(0_u32)
+    );
+}
+
 #[test]
 fn mul() {
     assert_eq!(Milliseconds(2_001_u32) * 2, Milliseconds(4_002_u32));
@@ -86,6 +247,59 @@ fn checked_mul() {
 
     assert_eq!(Milliseconds(u32::MAX).checked_mul(&2), None);
 }
+#[test]
+fn saturating_mul() {
+    assert_eq!(
+        Milliseconds(2_001_u32).saturating_mul(&2),
+        Milliseconds(4_002_u32)
+    );
+
+    assert_eq!(
+        Milliseconds(u32::MAX).saturating_mul(&2),
+        Milliseconds(u32::MAX)
+    );
+}
+
+#[test]
+fn saturating_wrapper() {
+    use embedded_time::overflow::Saturating;
+
+    let mut duration = Saturating(Milliseconds(u32::MAX - 1_000));
+    duration += Milliseconds(500_u32);
+    assert_eq!(duration, Saturating(Milliseconds(u32::MAX - 500)));
+    duration += Milliseconds(501_u32);
+    assert_eq!(duration, Saturating(Milliseconds(u32::MAX)));
+
+    assert_eq!(
+        Saturating(Seconds(1_u32)) - Saturating(Seconds(2_u32)),
+        Saturating(Seconds(0_u32))
+    );
+    // RHS too large for the LHS type
+    assert_eq!(
+        Saturating(Milliseconds(1_u32)) + Seconds(u32::MAX),
+        Saturating(Milliseconds(u32::MAX))
+    );
+    assert_eq!(
+        Saturating(Milliseconds(u32::MAX / 2 + 1)) * 2,
+        Saturating(Milliseconds(u32::MAX))
+    );
+}
+
+#[test]
+fn wrapping_wrapper() {
+    use embedded_time::overflow::Wrapping;
+
+    let mut duration = Wrapping(Milliseconds(u32::MAX - 1));
+    duration += Milliseconds(3_u32);
+    assert_eq!(duration, Wrapping(Milliseconds(1_u32)));
+    duration -= Wrapping(Milliseconds(2_u32));
+    assert_eq!(duration, Wrapping(Milliseconds(u32::MAX)));
+
+    assert_eq!(
+        Wrapping(Milliseconds(u32::MAX / 2 + 2)) * 2,
+        Wrapping(Milliseconds(2_u32))
+    );
+}
 
 #[test]
 fn div() {
@@ -117,6 +331,91 @@ fn rem() {
     assert_eq!(Minutes(62_u32) % Minutes(60_u32), Minutes(2_u32));
 }
 
+#[test]
+fn ordered_and_hashed_keys() {
+    use std::collections::{BTreeMap, BinaryHeap, HashSet};
+
+    let mut deadlines = BTreeMap::new();
+    deadlines.insert(Milliseconds(300_u32), "c");
+    deadlines.insert(Milliseconds(100_u32), "a");
+    deadlines.insert(Milliseconds(200_u32), "b");
+    assert_eq!(
+        deadlines.values().copied().collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+
+    let mut queue: BinaryHeap<_> = vec![Seconds(2_u64), Seconds(5_u64), Seconds(1_u64)].into();
+    assert_eq!(queue.pop(), Some(Seconds(5_u64)));
+
+    let set: HashSet<_> = vec![Minutes(1_u32), Minutes(1_u32), Minutes(2_u32)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+
+    let mut durations = [Seconds(3_u32), Seconds(1_u32), Seconds(2_u32)];
+    durations.sort();
+    assert_eq!(durations, [Seconds(1_u32), Seconds(2_u32), Seconds(3_u32)]);
+    assert_eq!(durations.iter().max(), Some(&Seconds(3_u32)));
+}
+
+#[test]
+fn min_max_clamp() {
+    assert_eq!(Seconds(2_u32).min(Seconds(1_u32)), Seconds(1_u32));
+    assert_eq!(Seconds(2_u32).max(Seconds(1_u32)), Seconds(2_u32));
+    assert_eq!(Seconds(1_u32).min(Milliseconds(1_999_u64)), Seconds(1_u32));
+    assert_eq!(Seconds(2_u32).min(Milliseconds(1_999_u64)), Seconds(1_u32));
+    assert_eq!(
+        Milliseconds(500_u64).min(Hours(1_u32)),
+        Milliseconds(500_u64)
+    );
+    assert_eq!(
+        Milliseconds(500_u64).max(Hours(1_u32)),
+        Milliseconds(3_600_000_u64)
+    );
+    assert_eq!(Nanoseconds(0_u32).max(Hours(2_u32)), Nanoseconds(u32::MAX));
+
+    let (lo, hi) = (Milliseconds(10_u32), Seconds(2_u32));
+    assert_eq!(
+        Microseconds(500_u32).clamp(lo, hi),
+        Microseconds(10_000_u32)
+    );
+    assert_eq!(
+        Microseconds(50_000_u32).clamp(lo, hi),
+        Microseconds(50_000_u32)
+    );
+    assert_eq!(Minutes(1_u32).clamp(lo, hi), Minutes(0_u32));
+    assert_eq!(
+        Seconds(5_u32).clamp(Seconds(3_u32), Seconds(1_u32)),
+        Seconds(1_u32)
+    );
+}
+
+#[test]
+fn compound_assignment() {
+    let mut duration = Milliseconds(1_u64);
+    duration += Seconds(1_u32);
+    assert_eq!(duration, Milliseconds(1_001_u64));
+    duration -= Microseconds(1_000_u64);
+    assert_eq!(duration, Milliseconds(1_000_u64));
+    duration *= 3;
+    assert_eq!(duration, Seconds(3_u32));
+    duration /= 2;
+    assert_eq!(duration, Milliseconds(1_500_u64));
+    duration %= Seconds(1_u32);
+    assert_eq!(duration, Milliseconds(500_u64));
+
+    let mut minutes = Minutes(90_u32);
+    minutes %= Hours(1_u32);
+    assert_eq!(minutes, Minutes(30_u32));
+}
+
+#[test]
+#[should_panic]
+fn sub_assign_underflow() {
+    let mut duration = Seconds(1_u32);
+    duration -= Seconds(2_u32);
+}
+
 #[test]
 fn from_generic() {
     assert_eq!(
@@ -144,6 +443,42 @@ fn from_generic() {
     );
 }
 
+#[test]
+fn lossless_intermediate_math() {
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Seconds(3_000_000_u32)),
+        Ok(Milliseconds(3_000_000_000_u32))
+    );
+}
+
+#[test]
+fn exotic_scaling_factors() {
+    // 32.768 kHz ticks (years worth) in nanoseconds
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(duration::Generic::new(
+            100_000_000_000_000_u64,
+            Fraction::new(1, 32_768)
+        )),
+        Ok(Nanoseconds(3_051_757_812_500_000_000_u64))
+    );
+
+    // a combined scaling factor that doesn't fit in a `u32`/`u32` fraction
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(duration::Generic::new(
+            1_000_000_007_u32,
+            Fraction::new(37, 1_000_000_007)
+        )),
+        Ok(Nanoseconds(37_000_000_000_u64))
+    );
+    assert_eq!(
+        Seconds(37_u32).to_generic::<u64>(Fraction::new(37, 1_000_000_007)),
+        Ok(duration::Generic::new(
+            1_000_000_007_u64,
+            Fraction::new(37, 1_000_000_007)
+        ))
+    );
+}
+
 #[test]
 fn to_generic() {
     assert_eq!(
@@ -242,6 +577,27 @@ fn into_core_duration() {
         Hours(123_u32).try_into(),
         Ok(core::time::Duration::from_secs(123 * 3600))
     );
+
+    assert_eq!(
+        Nanoseconds(5_025_678_901_234_u64).try_into(),
+        Ok(core::time::Duration::from_nanos(5_025_678_901_234))
+    );
+    assert_eq!(
+        Seconds(u64::MAX).try_into(),
+        Ok(core::time::Duration::from_secs(u64::MAX))
+    );
+    assert_eq!(
+        Minutes(123_u64).try_into(),
+        Ok(core::time::Duration::from_secs(123 * 60))
+    );
+    assert_eq!(
+        Hours(123_u64).try_into(),
+        Ok(core::time::Duration::from_secs(123 * 3600))
+    );
+    assert_eq!(
+        core::time::Duration::try_from(Hours(u64::MAX / 3_600 + 1)),
+        Err(ConversionError::Unspecified)
+    );
 }
 
 #[test]
@@ -499,4 +855,520 @@ fn error_try_from() {
         Milliseconds::<u32>::try_from(Seconds(u64::MAX)),
         Err(ConversionError::Unspecified)
     );
+
+    // same unit, narrower integer
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Milliseconds(u32::MAX as u64)),
+        Ok(Milliseconds(u32::MAX))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Milliseconds(u32::MAX as u64 + 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test_case(Seconds(0_u32) => "less than a second" ; "Zero")]
+#[test_case(Seconds(59_u32) => "59 seconds" ; "Below a minute")]
+#[test_case(Seconds(60_u32) => "about 1 minute" ; "A minute")]
+#[test_case(Seconds(3_569_u32) => "about 59 minutes" ; "Rounded down to minutes")]
+#[test_case(Seconds(3_570_u32) => "about 1 hour" ; "Rounded up to an hour")]
+#[test_case(Seconds(84_599_u32) => "about 23 hours" ; "Rounded down to hours")]
+#[test_case(Seconds(84_600_u32) => "about 1 day" ; "Rounded up to a day")]
+#[test_case(Seconds(u32::MAX) => "about 49710 days" ; "Maximum")]
+fn humanize(duration: Seconds<u32>) -> String {
+    duration.humanize().to_string()
+}
+
+#[test]
+fn minutes_and_hours() {
+    // conversions to and from the finer units
+    assert_eq!(
+        Seconds::<u32>::try_from(Minutes(3_u32)),
+        Ok(Seconds(180_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from(Hours(2_u32)),
+        Ok(Seconds(7_200_u32))
+    );
+    assert_eq!(Minutes::<u32>::from(Seconds(7_199_u32)), Minutes(119_u32));
+    assert_eq!(Hours::<u32>::from(Minutes(150_u32)), Hours(2_u32));
+    assert_eq!(
+        Milliseconds::<u64>::from(Hours(1_u32)),
+        Milliseconds(3_600_000_u64)
+    );
+
+    // mixed-unit arithmetic results in the LHS type
+    assert_eq!(Hours(1_u32) + Minutes(60_u32), Hours(2_u32));
+    assert_eq!(Minutes(90_u32) - Hours(1_u32), Minutes(30_u32));
+    assert_eq!(Seconds(10_u32) + Minutes(1_u32), Seconds(70_u32));
+
+    assert_eq!(format!("{}", Minutes(5_u32)), "5 min");
+    assert_eq!(format!("{}", Hours(7_u32)), "7 h");
+}
+
+#[test]
+fn fractional_generic() {
+    // 1.5 ms
+    let duration = duration::Generic::new(3_u32, Fraction::new(1, 2_000));
+
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(duration),
+        Ok(Nanoseconds(1_500_000_u64))
+    );
+    assert_eq!(
+        duration.to_generic::<u64>(Fraction::new(1, 1_000_000)),
+        Ok(duration::Generic::new(
+            1_500_u64,
+            Fraction::new(1, 1_000_000)
+        ))
+    );
+    assert_eq!(
+        duration.to_generic::<u32>(Fraction::new(1, 32_768)),
+        Ok(duration::Generic::new(49_u32, Fraction::new(1, 32_768)))
+    );
+
+    assert_eq!(
+        duration::Generic::new(u32::MAX, Fraction::new(1, 1))
+            .to_generic::<u32>(Fraction::new(1, 2)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        duration::Generic::new(u32::MAX as u64 + 1, Fraction::new(1, 1))
+            .to_generic::<u32>(Fraction::new(1, 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn runtime_period_generic() {
+    // 1 MHz timer clock divided by a prescaler of 8
+    let ticks = duration::Generic::new(1_000_u32, Fraction::new(8, 1_000_000));
+
+    assert_eq!(
+        Milliseconds::<u32>::try_from(ticks),
+        Ok(Milliseconds(8_u32))
+    );
+    assert_eq!(
+        ticks.checked_add(&Microseconds(20_u32).into()),
+        Some(duration::Generic::new(1_002_u32, Fraction::new(1, 125_000)))
+    );
+    assert_eq!(
+        ticks.checked_sub(&Microseconds(20_u32).into()),
+        Some(duration::Generic::new(998_u32, Fraction::new(1, 125_000)))
+    );
+    // sub-tick RHS are truncated
+    assert_eq!(ticks.checked_add(&Microseconds(7_u32).into()), Some(ticks));
+    assert_eq!(ticks.checked_sub(&Seconds(1_u32).into()), None);
+    assert_eq!(
+        ticks.checked_mul(&3),
+        Some(duration::Generic::new(3_000_u32, Fraction::new(1, 125_000)))
+    );
+    assert_eq!(
+        ticks.checked_div(&3),
+        Some(duration::Generic::new(333_u32, Fraction::new(1, 125_000)))
+    );
+    assert_eq!(ticks.checked_div(&0), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Hours(3_u32)), "3 h");
+    assert_eq!(format!("{}", Minutes(3_u32)), "3 min");
+    assert_eq!(format!("{}", Seconds(3_u32)), "3 s");
+    assert_eq!(format!("{}", Milliseconds(5_u32)), "5 ms");
+    assert_eq!(format!("{}", Microseconds(250_u64)), "250 µs");
+    assert_eq!(format!("{}", Nanoseconds(7_u32)), "7 ns");
+    assert_eq!(format!("{:>4}", Seconds(3_u32)), "   3 s");
+
+    assert_eq!(format!("{:#}", Seconds(90_u32)), "1 min 30 s");
+    assert_eq!(format!("{:#}", Minutes(150_u32)), "2 h 30 min");
+    assert_eq!(format!("{:#}", Seconds(3_600_u32)), "1 h");
+    assert_eq!(
+        format!("{:#}", Nanoseconds(1_001_001_u32)),
+        "1 ms 1 µs 1 ns"
+    );
+    assert_eq!(format!("{:#}", Seconds(0_u32)), "0 s");
+    assert_eq!(format!("{:#}", Hours(u64::MAX)), "18446744073709551615 h");
+}
+
+#[test]
+fn hhmmss() {
+    assert_eq!(Milliseconds(0_u32).hhmmss().to_string(), "00:00:00.000");
+    assert_eq!(Seconds(59_u32).hhmmss().to_string(), "00:00:59.000");
+    assert_eq!(Minutes(61_u32).hhmmss().to_string(), "01:01:00.000");
+    assert_eq!(
+        Microseconds(5_025_999_u32).hhmmss().to_string(),
+        "00:00:05.025"
+    );
+    assert_eq!(
+        Nanoseconds(86_399_999_999_999_u64).hhmmss().to_string(),
+        "23:59:59.999"
+    );
+    assert_eq!(
+        Hours(u64::MAX).hhmmss().to_string(),
+        "18446744073709551615:00:00.000"
+    );
+
+    // into a fixed buffer
+    struct Buffer([u8; 12], usize);
+    impl core::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+    let mut buffer = Buffer([0; 12], 0);
+    core::fmt::write(
+        &mut buffer,
+        format_args!("{}", Seconds(45_296_u32).hhmmss()),
+    )
+    .unwrap();
+    assert_eq!(&buffer.0, b"12:34:56.000");
+}
+
+#[test]
+fn zero() {
+    #[derive(Default)]
+    struct Config {
+        timeout: Milliseconds<u64>,
+        period: duration::Generic<u32>,
+    }
+
+    let config = Config::default();
+    assert_eq!(config.timeout, Milliseconds::<u64>::zero());
+    assert!(config.timeout.is_zero());
+    assert!(config.period.is_zero());
+
+    assert!(Nanoseconds::<u64>::zero().is_zero());
+    assert!(!Hours(1_u32).is_zero());
+    assert!(!duration::Generic::new(1_u32, Fraction::new(1, 1_000)).is_zero());
+    assert!((Seconds(1_u32) - Seconds(1_u32)).is_zero());
+}
+
+#[test]
+fn integer_conversions() {
+    assert_eq!(Milliseconds::from(5_u32), Milliseconds(5_u32));
+    assert_eq!(Hours::from(5_u64), Hours(5_u64));
+
+    let millis: Milliseconds<u64> = 7_u64.into();
+    assert_eq!(millis, Milliseconds(7_u64));
+
+    let ticks: u32 = Microseconds(9_u32).into();
+    assert_eq!(ticks, 9);
+    assert_eq!(u64::from(Nanoseconds(u64::MAX)), u64::MAX);
+}
+
+#[test]
+fn fraction_scaling() {
+    use embedded_time::fraction::Rounding;
+
+    // retry backoff
+    let mut timeout = Milliseconds(100_u32);
+    for _ in 0..3 {
+        timeout = timeout * Fraction::new(3, 2);
+    }
+    assert_eq!(timeout, Milliseconds(337_u32));
+
+    let half = Fraction::new(1, 2);
+    for (duration, down, nearest, up) in [(4_u64, 2, 2, 2), (5, 2, 3, 3), (7, 3, 4, 4)].iter() {
+        let duration = Seconds(*duration);
+        assert_eq!(
+            duration.checked_mul_fraction(&half, Rounding::Down),
+            Some(Seconds(*down))
+        );
+        assert_eq!(
+            duration.checked_mul_fraction(&half, Rounding::Nearest),
+            Some(Seconds(*nearest))
+        );
+        assert_eq!(
+            duration.checked_mul_fraction(&half, Rounding::Up),
+            Some(Seconds(*up))
+        );
+    }
+    assert_eq!(
+        Seconds(10_u32).checked_mul_fraction(&Fraction::new(1, 3), Rounding::Nearest),
+        Some(Seconds(3_u32))
+    );
+    assert_eq!(
+        Seconds(10_u32).checked_mul_fraction(&Fraction::new(2, 3), Rounding::Nearest),
+        Some(Seconds(7_u32))
+    );
+    assert_eq!(Rounding::default(), Rounding::Down);
+
+    // the intermediate product is widened
+    assert_eq!(
+        Seconds(u32::MAX).checked_mul_fraction(&Fraction::new(2, 3), Rounding::Down),
+        Some(Seconds(u32::MAX / 3 * 2))
+    );
+    assert_eq!(
+        Seconds(2_u32).checked_mul_fraction(&Fraction::new(1, 0), Rounding::Down),
+        None
+    );
+}
+
+#[test]
+fn rounded_conversion() {
+    use embedded_time::fraction::Rounding;
+
+    for (millis, down, nearest, up) in [
+        (0_u32, 0_u32, 0_u32, 0_u32),
+        (1_000, 1, 1, 1),
+        (1_001, 1, 1, 2),
+        (1_499, 1, 1, 2),
+        (1_500, 1, 2, 2),
+        (1_999, 1, 2, 2),
+    ]
+    .iter()
+    {
+        let millis = Milliseconds(*millis);
+        assert_eq!(
+            Seconds::try_from_rounded(millis, Rounding::Down),
+            Ok(Seconds(*down))
+        );
+        assert_eq!(
+            Seconds::try_from_rounded(millis, Rounding::Nearest),
+            Ok(Seconds(*nearest))
+        );
+        assert_eq!(
+            Seconds::try_from_rounded(millis, Rounding::Up),
+            Ok(Seconds(*up))
+        );
+    }
+
+    // widening, and toward the larger unit
+    assert_eq!(
+        Milliseconds::<u64>::try_from_rounded(Seconds(u32::MAX), Rounding::Up),
+        Ok(Milliseconds(u32::MAX as u64 * 1_000))
+    );
+    assert_eq!(
+        Minutes::<u32>::try_from_rounded(Nanoseconds(1_u64), Rounding::Up),
+        Ok(Minutes(1_u32))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_rounded(Seconds(5_000_000_u64), Rounding::Down),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Milliseconds::<u64>::try_from_rounded(Seconds(u64::MAX), Rounding::Down),
+        Err(ConversionError::Unspecified)
+    );
+}
+
+#[test]
+fn exact_conversion() {
+    assert_eq!(
+        Seconds::<u32>::try_from_exact(Microseconds(3_000_000_u64)),
+        Ok(Seconds(3_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_exact(Microseconds(2_999_001_u32)),
+        Err(ConversionError::LossOfPrecision)
+    );
+    assert_eq!(
+        Milliseconds::<u64>::try_from_exact(Minutes(2_u32)),
+        Ok(Milliseconds(120_000_u64))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from_exact(Seconds(5_000_000_u64)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_exact(Milliseconds(0_u32)),
+        Ok(Seconds(0_u32))
+    );
+    assert_eq!(
+        embedded_time::TimeError::from(ConversionError::LossOfPrecision),
+        embedded_time::TimeError::LossOfPrecision
+    );
+}
+
+#[test]
+fn u128_integers() {
+    use embedded_time::fraction::Rounding;
+
+    // nanoseconds over a thousand years don't fit in a `u64`
+    const MILLENNIUM: Nanoseconds<u128> = Nanoseconds(31_557_600_000_000_000_000);
+
+    assert_eq!(Hours128::new(8_766_000), MILLENNIUM);
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(MILLENNIUM),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u128>::from(MILLENNIUM),
+        Seconds(31_557_600_000_u128)
+    );
+    assert_eq!(
+        MILLENNIUM.to_ticks(Fraction::new(1, 1_000)),
+        Some(31_557_600_000_000_u128)
+    );
+    assert_eq!(u128::from(MILLENNIUM), 31_557_600_000_000_000_000);
+
+    assert_eq!(Seconds::<u128>::from(Seconds(5_u32)), Seconds(5_u128));
+    assert_eq!(Seconds::<u128>::from(Seconds(5_u64)), Seconds(5_u128));
+    assert_eq!(
+        Seconds::<u64>::try_from(Seconds(5_u128)),
+        Ok(Seconds(5_u64))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from(Seconds(u32::MAX as u128 + 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        duration::Generic::new(5_u128, Fraction::new(1, 1)).try_into(),
+        Ok(Milliseconds(5_000_u128))
+    );
+    assert_eq!(5_u128.minutes(), Seconds(300_u128));
+    assert_eq!(Seconds128::MAX, Seconds(u128::MAX));
+
+    // the intermediate product can't be widened, so conversions of huge values fail rather than
+    // overflow
+    assert_eq!(
+        Nanoseconds::<u128>::try_from(Hours(u128::MAX / 2)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(Hours(u128::MAX).to_ticks(Fraction::new(1, 1)), None);
+    assert_eq!(
+        Hours(u128::MAX / 2).checked_mul_fraction(&Fraction::new(3, 2), Rounding::Down),
+        None
+    );
+    assert_eq!(
+        format!("{:#}", Hours(u128::MAX)),
+        format!("{} h", u128::MAX)
+    );
+    assert_eq!(format!("{:#}", Minutes(61_u128)), "1 h 1 min");
+}
+
+#[test]
+fn narrow_integers() {
+    const BLINK: Milliseconds<u16> = Milliseconds(500);
+
+    assert_eq!(BLINK.to_ticks(Fraction::new(1, 32_768)), Some(16_384_u16));
+    assert_eq!(BLINK.to_ticks(Fraction::new(1, 1_000_000)), None);
+    assert_eq!(Milliseconds::<u8>::MAX, Milliseconds(u8::MAX));
+    assert_eq!(u8::from(Seconds(5_u8)), 5);
+    assert_eq!(5_u8.seconds(), Seconds(5_u8));
+    assert_eq!(100_u16.milliseconds(), Milliseconds(100_u16));
+
+    assert_eq!(Seconds::<u32>::from(Seconds(5_u8)), Seconds(5_u32));
+    assert_eq!(Seconds::<u16>::from(Seconds(5_u8)), Seconds(5_u16));
+    assert_eq!(Seconds::<u128>::from(Seconds(5_u16)), Seconds(5_u128));
+    assert_eq!(
+        Seconds::<u8>::try_from(Seconds(200_u64)),
+        Ok(Seconds(200_u8))
+    );
+    assert_eq!(
+        Seconds::<u8>::try_from(Seconds(256_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u16>::try_from(Minutes(2_u16)),
+        Ok(Seconds(120_u16))
+    );
+    assert_eq!(
+        Milliseconds::<u16>::try_from(Minutes(2_u16)),
+        Err(ConversionError::Unspecified)
+    );
+
+    assert_eq!(
+        Milliseconds(200_u8) + Milliseconds(50_u8),
+        Milliseconds(250_u8)
+    );
+    assert_eq!(Milliseconds(200_u8).checked_add(&Milliseconds(56_u8)), None);
+
+    // the conversion factor (1,000,000) doesn't have to fit
+    assert_eq!(Milliseconds(4_u8).to_rate(), Ok(Hertz(250_u8)));
+    assert_eq!(
+        Milliseconds(2_u8).to_rate::<Hertz<u8>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(Microseconds(250_u8).to_rate(), Ok(Kilohertz(4_u8)));
+    assert_eq!(
+        Milliseconds(0_u8).to_rate::<Hertz<u8>>(),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn integral_ratio_conversions() {
+    // scaling up only multiplies, scaling down only divides
+    assert_eq!(
+        Milliseconds::<u64>::try_from(Hours(u64::MAX / 3_600_000)),
+        Ok(Milliseconds(u64::MAX / 3_600_000 * 3_600_000))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Seconds(u32::MAX / 1_000 + 1)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        Seconds::<u128>::from(Nanoseconds(u128::MAX)),
+        Seconds(u128::MAX / 1_000_000_000)
+    );
+    assert_eq!(
+        Seconds::try_from_rounded(
+            Nanoseconds(u128::MAX),
+            embedded_time::fraction::Rounding::Up
+        ),
+        Ok(Seconds(u128::MAX / 1_000_000_000 + 1))
+    );
+
+    assert_eq!(
+        duration::Generic::new(u32::MAX, Fraction::new(3, 4)).try_into(),
+        Ok(Microseconds(3_221_225_471_250_000_u64))
+    );
+
+    // other ratios still widen
+    assert_eq!(
+        duration::Generic::new(u32::MAX, Fraction::new(1, 3)).try_into(),
+        Ok(Milliseconds(1_431_655_765_000_u64))
+    );
+}
+
+#[test]
+fn const_generic_ticks() {
+    type RtcTicks = Ticks<u32, 1, 32_768>;
+    type CpuCycles = Ticks<u64, 1, 72_000_000>;
+
+    assert_eq!(RtcTicks::try_from(Seconds(2_u32)), Ok(Ticks(65_536)));
+    assert_eq!(
+        Microseconds::<u32>::try_from(RtcTicks::new(1)),
+        Ok(Microseconds(30))
+    );
+    assert_eq!(
+        Ticks::<u32, 1, 1_000>::try_from(Seconds(u32::MAX)),
+        Err(ConversionError::Unspecified)
+    );
+
+    assert_eq!(
+        CpuCycles::try_from_rounded(RtcTicks::new(1), embedded_time::fraction::Rounding::Nearest),
+        Ok(Ticks(2_197))
+    );
+    assert_eq!(
+        RtcTicks::try_from_exact(CpuCycles::new(72_000_000)),
+        Ok(Ticks(32_768))
+    );
+
+    assert_eq!(
+        duration::Generic::from(RtcTicks::new(5)),
+        duration::Generic::new(5_u32, Fraction::new(1, 32_768))
+    );
+    assert_eq!(
+        RtcTicks::try_from(duration::Generic::new(1_u32, Fraction::new(1, 1_000))),
+        Ok(Ticks(32))
+    );
+
+    assert_eq!(RtcTicks::new(3) + Ticks(4), Ticks(7));
+    assert_eq!(RtcTicks::new(7) - Ticks(4), Ticks(3));
+    assert_eq!(RtcTicks::new(7) * 2, Ticks(14));
+    assert_eq!(RtcTicks::new(7) / 2, Ticks(3));
+    assert!(RtcTicks::default().is_zero());
+    assert_eq!(
+        format!("{}", Ticks::<u32, 1, 72_000_000>(8)),
+        "8 × 1/72000000 s"
+    );
 }
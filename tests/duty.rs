@@ -0,0 +1,65 @@
+use embedded_time::{duration::*, duty::DutyCycle, rate::*, ConversionError};
+
+#[test]
+fn construction() {
+    assert_eq!(DutyCycle::new(2, 4), DutyCycle::from_percent(50));
+    assert_eq!(DutyCycle::new(0, 7), Ok(DutyCycle::OFF));
+    assert_eq!(DutyCycle::from_percent(100), Ok(DutyCycle::FULL));
+    assert_eq!(DutyCycle::default(), DutyCycle::OFF);
+    assert_eq!(*DutyCycle::new(6, 9).unwrap().ratio().numerator(), 2);
+
+    assert_eq!(
+        DutyCycle::from_durations(Milliseconds(5_u32), Seconds(1_u32)),
+        DutyCycle::new(1, 200)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Nanoseconds(1_u64), Seconds(10_u64)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Seconds(2_u32), Milliseconds(1_999_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Seconds(0_u32), Milliseconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Minutes(u128::MAX), Hours(u128::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(DutyCycle::new(1, 0), Err(ConversionError::DivByZero));
+    assert_eq!(DutyCycle::from_percent(101), Err(ConversionError::Overflow));
+}
+
+#[test]
+fn conversions() {
+    let duty = DutyCycle::new(1, 3).unwrap();
+    assert_eq!(duty.percent(), 33);
+    assert_eq!(
+        duty.on_time(Microseconds(1_000_u32)),
+        Ok(Microseconds(333_u32))
+    );
+    assert_eq!(
+        duty.off_time(Microseconds(1_000_u32)),
+        Ok(Microseconds(667_u32))
+    );
+    assert_eq!(duty.on_time_at(Hertz(50_u32)), Ok(Microseconds(6_666_u32)));
+    assert_eq!(duty.compare_value(u32::MAX), u32::MAX / 3);
+
+    assert_eq!(DutyCycle::FULL.compare_value(u32::MAX), u32::MAX);
+    assert_eq!(DutyCycle::FULL.off_time(Seconds(1_u32)), Ok(Seconds(0_u32)));
+    assert_eq!(
+        DutyCycle::FULL.on_time(Seconds(u32::MAX)),
+        Ok(Seconds(u32::MAX))
+    );
+
+    assert_eq!(
+        duty.on_time_at::<Microseconds<u32>, _>(Hertz(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        duty.on_time_at::<Microseconds<u32>, _>(Megahertz(u128::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
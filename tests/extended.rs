@@ -0,0 +1,85 @@
+use core::cell::Cell;
+use embedded_time::{self as time, duration::*, extended::*, Clock as _, Instant};
+
+/// A 16-bit counter, reading `None` as a failure
+#[derive(Debug, Default)]
+struct Counter(Cell<Option<u32>>);
+
+impl time::Clock for Counter {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 16;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+fn clock(ticks: u32) -> ExtendedClock<Counter> {
+    let clock = ExtendedClock::new(Counter::default());
+    clock.counter().0.set(Some(ticks));
+    clock
+}
+
+#[test]
+fn polling() {
+    let clock = clock(0x1000);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x1000)));
+
+    for wrap in 1..=3_u64 {
+        clock.counter().0.set(Some(0xFF00));
+        assert_eq!(
+            clock.try_now(),
+            Ok(Instant::new((wrap - 1) * 0x1_0000 + 0xFF00))
+        );
+        clock.counter().0.set(Some(0x0100));
+        assert_eq!(clock.try_now(), Ok(Instant::new(wrap * 0x1_0000 + 0x0100)));
+    }
+
+    assert_eq!(
+        clock.try_now().unwrap() - Instant::new(0x1000),
+        Generic::new(0x2_F100_u64, Fraction::new(1, 1_000))
+    );
+}
+
+#[test]
+fn interrupt() {
+    let clock = clock(0x0010);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x0010)));
+
+    // no readings between the interrupts
+    clock.counter().0.set(Some(0x0020));
+    assert_eq!(clock.on_rollover(), Ok(()));
+    clock.counter().0.set(Some(0x0005));
+    assert_eq!(clock.on_rollover(), Ok(()));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x2_0005)));
+
+    // read while the interrupt is pending
+    clock.counter().0.set(Some(0xFFF0));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x2_FFF0)));
+    clock.counter().0.set(Some(0x0008));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x3_0008)));
+    assert_eq!(clock.on_rollover(), Ok(()));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x3_0008)));
+
+    // the next period is counted again
+    clock.counter().0.set(Some(0x0009));
+    assert_eq!(clock.on_rollover(), Ok(()));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x4_0009)));
+}
+
+#[test]
+fn errors() {
+    let clock = clock(0x0010);
+    clock.counter().0.set(None);
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NotRunning));
+    assert_eq!(clock.on_rollover(), Err(time::clock::Error::NotRunning));
+
+    // the first successful reading is that of the counter
+    clock.counter().0.set(Some(0x0020));
+    assert_eq!(clock.on_rollover(), Ok(()));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x0020)));
+}
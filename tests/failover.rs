@@ -0,0 +1,123 @@
+use core::cell::Cell;
+use embedded_time::{self as time, duration::*, failover::*, Clock as _, Instant};
+
+/// Reads `None` as a failure
+#[derive(Debug, Default)]
+struct Primary(Cell<Option<u32>>);
+
+impl time::Clock for Primary {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+/// Reads `None` as a failure
+#[derive(Debug, Default)]
+struct Backup(Cell<Option<u32>>);
+
+impl time::Clock for Backup {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+fn clock(primary: u32, backup: u32) -> FailoverClock<Primary, Backup> {
+    let clock =
+        FailoverClock::new(Primary::default(), Backup::default(), Milliseconds(10_u32)).unwrap();
+    clock.primary().0.set(Some(primary));
+    clock.backup().0.set(Some(backup));
+    clock
+}
+
+#[test]
+fn primary() {
+    let clock = clock(u32::MAX - 500, 0);
+    assert_eq!(clock.try_now(), Ok(Instant::new(u32::MAX - 500)));
+
+    clock.primary().0.set(Some(1_000));
+    clock.backup().0.set(Some(1));
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_000)));
+
+    // the backup isn't required while the primary is healthy
+    clock.primary().0.set(Some(2_000));
+    clock.backup().0.set(None);
+    assert_eq!(clock.try_now(), Ok(Instant::new(2_000)));
+    assert_eq!(clock.active_source(), Source::Primary);
+}
+
+#[test]
+fn primary_error() {
+    let clock = clock(5_000, 100);
+    assert_eq!(clock.try_now(), Ok(Instant::new(5_000)));
+
+    // the time since the last primary reading is bridged by the backup
+    clock.primary().0.set(None);
+    clock.backup().0.set(Some(102));
+    assert_eq!(clock.try_now(), Ok(Instant::new(7_000)));
+    assert_eq!(clock.active_source(), Source::Backup);
+
+    // the backup remains the source after the primary recovers
+    clock.primary().0.set(Some(0));
+    clock.backup().0.set(Some(105));
+    assert_eq!(clock.try_now(), Ok(Instant::new(10_000)));
+
+    clock.backup().0.set(None);
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NotRunning));
+}
+
+#[test]
+fn primary_stall() {
+    let clock = clock(5_000, 0);
+    assert_eq!(clock.try_now(), Ok(Instant::new(5_000)));
+
+    clock.backup().0.set(Some(9));
+    assert_eq!(clock.try_now(), Ok(Instant::new(5_000)));
+    assert_eq!(clock.active_source(), Source::Primary);
+
+    // stalled for 10 ms
+    clock.backup().0.set(Some(10));
+    assert_eq!(clock.try_now(), Ok(Instant::new(15_000)));
+    assert_eq!(clock.active_source(), Source::Backup);
+}
+
+#[test]
+fn restore_primary() {
+    let clock = clock(5_000, 0);
+    clock.try_now().unwrap();
+    clock.primary().0.set(None);
+    clock.backup().0.set(Some(1));
+    assert_eq!(clock.try_now(), Ok(Instant::new(6_000)));
+
+    // bridged by the backup until the next primary reading
+    clock.restore_primary();
+    clock.primary().0.set(Some(100));
+    clock.backup().0.set(Some(2));
+    assert_eq!(clock.try_now(), Ok(Instant::new(7_000)));
+    assert_eq!(clock.active_source(), Source::Primary);
+
+    clock.primary().0.set(Some(600));
+    assert_eq!(clock.try_now(), Ok(Instant::new(7_500)));
+}
+
+#[test]
+fn unavailable_at_start() {
+    let clock = clock(0, 0);
+    clock.primary().0.set(None);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+    assert_eq!(clock.active_source(), Source::Backup);
+
+    clock.backup().0.set(Some(3));
+    assert_eq!(clock.try_now(), Ok(Instant::new(3_000)));
+}
@@ -60,3 +60,51 @@ fn fraction_div_fraction() {
     assert_eq!(*product.numerator(), 100_000_u32);
     assert_eq!(*product.denominator(), 1_u32);
 }
+
+#[test_case(u32::MAX, (3,4) => Ok(3_221_225_471) ; "The intermediate product is widened")]
+#[test_case(5, (1_000,1) => Ok(5_000) ; "Integral fractions are exact")]
+#[test_case(5_000, (1,1_000) => Ok(5) ; "Reciprocals of integral fractions are exact")]
+#[test_case(5_001, (1,1_000) => Ok(5) ; "The result is truncated")]
+#[test_case(u32::MAX, (2,1) => Err(ConversionError::Overflow) ; "Overflow fails")]
+#[test_case(5, (1,0) => Err(ConversionError::DivByZero) ; "A denominator of 0 fails")]
+fn try_mul_integer(integer: u32, fraction: (u32, u32)) -> Result<u32, ConversionError> {
+    Fraction::new(fraction.0, fraction.1).try_mul_integer(integer)
+}
+
+#[test]
+fn compares_by_value() {
+    use core::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+
+    let hash = |fraction: Fraction| {
+        let mut hasher = DefaultHasher::new();
+        fraction.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    assert_eq!(Fraction::new(1, 2), Fraction::new(2, 4));
+    assert_eq!(hash(Fraction::new(1, 2)), hash(Fraction::new(2, 4)));
+    assert_ne!(Fraction::new(1, 2), Fraction::new(1, 3));
+
+    assert!(Fraction::new(1, 3) < Fraction::new(1, 2));
+    // (the cross products don't fit in a `u32`)
+    assert!(Fraction::new(u32::MAX - 1, u32::MAX - 2) > Fraction::new(u32::MAX, u32::MAX - 1));
+    assert!(Fraction::new(3, 2) > Fraction::new(1, 1));
+}
+
+#[test_case((u32::MAX, 3), (6, u32::MAX) => Some((2, 1)) ; "Factors are cross-reduced before multiplying")]
+#[test_case((u32::MAX, 1), (2, 1) => None ; "Overflow fails")]
+#[test_case((1, 0), (1, 1) => None ; "A denominator of 0 fails")]
+fn checked_mul(lhs: (u32, u32), rhs: (u32, u32)) -> Option<(u32, u32)> {
+    Fraction::new(lhs.0, lhs.1)
+        .checked_mul(&Fraction::new(rhs.0, rhs.1))
+        .map(|fraction| (*fraction.numerator(), *fraction.denominator()))
+}
+
+#[test_case((1, 2), (0, 1) => None ; "Division by 0 fails")]
+#[test_case((1, 2), (1, 4) => Some((2, 1)) ; "Returns the quotient in lowest terms")]
+fn checked_div(lhs: (u32, u32), rhs: (u32, u32)) -> Option<(u32, u32)> {
+    Fraction::new(lhs.0, lhs.1)
+        .checked_div(&Fraction::new(rhs.0, rhs.1))
+        .map(|fraction| (*fraction.numerator(), *fraction.denominator()))
+}
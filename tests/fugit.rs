@@ -0,0 +1,88 @@
+#![cfg(feature = "fugit")]
+
+use core::convert::TryFrom;
+use embedded_time::{self as time, duration, duration::*, rate, rate::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn durations() {
+    let duration = fugit::MicrosDurationU64::from_ticks(2_500_000);
+
+    assert_eq!(
+        duration::Generic::from(duration),
+        duration::Generic::new(2_500_000_u64, Fraction::new(1, 1_000_000))
+    );
+    assert_eq!(Seconds::<u64>::try_from(duration), Ok(Seconds(2_u64)));
+    assert_eq!(
+        Milliseconds::<u64>::try_from(duration),
+        Ok(Milliseconds(2_500_u64))
+    );
+
+    assert_eq!(
+        fugit::MillisDurationU32::try_from(Minutes(2_u32)),
+        Ok(fugit::MillisDurationU32::from_ticks(120_000))
+    );
+    assert_eq!(
+        fugit::Duration::<u32, 1, 1_000>::try_from(duration::Generic::new(
+            3_u32,
+            Fraction::new(1, 1)
+        )),
+        Ok(fugit::Duration::<u32, 1, 1_000>::from_ticks(3_000))
+    );
+
+    assert_eq!(
+        fugit::NanosDurationU32::try_from(Seconds(5_u32)),
+        Err(ConversionError::Unspecified)
+    );
+}
+
+#[test]
+fn rates() {
+    let rate = fugit::MegahertzU32::from_raw(16);
+
+    assert_eq!(
+        rate::Generic::from(rate),
+        rate::Generic::new(16_u32, Fraction::new(1_000_000, 1))
+    );
+    assert_eq!(Kilohertz::<u32>::try_from(rate), Ok(Kilohertz(16_000_u32)));
+
+    assert_eq!(
+        fugit::KilohertzU64::try_from(Hertz(1_500_u64)),
+        Ok(fugit::KilohertzU64::from_raw(1))
+    );
+    assert_eq!(
+        fugit::HertzU32::try_from(rate::Generic::new(2_u32, Fraction::new(1_000, 1))),
+        Ok(fugit::HertzU32::from_raw(2_000))
+    );
+}
+
+#[test]
+fn instants() {
+    let instant = fugit::TimerInstantU32::<1_000>::from_ticks(12_345);
+
+    assert_eq!(
+        Instant::<Clock>::try_from(instant),
+        Ok(Instant::new(12_345))
+    );
+    assert_eq!(
+        fugit::TimerInstantU32::<1_000>::try_from(Instant::<Clock>::new(42)),
+        Ok(fugit::TimerInstantU32::<1_000>::from_ticks(42))
+    );
+
+    // rescaled
+    assert_eq!(
+        fugit::TimerInstantU32::<100>::try_from(Instant::<Clock>::new(42)),
+        Ok(fugit::TimerInstantU32::<100>::from_ticks(4))
+    );
+}
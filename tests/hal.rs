@@ -0,0 +1,129 @@
+#![cfg(feature = "embedded-hal")]
+
+use core::cell::Cell;
+use embedded_hal::timer::CountDown;
+use embedded_time::{self as time, duration::*, hal::*, Instant};
+
+/// Reads `None` as a failure
+#[derive(Debug, Default)]
+struct Clock(Cell<Option<u32>>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(time::clock::Error::NotRunning)
+    }
+}
+
+#[derive(Debug, Default)]
+struct HardwareTimer {
+    count: Microseconds<u32>,
+    waits: u32,
+}
+
+impl CountDown for HardwareTimer {
+    type Time = Microseconds<u32>;
+
+    fn start<T: Into<Self::Time>>(&mut self, count: T) {
+        self.count = count.into();
+    }
+
+    fn wait(&mut self) -> nb::Result<(), void::Void> {
+        self.waits += 1;
+        if self.waits < 3 {
+            Err(nb::Error::WouldBlock)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn start_for() {
+    let mut timer = HardwareTimer::default();
+    assert_eq!(timer.start_for(Seconds(2_u32)), Ok(()));
+    assert_eq!(timer.count, Microseconds(2_000_000_u32));
+
+    assert_eq!(timer.start_for(Nanoseconds(1_500_u64)), Ok(()));
+    assert_eq!(timer.count, Microseconds(1_u32));
+
+    assert!(timer.start_for(Hours(2_u32)).is_err());
+    assert_eq!(timer.count, Microseconds(1_u32));
+}
+
+#[test]
+fn wait_for() {
+    let mut timer = HardwareTimer::default();
+    assert_eq!(timer.wait_for(Milliseconds(5_u32)), Ok(()));
+    assert_eq!(timer.count, Microseconds(5_000_u32));
+    assert_eq!(timer.waits, 3);
+}
+
+#[test]
+fn clock_count_down() {
+    let clock = Clock(Cell::new(Some(u32::MAX - 5)));
+    let mut count_down = ClockCountDown::<_, Milliseconds<u32>>::new(&clock);
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+
+    count_down.start(Milliseconds(10_u32));
+    clock.0.set(Some(3));
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+
+    // periods are scheduled without drift, across the wrap
+    clock.0.set(Some(8));
+    assert_eq!(count_down.wait(), Ok(()));
+    clock.0.set(Some(13));
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+    clock.0.set(Some(14));
+    assert_eq!(count_down.wait(), Ok(()));
+
+    // clock errors don't expire the count down
+    clock.0.set(None);
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+    clock.0.set(Some(24));
+    assert_eq!(count_down.wait(), Ok(()));
+}
+
+#[test]
+fn clock_unavailable_at_start() {
+    let clock = Clock(Cell::new(None));
+    let mut count_down = ClockCountDown::<_, Seconds<u32>>::new(&clock);
+    count_down.start(Seconds(1_u32));
+
+    // the count down starts on the first successful reading
+    clock.0.set(Some(500));
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+    clock.0.set(Some(1_499));
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+    clock.0.set(Some(1_500));
+    assert_eq!(count_down.wait(), Ok(()));
+}
+
+#[test]
+fn period_overflow() {
+    let clock = Clock(Cell::new(Some(0)));
+    let mut count_down = ClockCountDown::<_, Seconds<u32>>::new(&clock);
+    assert!(count_down.try_start(Seconds(u32::MAX)).is_err());
+    assert_eq!(
+        count_down.try_start(Seconds(u32::MAX / 1_000)),
+        Err(time::ConversionError::Overflow)
+    );
+    // not started
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+
+    // limited to the valid comparison window
+    count_down.start(Seconds(u32::MAX));
+    clock.0.set(Some(u32::MAX / 2 - 1));
+    assert_eq!(count_down.wait(), Err(nb::Error::WouldBlock));
+    clock.0.set(Some(u32::MAX / 2));
+    assert_eq!(count_down.wait(), Ok(()));
+
+    assert_eq!(count_down.try_start(Seconds(1_u32)), Ok(()));
+    clock.0.set(Some(u32::MAX / 2 + 1_000));
+    assert_eq!(count_down.wait(), Ok(()));
+}
@@ -58,6 +58,18 @@ fn duration_until() {
     assert_eq!(diff, None);
 }
 
+#[test]
+fn sub_instant() {
+    assert_eq!(
+        Instant::<Clock>::new(6) - Instant::<Clock>::new(5),
+        duration::Generic::new(1_u32, Fraction::new(1, 1_000))
+    );
+
+    let millis: Result<Milliseconds<u32>, _> =
+        (Instant::<Clock>::new(3) - Instant::<Clock>::new(u32::MAX - 1)).try_into();
+    assert_eq!(millis, Ok(Milliseconds(5_u32)));
+}
+
 #[test]
 fn duration_since_epoch() {
     assert_eq!(
@@ -77,3 +89,216 @@ fn checked_add(base: u32, addition: u32) -> Option<Instant<Clock>> {
 fn checked_sub(base: u32, subtrahend: u32) -> Option<Instant<Clock>> {
     Instant::<Clock>::new(base).checked_sub(Milliseconds(subtrahend))
 }
+
+#[test]
+fn add_sub_duration() {
+    let now = Instant::<Clock>::new(u32::MAX - 4);
+    assert_eq!(now + Milliseconds(10_u32), Instant::new(5));
+    assert_eq!(now + Seconds(1_u64), Instant::new(995));
+    assert_eq!(Instant::<Clock>::new(5) - Milliseconds(10_u32), now);
+
+    let mut instant = now;
+    instant += Milliseconds(10_u32);
+    assert_eq!(instant, Instant::new(5));
+    instant += Seconds(1_u32);
+    assert_eq!(instant, Instant::new(1_005));
+    instant -= Microseconds(1_010_000_u32);
+    assert_eq!(instant, now);
+}
+
+#[test]
+#[should_panic]
+fn add_assign_beyond_comparison_window() {
+    let mut instant = Instant::<Clock>::new(0);
+    instant += Milliseconds(u32::MAX / 2 + 1);
+}
+
+#[test]
+fn valid_comparison_window() {
+    assert_eq!(
+        Instant::<Clock>::valid_comparison_window(),
+        duration::Generic::new(u32::MAX / 2, Fraction::new(1, 1_000))
+    );
+
+    // the maximum duration that can be added is exactly the window
+    let window: Milliseconds<u32> = Instant::<Clock>::valid_comparison_window()
+        .try_into()
+        .unwrap();
+    assert!(Instant::<Clock>::new(0).checked_add(window).is_some());
+    assert!(Instant::<Clock>::new(0)
+        .checked_add(window + Milliseconds(1_u32))
+        .is_none());
+}
+
+#[test]
+fn wrap_horizon() {
+    use time::Clock as _;
+
+    assert_eq!(
+        Clock::wrap_horizon(),
+        duration::Generic::new(u32::MAX, Fraction::new(1, 1_000))
+    );
+}
+
+#[test]
+fn tick_introspection() {
+    use time::{rate::*, Clock as _};
+
+    assert_eq!(Clock::ticks_to_duration(1_500), Ok(Seconds(1_u32)));
+    assert_eq!(
+        Clock::ticks_to_duration(1_500),
+        Ok(Microseconds(1_500_000_u64))
+    );
+    assert_eq!(
+        Clock::ticks_to_duration::<Microseconds<u32>>(u32::MAX),
+        Err(time::ConversionError::Unspecified)
+    );
+
+    assert_eq!(Clock::frequency(), Ok(Hertz(1_000_u32)));
+    assert_eq!(Clock::frequency(), Ok(Kilohertz(1_u32)));
+    assert_eq!(Clock::frequency::<Megahertz<u32>>(), Ok(Megahertz(0_u32)));
+
+    // a narrower counter of the same frequency has the same resolution; a faster one is finer
+    assert_eq!(NarrowClock::SCALING_FACTOR, Clock::SCALING_FACTOR);
+    assert!(Rtc::SCALING_FACTOR < Clock::SCALING_FACTOR);
+}
+
+/// A 16-bit hardware counter
+#[derive(Debug)]
+struct NarrowClock;
+
+impl time::Clock for NarrowClock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 16;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn narrow_counter() {
+    use time::Clock as _;
+
+    assert_eq!(
+        NarrowClock::wrap_horizon(),
+        duration::Generic::new(0xFFFF_u32, Fraction::new(1, 1_000))
+    );
+    assert_eq!(
+        Instant::<NarrowClock>::valid_comparison_window(),
+        duration::Generic::new(0x7FFF_u32, Fraction::new(1, 1_000))
+    );
+
+    // across a rollover
+    let earlier = Instant::<NarrowClock>::new(0xFFF0);
+    let later = Instant::<NarrowClock>::new(0x0010);
+    assert!(later > earlier);
+    assert_eq!(
+        later.checked_duration_since(&earlier),
+        Some(duration::Generic::new(0x20_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        earlier.checked_duration_until(&later),
+        Some(duration::Generic::new(0x20_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(later.checked_duration_since(&Instant::new(0x8001)), None);
+
+    assert_eq!(earlier.checked_add(Milliseconds(0x20_u32)), Some(later));
+    assert_eq!(later.checked_sub(Milliseconds(0x20_u32)), Some(earlier));
+    assert_eq!(earlier.checked_add(Milliseconds(0x8000_u32)), None);
+}
+
+#[derive(Debug)]
+struct U16Clock;
+
+impl time::Clock for U16Clock {
+    type T = u16;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn u16_ticks() {
+    use time::Clock as _;
+
+    assert_eq!(
+        U16Clock::wrap_horizon(),
+        duration::Generic::new(u16::MAX, Fraction::new(1, 1_000))
+    );
+
+    let earlier = Instant::<U16Clock>::new(0xFFF0);
+    let later = Instant::<U16Clock>::new(0x0010);
+    assert!(later > earlier);
+    assert_eq!(
+        later.checked_duration_since(&earlier),
+        Some(duration::Generic::new(0x20_u16, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(earlier.checked_add(Milliseconds(0x20_u16)), Some(later));
+    assert_eq!(later.checked_sub(Milliseconds(0x20_u16)), Some(earlier));
+}
+
+#[derive(Debug)]
+struct Rtc;
+
+impl time::Clock for Rtc {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[derive(Debug)]
+struct HighResolutionTimer;
+
+impl time::Clock for HighResolutionTimer {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn convert_to() {
+    let rtc = Instant::<Rtc>::new;
+    let timer = Instant::<HighResolutionTimer>::new;
+    let reference = (rtc(1_000), timer(5_000_000));
+
+    assert_eq!(rtc(1_000).convert_to(reference), Ok(timer(5_000_000)));
+    assert_eq!(
+        rtc(1_000 + 32_768).convert_to(reference),
+        Ok(timer(6_000_000))
+    );
+    // one tick is 30.52 µs (rounded)
+    assert_eq!(rtc(1_001).convert_to(reference), Ok(timer(5_000_031)));
+    // earlier than the reference (across the RTC's rollover)
+    assert_eq!(
+        rtc(1_000_u32.wrapping_sub(16_384)).convert_to(reference),
+        Ok(timer(4_500_000))
+    );
+
+    // and back
+    let reference = (reference.1, reference.0);
+    assert_eq!(
+        timer(6_000_000).convert_to(reference),
+        Ok(rtc(1_000 + 32_768))
+    );
+    assert_eq!(timer(4_999_985).convert_to(reference), Ok(rtc(1_000)));
+
+    // across the timer's rollover
+    let reference = (rtc(0), timer(u32::MAX - 499_999));
+    assert_eq!(rtc(32_768).convert_to(reference), Ok(timer(500_000)));
+
+    // beyond the timer's ±2,147 s window
+    assert_eq!(
+        rtc(3_000 * 32_768).convert_to(reference),
+        Err(time::ConversionError::Overflow)
+    );
+}
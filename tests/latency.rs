@@ -0,0 +1,98 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    latency::*,
+    queue::{TimeoutQueue, TimerQueue},
+    Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn max_lateness() {
+    let mut lateness = MaxLateness::<Clock>::default();
+    assert_eq!(lateness.max(), Generic::new(0_u32, Fraction::new(1, 1_000)));
+
+    lateness.record(Instant::new(10), Instant::new(15));
+    lateness.record(Instant::new(20), Instant::new(22));
+    // serviced early
+    lateness.record(Instant::new(30), Instant::new(29));
+    // across a clock wrap
+    lateness.record(Instant::new(u32::MAX - 1), Instant::new(4));
+
+    assert_eq!(lateness.max(), Generic::new(6_u32, Fraction::new(1, 1_000)));
+    assert_eq!(lateness.samples(), 4);
+
+    lateness.reset();
+    assert_eq!(lateness.samples(), 0);
+}
+
+#[test]
+fn discarding_recorder() {
+    let mut recorder = ();
+    Recorder::<Clock>::record(&mut recorder, Instant::new(0), Instant::new(1));
+}
+
+#[test]
+fn timer_queue_service() {
+    let mut queue = TimerQueue::<Clock, usize, 4>::new();
+    // across a clock wrap
+    for (token, expiration) in [u32::MAX - 2, 3, 1, 50].iter().enumerate() {
+        queue.push(Instant::new(*expiration), token).unwrap();
+    }
+
+    let mut worst = MaxLateness::new();
+    let mut per_timer = [None; 4];
+    let serviced = queue.service(Instant::new(5), &mut worst, |token, lateness| {
+        per_timer[token] = Some(lateness);
+    });
+
+    assert_eq!(serviced, 3);
+    assert_eq!(
+        per_timer,
+        [
+            Some(Generic::new(8_u32, Fraction::new(1, 1_000))),
+            Some(Generic::new(2_u32, Fraction::new(1, 1_000))),
+            Some(Generic::new(4_u32, Fraction::new(1, 1_000))),
+            None
+        ]
+    );
+    assert_eq!(worst.max(), Generic::new(8_u32, Fraction::new(1, 1_000)));
+    assert_eq!(worst.samples(), 3);
+    assert_eq!(queue.len(), 1);
+
+    // through the trait, recording nothing
+    let mut tokens = Vec::new();
+    let serviced = TimeoutQueue::service(&mut queue, Instant::new(50), &mut (), |token, _| {
+        tokens.push(token)
+    });
+    assert_eq!(serviced, 1);
+    assert_eq!(tokens, [3]);
+    assert!(queue.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_recorder() {
+    let mut trace = Vec::new();
+    Recorder::<Clock>::record(&mut trace, Instant::new(10), Instant::new(12));
+    Recorder::<Clock>::record(&mut trace, Instant::new(20), Instant::new(20));
+
+    assert_eq!(
+        trace,
+        [
+            (Instant::new(10), Instant::new(12)),
+            (Instant::new(20), Instant::new(20))
+        ]
+    );
+}
@@ -69,10 +69,35 @@ fn common_types() {
     assert!(then < now);
 }
 
+/// An external RTC whose bus reads fail
+#[derive(Debug)]
+struct UnreadableClock;
+
+impl time::Clock for UnreadableClock {
+    type T = u32;
+    const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 1);
+
+    fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::ReadFailure(0x20))
+    }
+}
+
 #[test]
 fn errors() {
     assert_eq!(BadClock.try_now(), Err(time::clock::Error::NotRunning));
 
+    assert_eq!(
+        UnreadableClock.try_now(),
+        Err(time::clock::Error::ReadFailure(0x20))
+    );
+    assert_eq!(
+        UnreadableClock
+            .new_timer(Seconds(1_u32))
+            .start()
+            .map(|_| ()),
+        Err(TimeError::Clock(time::clock::Error::ReadFailure(0x20)))
+    );
+
     assert_eq!(
         TimeError::from(time::clock::Error::NotRunning),
         TimeError::Clock(time::clock::Error::NotRunning)
@@ -98,6 +123,33 @@ fn errors() {
         TimeError::from(ConversionError::NegDuration),
         TimeError::NegDuration
     );
+
+    assert_eq!(ConversionError::Overflow.to_string(), "value overflowed");
+    assert_eq!(
+        ConversionError::LossOfPrecision.to_string(),
+        "conversion would discard a remainder"
+    );
+
+    assert_eq!(TimeError::NegDuration.to_string(), "negative duration");
+    assert_eq!(
+        TimeError::Clock(time::clock::Error::ReadFailure(0x20)).to_string(),
+        "clock error: clock read failed (code 32)"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_errors() {
+    use std::error::Error;
+
+    let error = TimeError::Clock(time::clock::Error::NotRunning);
+    assert_eq!(
+        error.source().map(ToString::to_string),
+        Some("clock not running".to_string())
+    );
+    assert!(TimeError::from(ConversionError::Overflow)
+        .source()
+        .is_none());
 }
 
 struct Timestamp<Clock>(time::Instant<Clock>)
@@ -131,7 +183,10 @@ where
 
         f.write_fmt(format_args!(
             "{}:{:02}:{:02}.{:03}",
-            hours, minutes, seconds, milliseconds
+            hours.integer(),
+            minutes.integer(),
+            seconds.integer(),
+            milliseconds.integer()
         ))
     }
 }
@@ -0,0 +1,69 @@
+use embedded_time::{
+    self as time, clock, duration::*, mock::MockClock, Clock as _, ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct SysTick;
+
+impl time::Clock for SysTick {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 24;
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn advance_and_set() {
+    let clock = MockClock::<SysTick>::default();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    clock.advance(Seconds(2_u32)).unwrap();
+    clock.advance(Microseconds(1_999_u32)).unwrap();
+    clock.advance_ticks(3);
+    assert_eq!(clock.try_now(), Ok(Instant::new(2_004)));
+
+    clock.set(Instant::new(50));
+    assert_eq!(clock.try_now(), Ok(Instant::new(50)));
+
+    assert_eq!(
+        clock.advance(Seconds(u32::MAX)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(clock.try_now(), Ok(Instant::new(50)));
+}
+
+#[test]
+fn wraps_like_the_mocked_clock() {
+    let clock = MockClock::<SysTick>::new();
+    clock.set(Instant::new((1 << 24) - 2));
+
+    clock.advance(Milliseconds(5_u32)).unwrap();
+    assert_eq!(clock.try_now(), Ok(Instant::new(3)));
+}
+
+#[test]
+fn stopped() {
+    let clock = MockClock::<SysTick>::new();
+
+    clock.set_running(false);
+    assert_eq!(clock.try_now(), Err(clock::Error::NotRunning));
+
+    clock.set_running(true);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+}
+
+#[test]
+fn timers() {
+    let clock = MockClock::<SysTick>::new();
+    let timer = clock.new_timer(Milliseconds(100_u32)).start().unwrap();
+
+    clock.advance(Milliseconds(60_u32)).unwrap();
+    assert_eq!(timer.is_expired(), Ok(false));
+    assert_eq!(timer.remaining(), Ok(Milliseconds(40_u32)));
+
+    clock.advance(Milliseconds(40_u32)).unwrap();
+    assert_eq!(timer.is_expired(), Ok(true));
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "wallclock")]
+
+use core::convert::TryFrom;
+use embedded_time::{duration::*, ntp::*, wallclock::Timestamp, ConversionError};
+
+#[test]
+fn timestamp_fields() {
+    let timestamp = NtpTimestamp::new(0xE308_8E80, 0x4000_0000);
+    assert_eq!(timestamp.seconds(), 0xE308_8E80);
+    assert_eq!(timestamp.fraction(), 0x4000_0000);
+    assert_eq!(
+        timestamp.to_be_bytes(),
+        [0xE3, 0x08, 0x8E, 0x80, 0x40, 0x00, 0x00, 0x00]
+    );
+    assert_eq!(
+        NtpTimestamp::from_be_bytes(timestamp.to_be_bytes()),
+        timestamp
+    );
+}
+
+#[test]
+fn timestamp_conversions() {
+    let timestamp = Timestamp::new(1_600_000_000, 250_000_000).unwrap();
+    let ntp = NtpTimestamp::try_from(timestamp).unwrap();
+    assert_eq!(ntp, NtpTimestamp::new(0xE308_8E80, 0x4000_0000));
+    assert_eq!(Timestamp::try_from(ntp), Ok(timestamp));
+
+    assert_eq!(
+        NtpTimestamp::try_from(Timestamp::UNIX_EPOCH),
+        Ok(NtpTimestamp::new(2_208_988_800, 0))
+    );
+
+    // truncated to nanoseconds
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(2_208_988_800, 1)),
+        Ok(Timestamp::UNIX_EPOCH)
+    );
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(2_208_988_800, u32::MAX)),
+        Ok(Timestamp::new(0, 999_999_999).unwrap())
+    );
+}
+
+#[test]
+fn era_rollover() {
+    // 2036-02-07 06:28:16 UTC
+    let rollover = Timestamp::from_unix_seconds(2_085_978_496);
+    assert_eq!(
+        NtpTimestamp::try_from(rollover),
+        Ok(NtpTimestamp::new(0, 0))
+    );
+    assert_eq!(Timestamp::try_from(NtpTimestamp::new(0, 0)), Ok(rollover));
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(u32::MAX, 0)),
+        Ok(Timestamp::from_unix_seconds(2_085_978_495))
+    );
+
+    // the end of era 1
+    let last = Timestamp::from_unix_seconds(4_233_462_143);
+    assert_eq!(
+        NtpTimestamp::try_from(last),
+        Ok(NtpTimestamp::new(0x7FFF_FFFF, 0))
+    );
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(0x7FFF_FFFF, 0)),
+        Ok(last)
+    );
+    assert_eq!(
+        NtpTimestamp::try_from(Timestamp::from_unix_seconds(4_233_462_144)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn before_unix_epoch() {
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(0x8000_0000, 0)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Timestamp::try_from(NtpTimestamp::new(2_208_988_799, u32::MAX)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn short_format() {
+    let short = NtpShort::new(1, 0x8000);
+    assert_eq!(short, NtpShort(0x0001_8000));
+    assert_eq!(short.to_be_bytes(), [0x00, 0x01, 0x80, 0x00]);
+    assert_eq!(NtpShort::from_be_bytes([0x00, 0x01, 0x80, 0x00]), short);
+
+    assert_eq!(NtpShort::from_duration(Milliseconds(1_500_u32)), Ok(short));
+    assert_eq!(
+        NtpShort::from_duration(Microseconds(1_500_000_u64)),
+        Ok(short)
+    );
+    assert_eq!(short.to_duration(), Ok(Milliseconds(1_500_u32)));
+    assert_eq!(short.to_duration(), Ok(Microseconds(1_500_000_u32)));
+    assert_eq!(short.to_duration(), Ok(Seconds(1_u32)));
+
+    // truncated to 2⁻¹⁶ s
+    assert_eq!(
+        NtpShort::from_duration(Microseconds(15_u32)),
+        Ok(NtpShort(0))
+    );
+    assert_eq!(
+        NtpShort::from_duration(Microseconds(16_u32)),
+        Ok(NtpShort(1))
+    );
+
+    assert_eq!(
+        NtpShort::from_duration(Seconds(65_535_u32)),
+        Ok(NtpShort::new(u16::MAX, 0))
+    );
+    assert_eq!(
+        NtpShort::from_duration(Seconds(65_536_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        NtpShort(u32::MAX).to_duration::<Nanoseconds<u32>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+}
@@ -0,0 +1,104 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    persist::{self, Persist, ENCODED_LEN},
+    Instant,
+};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[derive(Debug)]
+struct FastClock;
+
+impl time::Clock for FastClock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn instant_round_trip() {
+    let instant = Instant::<Clock>::new(u32::MAX - 5);
+    let bytes = instant.persist();
+
+    assert_eq!(bytes.len(), ENCODED_LEN);
+    assert_eq!(&bytes[0..2], &persist::MAGIC);
+    assert_eq!(bytes[2], persist::VERSION);
+    assert_eq!(Instant::<Clock>::restore(&bytes), Ok(instant));
+}
+
+/// The same tick rate as `Clock`, with an unreduced scaling factor
+#[derive(Debug)]
+struct UnreducedClock;
+
+impl time::Clock for UnreducedClock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(2, 65_536);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn equal_scaling_factors() {
+    assert_eq!(
+        Instant::<UnreducedClock>::restore(&Instant::<Clock>::new(5).persist()),
+        Ok(Instant::new(5))
+    );
+    assert_eq!(
+        Instant::<Clock>::restore(&Instant::<UnreducedClock>::new(5).persist()),
+        Ok(Instant::new(5))
+    );
+}
+
+#[test]
+fn duration_round_trip() {
+    assert_eq!(
+        Microseconds::<u64>::restore(&Microseconds(u64::MAX).persist()),
+        Ok(Microseconds(u64::MAX))
+    );
+
+    // the integer type may differ as long as the value fits
+    assert_eq!(
+        Seconds::<u32>::restore(&Seconds(23_u64).persist()),
+        Ok(Seconds(23_u32))
+    );
+}
+
+#[test_case(&Instant::<FastClock>::new(5).persist() => Err(persist::Error::IncompatibleScalingFactor) ; "Different tick rate")]
+#[test_case(&Milliseconds(5_u32).persist() => Err(persist::Error::WrongKind) ; "A duration is not an instant")]
+#[test_case(&[0; 12] => Err(persist::Error::Truncated) ; "Too few bytes")]
+#[test_case(&[0; ENCODED_LEN] => Err(persist::Error::BadMagic) ; "Missing magic")]
+fn restore_instant_errors(bytes: &[u8]) -> Result<Instant<Clock>, persist::Error> {
+    Instant::<Clock>::restore(bytes)
+}
+
+#[test]
+fn restore_duration_errors() {
+    let mut bytes = Seconds(1_u32).persist();
+    bytes[2] = persist::VERSION + 1;
+    assert_eq!(
+        Seconds::<u32>::restore(&bytes),
+        Err(persist::Error::UnsupportedVersion)
+    );
+
+    assert_eq!(
+        Seconds::<u32>::restore(&Seconds(u32::MAX as u64 + 1).persist()),
+        Err(persist::Error::Overflow)
+    );
+}
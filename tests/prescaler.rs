@@ -0,0 +1,66 @@
+use embedded_time::{duration::*, prescaler::Prescaler, rate::*, ConversionError};
+
+#[test]
+fn exact() {
+    let dividers =
+        Prescaler::for_rate(Megahertz(48_u32), Kilohertz(1_u32), 65_536, 65_536).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (1, 48_000));
+    assert_eq!(dividers.error_ppm(), 0);
+    assert_eq!(dividers.achieved_rate::<Hertz>(), Ok(Hertz(1_000_u32)));
+
+    // the reload doesn't fit without prescaling
+    let dividers = Prescaler::for_rate(Megahertz(48_u32), Hertz(10_u32), 65_536, 65_536).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (75, 64_000));
+    assert_eq!(dividers.achieved_rate::<Hertz>(), Ok(Hertz(10_u32)));
+    assert_eq!(dividers.achieved_rate::<Kilohertz>(), Ok(Kilohertz(0_u32)));
+
+    let dividers = Prescaler::for_period(Hertz(32_768_u32), Seconds(2_u32), 65_536, 256).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (256, 256));
+    assert_eq!(dividers.error_ppm(), 0);
+}
+
+#[test]
+fn inexact() {
+    let dividers = Prescaler::for_rate(Megahertz(1_u32), Hertz(3_u32), 16, 65_536).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (7, 47_619));
+    assert_eq!(dividers.error_ppm(), 1);
+
+    // out of range, the dividers are saturated
+    let dividers =
+        Prescaler::for_period(Megahertz(16_u32), Microseconds(1_500_u32), 8, 256).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (8, 256));
+    assert_eq!(dividers.error_ppm(), 10_718_750);
+    assert_eq!(dividers.achieved_rate::<Hertz>(), Ok(Hertz(7_812_u32)));
+
+    let dividers = Prescaler::for_rate(Hertz(100_u32), Hertz(1_000_u32), 8, 256).unwrap();
+    assert_eq!((dividers.prescaler(), dividers.reload()), (1, 1));
+    assert_eq!(dividers.error_ppm(), -900_000);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Prescaler::for_rate(Hertz(0_u32), Hertz(1_u32), 1, 1),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Prescaler::for_period(Hertz(1_u32), Seconds(0_u32), 1, 1),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Prescaler::for_rate(Hertz(1_u32), Hertz(1_u32), 0, 1),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Prescaler::for_rate(Megahertz(u64::MAX), Hertz(1_u32), 1, 1),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Prescaler::for_period(Hertz(u64::MAX), Hours(u64::MAX), 256, 65_536),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Prescaler::for_rate(Megahertz(u128::MAX), Hertz(1_u32), 256, 65_536),
+        Err(ConversionError::Overflow)
+    );
+}
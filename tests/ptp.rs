@@ -0,0 +1,176 @@
+#![cfg(feature = "wallclock")]
+
+use core::convert::TryFrom;
+use embedded_time::{
+    duration::*,
+    ptp::*,
+    wallclock::{TaiTimestamp, Timestamp},
+    ConversionError,
+};
+
+#[test]
+fn construction() {
+    let timestamp = PtpTimestamp::new(0x1234_5678_9ABC, 999_999_999).unwrap();
+    assert_eq!(timestamp.seconds(), 0x1234_5678_9ABC);
+    assert_eq!(timestamp.nanoseconds(), 999_999_999);
+    assert_eq!(
+        timestamp.to_be_bytes(),
+        [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0x3B, 0x9A, 0xC9, 0xFF]
+    );
+    assert_eq!(
+        PtpTimestamp::from_be_bytes(timestamp.to_be_bytes()),
+        Ok(timestamp)
+    );
+
+    assert_eq!(
+        PtpTimestamp::new(1 << 48, 0),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        PtpTimestamp::new(0, 1_000_000_000),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        PtpTimestamp::from_be_bytes([0, 0, 0, 0, 0, 0, 0x3B, 0x9A, 0xCA, 0x00]),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn tai_conversions() {
+    let tai = TaiTimestamp(Timestamp::new(1_600_000_037, 250).unwrap());
+    let timestamp = PtpTimestamp::try_from(tai).unwrap();
+    assert_eq!(timestamp, PtpTimestamp::new(1_600_000_037, 250).unwrap());
+    assert_eq!(TaiTimestamp::from(timestamp), tai);
+
+    assert_eq!(
+        PtpTimestamp::try_from(TaiTimestamp(Timestamp::from_unix_seconds(1 << 48))),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn durations() {
+    let timestamp = PtpTimestamp::from_duration(Milliseconds(1_500_u32)).unwrap();
+    assert_eq!(timestamp, PtpTimestamp::new(1, 500_000_000).unwrap());
+    assert_eq!(
+        timestamp.duration_since_epoch(),
+        Ok(Milliseconds(1_500_u32))
+    );
+    assert_eq!(
+        PtpTimestamp::from_duration(Seconds(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        timestamp.checked_add(Microseconds(600_000_u32)),
+        PtpTimestamp::new(2, 100_000_000).ok()
+    );
+    assert_eq!(
+        timestamp.checked_sub(Milliseconds(1_500_u32)),
+        Some(PtpTimestamp::default())
+    );
+    assert_eq!(timestamp.checked_sub(Seconds(2_u32)), None);
+    assert_eq!(
+        PtpTimestamp::new((1 << 48) - 1, 0)
+            .unwrap()
+            .checked_add(Seconds(1_u32)),
+        None
+    );
+
+    let later = PtpTimestamp::new(3, 0).unwrap();
+    assert_eq!(
+        later.checked_duration_since(&timestamp),
+        Some(Milliseconds(1_500_u32))
+    );
+    assert_eq!(
+        timestamp.checked_duration_since::<Milliseconds<u32>>(&later),
+        None
+    );
+}
+
+#[test]
+fn corrections() {
+    let correction = Correction::from_nanoseconds(-3);
+    assert_eq!(correction, Correction::from_bits(-3 << 16));
+    assert_eq!(correction.to_bits(), -196_608);
+    assert_eq!(
+        Correction::from_be_bytes(correction.to_be_bytes()),
+        correction
+    );
+    assert_eq!(
+        Correction::from_bits(0x1_8000).to_be_bytes(),
+        [0, 0, 0, 0, 0, 0x01, 0x80, 0x00]
+    );
+
+    // truncated towards zero
+    assert_eq!(Correction::from_bits(0x1_8000).nanoseconds(), 1);
+    assert_eq!(Correction::from_bits(-0x1_8000).nanoseconds(), -1);
+
+    assert_eq!(
+        Correction::from_duration(Microseconds(3_u32)),
+        Ok(Correction::from_nanoseconds(3_000))
+    );
+    assert_eq!(
+        Correction::from_duration(Hours(40_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Correction::from_nanoseconds(3_000).to_duration(),
+        Ok(Microseconds(3_u32))
+    );
+    assert_eq!(
+        correction.to_duration::<Nanoseconds<u32>>(),
+        Err(ConversionError::NegDuration)
+    );
+
+    assert_eq!(
+        correction.checked_add(Correction::from_nanoseconds(5)),
+        Some(Correction::from_nanoseconds(2))
+    );
+    assert_eq!(Correction(i64::MAX).checked_add(Correction(1)), None);
+    assert_eq!(Correction(i64::MIN).checked_sub(Correction(1)), None);
+}
+
+#[test]
+fn timestamp_corrections() {
+    let earlier = PtpTimestamp::new(10, 999_999_000).unwrap();
+    let later = PtpTimestamp::new(11, 500).unwrap();
+
+    assert_eq!(
+        later.correction_since(&earlier),
+        Some(Correction::from_nanoseconds(1_500))
+    );
+    assert_eq!(
+        earlier.correction_since(&later),
+        Some(Correction::from_nanoseconds(-1_500))
+    );
+    assert_eq!(
+        PtpTimestamp::new(40 * 3_600, 0)
+            .unwrap()
+            .correction_since(&PtpTimestamp::default()),
+        None
+    );
+
+    assert_eq!(
+        earlier.checked_add_correction(Correction::from_nanoseconds(1_500)),
+        Some(later)
+    );
+    assert_eq!(
+        later.checked_add_correction(Correction::from_nanoseconds(-1_500)),
+        Some(earlier)
+    );
+    // the sub-nanosecond part is truncated towards the past
+    assert_eq!(
+        later.checked_add_correction(Correction::from_bits(0x8000)),
+        Some(later)
+    );
+    assert_eq!(
+        later.checked_add_correction(Correction::from_bits(-0x8000)),
+        PtpTimestamp::new(11, 499).ok()
+    );
+    assert_eq!(
+        PtpTimestamp::default().checked_add_correction(Correction::from_nanoseconds(-1)),
+        None
+    );
+}
@@ -0,0 +1,137 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    queue::{TimeoutQueue, TimerQueue},
+    Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn ordering() {
+    let mut queue = TimerQueue::<Clock, _, 8>::new();
+    assert!(queue.is_empty());
+    assert_eq!(queue.capacity(), 8);
+    assert_eq!(queue.next_expiration(), None);
+    assert_eq!(queue.pop(), None);
+
+    for (token, expiration) in [50_u32, 20, 80, 10, 70, 30, 60, 40].iter().enumerate() {
+        queue.push(Instant::new(*expiration), token).unwrap();
+    }
+    assert_eq!(queue.push(Instant::new(0), 8), Err(8));
+    assert_eq!(queue.len(), 8);
+
+    assert_eq!(queue.next_expiration(), Some(Instant::new(10)));
+    assert_eq!(queue.pop_expired(Instant::new(9)), None);
+
+    let mut expired = Vec::new();
+    while let Some(token) = queue.pop_expired(Instant::new(45)) {
+        expired.push(token);
+    }
+    assert_eq!(expired, [3, 1, 5, 7]);
+
+    assert_eq!(queue.pop(), Some((Instant::new(50), 0)));
+    assert_eq!(queue.len(), 3);
+
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.next_expiration(), None);
+}
+
+#[test]
+fn across_clock_wrap() {
+    let mut queue = TimerQueue::<Clock, _, 3>::new();
+
+    queue.push(Instant::new(5), 'b').unwrap();
+    queue.push(Instant::new(u32::MAX), 'a').unwrap();
+    queue.push(Instant::new(10), 'c').unwrap();
+
+    assert_eq!(queue.next_expiration(), Some(Instant::new(u32::MAX)));
+    assert_eq!(queue.pop_expired(Instant::new(u32::MAX - 1)), None);
+    assert_eq!(queue.pop_expired(Instant::new(7)), Some('a'));
+    assert_eq!(queue.pop_expired(Instant::new(7)), Some('b'));
+    assert_eq!(queue.pop_expired(Instant::new(7)), None);
+}
+
+#[test]
+fn capacity() {
+    // a `const` queue (eg. in a `static`), not requiring `Copy` tokens
+    const QUEUE: TimerQueue<Clock, String, 1> = TimerQueue::new();
+    let mut queue = QUEUE;
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop(), None);
+
+    queue.push(Instant::new(1), "a".to_string()).unwrap();
+    assert_eq!(
+        queue.push(Instant::new(0), "b".to_string()),
+        Err("b".to_string())
+    );
+    assert_eq!(queue.pop(), Some((Instant::new(1), "a".to_string())));
+
+    let mut queue = TimerQueue::<Clock, (), 0>::default();
+    assert_eq!(queue.capacity(), 0);
+    assert_eq!(queue.push(Instant::new(0), ()), Err(()));
+    assert_eq!(queue.next_expiration(), None);
+}
+
+/// Collects the expired tokens of any queue
+fn expired<Q: TimeoutQueue<Clock, usize>>(queue: &mut Q, now: Instant<Clock>) -> Vec<usize> {
+    let mut expired = Vec::new();
+    while let Some(token) = queue.pop_expired(now) {
+        expired.push(token);
+    }
+    expired
+}
+
+#[test]
+fn timeout_queue() {
+    let mut queue = TimerQueue::<Clock, _, 4>::new();
+
+    for (token, expiration) in [30_u32, 10, 20].iter().enumerate() {
+        TimeoutQueue::push(&mut queue, Instant::new(*expiration), token).unwrap();
+    }
+    assert_eq!(TimeoutQueue::len(&queue), 3);
+    assert_eq!(expired(&mut queue, Instant::new(25)), [1, 2]);
+    assert!(!TimeoutQueue::is_empty(&queue));
+    TimeoutQueue::clear(&mut queue);
+    assert!(TimeoutQueue::is_empty(&queue));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_timer_queue() {
+    use time::queue::VecTimerQueue;
+
+    let mut queue = VecTimerQueue::<Clock, _>::with_capacity(2);
+    assert!(queue.is_empty());
+    assert_eq!(queue.next_expiration(), None);
+
+    // beyond the initial capacity, across a clock wrap
+    for (token, expiration) in [u32::MAX - 10, 5, u32::MAX - 20, 0, u32::MAX]
+        .iter()
+        .enumerate()
+    {
+        queue.push(Instant::new(*expiration), token).unwrap();
+    }
+    assert_eq!(queue.len(), 5);
+    assert_eq!(queue.next_expiration(), Some(Instant::new(u32::MAX - 20)));
+    assert_eq!(expired(&mut queue, Instant::new(u32::MAX)), [2, 0, 4]);
+    assert_eq!(queue.pop(), Some((Instant::new(0), 3)));
+    assert_eq!(expired(&mut queue, Instant::new(5)), [1]);
+    assert_eq!(queue.pop(), None);
+
+    queue.push(Instant::new(1), 0).unwrap();
+    queue.clear();
+    assert!(VecTimerQueue::<Clock, usize>::default().is_empty());
+    assert!(queue.is_empty());
+}
@@ -0,0 +1,78 @@
+use embedded_time::{duration::*, ramp::RateOfChange, rate::*, ConversionError};
+
+#[test]
+fn change_over() {
+    let ramp = RateOfChange(Kilohertz(2_u32));
+
+    assert_eq!(
+        ramp.checked_change_over(Seconds(3_u32)),
+        Some(Kilohertz(6_u32))
+    );
+    assert_eq!(
+        ramp.checked_change_over(Milliseconds(499_u32)),
+        Some(Kilohertz(0_u32))
+    );
+    assert_eq!(
+        RateOfChange(Hertz(1_000_000_u32)).checked_change_over(Milliseconds(10_000_u32)),
+        Some(Hertz(10_000_000_u32))
+    );
+    assert_eq!(
+        RateOfChange(Hertz(u32::MAX)).checked_change_over(Seconds(2_u32)),
+        None
+    );
+}
+
+#[test]
+fn from_change() {
+    assert_eq!(
+        RateOfChange::from_change(Megahertz(4_u64), Microseconds(2_000_u64)),
+        Ok(RateOfChange(Megahertz(2_000_u64)))
+    );
+    assert_eq!(
+        RateOfChange::from_change(Hertz(1_u32), Seconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        RateOfChange::from_change(Hertz(u32::MAX), Nanoseconds(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn duration_of_change() {
+    let ramp = RateOfChange(Hertz(300_u32));
+
+    assert_eq!(
+        ramp.duration_of_change(Hertz(1_000_u32)),
+        Ok(Milliseconds(3_333_u32))
+    );
+    assert_eq!(
+        RateOfChange(Hertz(0_u32)).duration_of_change::<Seconds<u32>>(Hertz(1_u32)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn step() {
+    let ramp = RateOfChange(Hertz(1_000_u32));
+
+    // accelerate and decelerate toward the target
+    assert_eq!(
+        ramp.step(Hertz(0_u32), Hertz(500_u32), Milliseconds(100_u32)),
+        Hertz(100_u32)
+    );
+    assert_eq!(
+        ramp.step(Hertz(500_u32), Hertz(0_u32), Milliseconds(100_u32)),
+        Hertz(400_u32)
+    );
+
+    // without overshooting
+    assert_eq!(
+        ramp.step(Hertz(450_u32), Hertz(500_u32), Milliseconds(100_u32)),
+        Hertz(500_u32)
+    );
+    assert_eq!(
+        ramp.step(Hertz(1_u32), Hertz(0_u32), Hours(u32::MAX)),
+        Hertz(0_u32)
+    );
+}
@@ -38,6 +38,19 @@ fn construction() {
     assert_eq!(5_u32.Bd(), Baud(5_u32));
 }
 
+#[test]
+fn numerical_rate() {
+    fn construct<T: NumericalRate>(value: T) -> Kilohertz<T> {
+        value.khz()
+    }
+
+    assert_eq!(construct(32_u32), Kilohertz(32_u32));
+    assert_eq!(construct(32_u64), Kilohertz(32_u64));
+    assert_eq!(8_u32.mhz(), Megahertz(8_u32));
+    assert_eq!(32_768_u32.hz(), Hertz(32_768_u32));
+    assert_eq!(115_200_u32.bps(), BitsPerSecond(115_200_u32));
+}
+
 #[test]
 fn comparisons() {
     assert_ne!(2_001_u32.Hz(), 2_u32.kHz());
@@ -60,6 +73,24 @@ fn comparisons() {
     assert_ne!(Kilohertz(u64::MAX), Kilohertz(5_u32));
 }
 
+#[test]
+fn ordered_and_hashed_keys() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let rates: BTreeSet<_> = vec![Kilohertz(8_u32), Kilohertz(1_u32), Kilohertz(4_u32)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        rates.into_iter().collect::<Vec<_>>(),
+        [Kilohertz(1_u32), Kilohertz(4_u32), Kilohertz(8_u32)]
+    );
+
+    let mut dividers = HashMap::new();
+    dividers.insert(Baud(9_600_u32), 104);
+    dividers.insert(Baud(115_200_u32), 8);
+    assert_eq!(dividers.get(&Baud(115_200_u32)), Some(&8));
+}
+
 #[test]
 fn add() {
     assert_eq!((Kilohertz(1_u32) + Megahertz(1_u32)), Kilohertz(1_001_u32));
@@ -166,6 +197,14 @@ fn get_generic_integer() {
 fn convert_to_duration() {
     assert_eq!(Hertz(500_u32).to_duration(), Ok(Milliseconds(2_u32)));
     assert_eq!(Kilohertz(500_u32).to_duration(), Ok(Microseconds(2_u32)));
+    assert_eq!(
+        Hertz(1_000_u32).to_duration::<Microseconds<u32>>(),
+        Ok(Microseconds(1_000_u32))
+    );
+    assert_eq!(
+        Megahertz(2_u32).to_duration::<Nanoseconds<u64>>(),
+        Ok(Nanoseconds(500_u64))
+    );
 
     // Errors
     assert_eq!(
@@ -182,6 +221,27 @@ fn convert_to_duration() {
     );
 }
 
+#[test]
+fn mul_duration() {
+    assert_eq!(Milliseconds(10_u32) * Kilohertz(8_u32), 80);
+    assert_eq!(Kilohertz(8_u32) * Milliseconds(10_u32), 80);
+    assert_eq!(Seconds(1_u64) * Mebihertz(3_u64), 3_145_728);
+    assert_eq!(Nanoseconds(999_u32) * Megahertz(1_u32), 0);
+    assert_eq!(Minutes(70_u64) * Megahertz(1_u64), 4_200_000_000);
+
+    // the intermediate product (5,000,000,000 µs·kHz) doesn't fit in a `u32`
+    assert_eq!(
+        Microseconds(1_000_000_u32) * Kilohertz(5_000_u32),
+        5_000_000
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_duration_overflow() {
+    let _ = Minutes(72_u32) * Megahertz(1_u32);
+}
+
 #[test]
 fn frequency_scaling() {
     assert_eq!(1_u32.Hz(), 1_u32.Hz());
@@ -385,3 +445,68 @@ fn into_smaller() {
     test_into_smaller![Baud, Kilobaud, Megabaud];
     test_into_smaller![Baud, Kibibaud, Mebibaud];
 }
+
+#[test]
+fn integer_conversions() {
+    assert_eq!(Hertz::from(5_u32), Hertz(5_u32));
+    assert_eq!(KilobytesPerSecond::from(5_u64), KilobytesPerSecond(5_u64));
+
+    let rate: Megahertz<u64> = 7_u64.into();
+    assert_eq!(rate, Megahertz(7_u64));
+
+    let integer: u32 = Kibihertz(9_u32).into();
+    assert_eq!(integer, 9);
+    assert_eq!(u64::from(BitsPerSecond(u64::MAX)), u64::MAX);
+}
+
+#[test]
+fn u128_integers() {
+    assert_eq!(
+        Hertz::<u128>::try_from(Kilohertz(5_u128)),
+        Ok(Hertz(5_000_u128))
+    );
+    assert_eq!(Hertz::<u128>::from(Hertz(5_u32)), Hertz(5_u128));
+    assert_eq!(Hertz::<u128>::from(Hertz(5_u64)), Hertz(5_u128));
+    assert_eq!(Hertz::<u32>::try_from(Hertz(5_u128)), Ok(Hertz(5_u32)));
+    assert_eq!(
+        Hertz::<u64>::try_from(Hertz(u64::MAX as u128 + 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(u128::from(Hertz(5_u128)), 5);
+    assert_eq!(5_u128.MHz(), Hertz(5_000_000_u128));
+
+    assert_eq!(
+        Megahertz(1_u128).to_duration::<Nanoseconds<u128>>(),
+        Ok(Nanoseconds(1_000_u128))
+    );
+    assert_eq!(
+        Hertz::<u128>::try_from(Megahertz(u128::MAX / 2)),
+        Err(ConversionError::Unspecified)
+    );
+}
+
+#[test]
+fn narrow_integers() {
+    assert_eq!(u16::from(Hertz(5_u16)), 5);
+    assert_eq!(5_u8.kHz(), Kilohertz(5_u8));
+    assert_eq!(Hertz::<u32>::from(Hertz(5_u8)), Hertz(5_u32));
+    assert_eq!(
+        Hertz::<u16>::try_from(Kilohertz(5_u16)),
+        Ok(Hertz(5_000_u16))
+    );
+    assert_eq!(
+        Hertz::<u16>::try_from(Kilohertz(100_u16)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        Hertz::<u8>::try_from(Hertz(300_u16)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(Hertz(250_u8).to_duration(), Ok(Milliseconds(4_u8)));
+    assert_eq!(Kilohertz(4_u8).to_duration(), Ok(Microseconds(250_u8)));
+    assert_eq!(
+        Hertz(0_u16).to_duration::<Milliseconds<u16>>(),
+        Err(ConversionError::DivByZero)
+    );
+}
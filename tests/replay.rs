@@ -0,0 +1,58 @@
+use embedded_time::{self as time, duration::*, replay::*, Clock as _, Instant};
+use std::cell::Cell;
+
+#[derive(Debug, Default)]
+struct Clock {
+    ticks: Cell<u32>,
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.ticks.set(self.ticks.get() + 7);
+        Ok(Instant::new(self.ticks.get()))
+    }
+}
+
+/// Code under test, generic over any clock
+fn elapsed_between_reads<C: time::Clock>(clock: &C) -> Option<Generic<C::T>> {
+    let start = clock.try_now().ok()?;
+    let end = clock.try_now().ok()?;
+    end.checked_duration_since(&start)
+}
+
+#[test]
+fn record_and_replay() {
+    let mut log = Vec::new();
+    let clock = RecordingClock::new(Clock::default(), |ticks| log.push(ticks));
+
+    let recorded = elapsed_between_reads(&clock);
+    assert_eq!(clock.inner().ticks.get(), 14);
+    let (_, _) = clock.into_parts();
+
+    assert_eq!(log, [7, 14]);
+
+    let replay = ReplayClock::<Clock>::new(&log);
+    assert_eq!(replay.remaining(), 2);
+    assert_eq!(elapsed_between_reads(&replay), recorded);
+    assert_eq!(replay.remaining(), 0);
+
+    // exhausted
+    assert_eq!(replay.try_now(), Err(time::clock::Error::NotRunning));
+
+    replay.rewind();
+    assert_eq!(replay.try_now(), Ok(Instant::new(7)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_sink() {
+    let clock = RecordingClock::new(Clock::default(), Vec::new());
+    clock.try_now().unwrap();
+    clock.try_now().unwrap();
+
+    let (_, log) = clock.into_parts();
+    assert_eq!(log, [7, 14]);
+}
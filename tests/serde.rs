@@ -0,0 +1,59 @@
+#![cfg(feature = "serde")]
+
+use embedded_time::{duration, duration::*, rate, rate::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    timeout: Milliseconds<u32>,
+    period: Seconds<u64>,
+    baud_rate: Baud<u32>,
+}
+
+#[test]
+fn raw_integer() {
+    assert_eq!(serde_json::to_string(&Milliseconds(5_u32)).unwrap(), "5");
+    assert_eq!(serde_json::to_string(&Kilohertz(48_u64)).unwrap(), "48");
+    assert_eq!(
+        serde_json::from_str::<Microseconds<u64>>("250").unwrap(),
+        Microseconds(250_u64)
+    );
+    assert!(serde_json::from_str::<Hertz<u32>>("-1").is_err());
+}
+
+#[test]
+fn config_round_trip() {
+    let config = Config {
+        timeout: Milliseconds(500),
+        period: Seconds(3_600),
+        baud_rate: Baud(115_200),
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"timeout":500,"period":3600,"baud_rate":115200}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn generic() {
+    let duration = duration::Generic::new(3_u32, Fraction::new(1, 2_000));
+    let json = serde_json::to_string(&duration).unwrap();
+    assert_eq!(json, r#"{"integer":3,"scaling_factor":[1,2000]}"#);
+    assert_eq!(
+        serde_json::from_str::<duration::Generic<u32>>(&json).unwrap(),
+        duration
+    );
+
+    let rate = rate::Generic::new(32_768_u64, Fraction::new(1, 1));
+    let json = serde_json::to_string(&rate).unwrap();
+    assert_eq!(
+        serde_json::from_str::<rate::Generic<u64>>(&json).unwrap(),
+        rate
+    );
+
+    // reduced on deserialization
+    assert_eq!(
+        serde_json::from_str::<Fraction>("[2,4]").unwrap(),
+        Fraction::new(1, 2)
+    );
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "critical-section")]
+
+use core::cell::Cell;
+use embedded_time::{
+    self as time,
+    duration::*,
+    extended::ExtendedClock,
+    shared::{AtomicInstant, SharedClock},
+    Clock as _, Instant,
+};
+
+#[derive(Debug)]
+struct Timer(Cell<u32>);
+
+impl time::Clock for Timer {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    const COUNTER_BITS: u32 = 16;
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[test]
+fn atomic_instant() {
+    static INSTANT: AtomicInstant<Timer> = AtomicInstant::new();
+
+    assert_eq!(INSTANT.load(), None);
+    INSTANT.store(Instant::new(5));
+    assert_eq!(INSTANT.load(), Some(Instant::new(5)));
+    assert_eq!(INSTANT.swap(Instant::new(7)), Some(Instant::new(5)));
+    assert_eq!(
+        format!("{:?}", INSTANT),
+        "AtomicInstant(Some(Instant(7 ticks × 1/1000 s)))"
+    );
+    assert_eq!(INSTANT.take(), Some(Instant::new(7)));
+    assert_eq!(INSTANT.take(), None);
+}
+
+#[test]
+fn shared_clock() {
+    let clock = SharedClock::new(ExtendedClock::new(Timer(Cell::new(0xFFF0))));
+
+    assert_eq!(
+        <SharedClock<ExtendedClock<Timer>> as time::Clock>::SCALING_FACTOR,
+        Fraction::new(1, 1_000)
+    );
+    assert_eq!(clock.try_now(), Ok(Instant::new(0xFFF0)));
+
+    clock.with(|clock| clock.counter().0.set(0x10));
+    clock.with(|clock| clock.on_rollover()).unwrap();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x1_0010)));
+
+    let clock = clock.into_inner();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0x1_0010)));
+}
+
+#[test]
+fn shared_between_threads() {
+    static CLOCK: SharedClock<ExtendedClock<Timer>> =
+        SharedClock::new(ExtendedClock::new(Timer(Cell::new(0xFFFF))));
+    static LAST: AtomicInstant<SharedClock<ExtendedClock<Timer>>> = AtomicInstant::new();
+
+    assert_eq!(CLOCK.try_now(), Ok(Instant::new(0xFFFF)));
+    crossbeam_utils::thread::scope(|scope| {
+        // the "overflow interrupt"
+        scope.spawn(|_| {
+            CLOCK.with(|clock| clock.counter().0.set(0x2));
+            CLOCK.with(|clock| clock.on_rollover()).unwrap();
+            LAST.store(CLOCK.try_now().unwrap());
+        });
+    })
+    .unwrap();
+
+    assert_eq!(LAST.load(), Some(Instant::new(0x1_0002)));
+    assert_eq!(CLOCK.try_now(), Ok(Instant::new(0x1_0002)));
+}
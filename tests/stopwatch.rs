@@ -0,0 +1,75 @@
+use core::convert::TryFrom;
+use embedded_time::{self as time, duration::*, stopwatch::Stopwatch, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(u32::MAX - 1))
+    }
+}
+
+fn ms(duration: Generic<u32>) -> Milliseconds<u32> {
+    Milliseconds::try_from(duration).unwrap()
+}
+
+#[test]
+fn laps() {
+    let mut stopwatch = Stopwatch::start(&Clock).unwrap();
+
+    // across a clock wrap
+    assert_eq!(ms(stopwatch.elapsed(Instant::new(8))), Milliseconds(10_u32));
+    assert_eq!(ms(stopwatch.lap(Instant::new(3))), Milliseconds(5_u32));
+    assert_eq!(ms(stopwatch.lap(Instant::new(10))), Milliseconds(7_u32));
+    assert_eq!(ms(stopwatch.lap(Instant::new(10))), Milliseconds(0_u32));
+
+    // before the start
+    assert_eq!(
+        ms(stopwatch.elapsed(Instant::new(u32::MAX - 5))),
+        Milliseconds(0_u32)
+    );
+
+    stopwatch.restart(Instant::new(100));
+    assert_eq!(ms(stopwatch.lap(Instant::new(120))), Milliseconds(20_u32));
+    assert_eq!(
+        ms(stopwatch.elapsed(Instant::new(130))),
+        Milliseconds(30_u32)
+    );
+}
+
+#[test]
+fn pause_resume() {
+    let mut stopwatch = Stopwatch::<Clock>::new(Instant::new(0));
+    assert!(!stopwatch.is_paused());
+
+    stopwatch.pause(Instant::new(10));
+    assert!(stopwatch.is_paused());
+    assert_eq!(
+        ms(stopwatch.elapsed(Instant::new(50))),
+        Milliseconds(10_u32)
+    );
+
+    // already paused
+    stopwatch.pause(Instant::new(40));
+    stopwatch.resume(Instant::new(60));
+    assert!(!stopwatch.is_paused());
+    assert_eq!(
+        ms(stopwatch.elapsed(Instant::new(70))),
+        Milliseconds(20_u32)
+    );
+    assert_eq!(ms(stopwatch.lap(Instant::new(70))), Milliseconds(20_u32));
+
+    // not paused
+    stopwatch.resume(Instant::new(100));
+    stopwatch.pause(Instant::new(100));
+    assert_eq!(ms(stopwatch.lap(Instant::new(200))), Milliseconds(30_u32));
+    stopwatch.resume(Instant::new(200));
+    assert_eq!(
+        ms(stopwatch.elapsed(Instant::new(205))),
+        Milliseconds(55_u32)
+    );
+}
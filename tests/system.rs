@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+use core::convert::TryFrom;
+use embedded_time::{duration::*, system::SystemClock, Clock as _, Instant};
+use std::{thread, time};
+
+#[test]
+fn counts_from_epoch() {
+    let clock = SystemClock::new();
+    let start = clock.try_now().unwrap();
+
+    thread::sleep(time::Duration::from_millis(5));
+
+    let elapsed = clock
+        .try_now()
+        .unwrap()
+        .checked_duration_since(&start)
+        .unwrap();
+    assert!(Milliseconds::<u64>::try_from(elapsed).unwrap() >= Milliseconds(5_u64));
+}
+
+#[test]
+fn future_epoch() {
+    let clock = SystemClock::with_epoch(time::Instant::now() + time::Duration::from_secs(60));
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+}
+
+#[test]
+fn timers() {
+    let clock = SystemClock::default();
+    let timer = clock.new_timer(Milliseconds(2_u32)).start().unwrap();
+
+    timer.wait().unwrap();
+    assert!(clock.epoch().elapsed() >= time::Duration::from_millis(2));
+}
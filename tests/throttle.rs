@@ -0,0 +1,75 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time, duration::*, rate::*, throttle::Throttle, ConversionError, Instant,
+};
+
+/// Advances by 1 ms per reading
+#[derive(Debug, Default)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let ticks = self.0.get();
+        self.0.set(ticks.wrapping_add(1));
+        Ok(Instant::new(ticks))
+    }
+}
+
+#[test]
+fn token_bucket() {
+    let mut throttle =
+        Throttle::<Clock>::new(Instant::new(u32::MAX - 500), Hertz(4_u32), 3).unwrap();
+
+    assert_eq!(throttle.available(Instant::new(u32::MAX - 500)), 3);
+    for _ in 0..3 {
+        assert!(throttle.ready(Instant::new(u32::MAX - 500)));
+    }
+    assert!(!throttle.ready(Instant::new(u32::MAX - 300)));
+
+    // across a clock wrap, a token every 250 ms
+    assert!(throttle.ready(Instant::new(u32::MAX - 250)));
+    assert!(!throttle.ready(Instant::new(u32::MAX - 1)));
+    assert_eq!(throttle.available(Instant::new(249)), 2);
+    assert!(throttle.ready(Instant::new(249)));
+    assert!(throttle.ready(Instant::new(249)));
+    assert_eq!(throttle.available(Instant::new(499)), 1);
+
+    // capped at the burst size
+    assert_eq!(throttle.available(Instant::new(100_000)), 3);
+}
+
+#[test]
+fn wait() {
+    let clock = Clock::default();
+    let mut throttle = Throttle::start(&clock, Hertz(100_u32), 1).unwrap();
+
+    throttle.wait(&clock).unwrap();
+    assert_eq!(clock.0.get(), 2);
+
+    // tokens accrue once taken from the full bucket
+    throttle.wait(&clock).unwrap();
+    assert_eq!(clock.0.get(), 12);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Throttle::<Clock>::new(Instant::new(0), Hertz(0_u32), 1).err(),
+        Some(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Throttle::<Clock>::new(Instant::new(0), Kilohertz(2_u32), 1).err(),
+        Some(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Throttle::<Clock>::new(Instant::new(0), Megahertz(u128::MAX), 1).err(),
+        Some(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Throttle::<Clock>::new(Instant::new(0), Hertz(1_u32), 1).map(|_| ()),
+        Ok(())
+    );
+}
@@ -0,0 +1,95 @@
+use core::{
+    cell::Cell,
+    convert::TryFrom,
+    sync::atomic::{AtomicU32, Ordering},
+};
+use embedded_time::{duration::*, tick::TickClock, Clock, Instant};
+
+#[test]
+fn reads_the_counter() {
+    let counter = Cell::new(0_u32);
+    let clock = TickClock::<1_000, _>::new(|| counter.get());
+    let start = clock.try_now().unwrap();
+
+    counter.set(1_500);
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_500)));
+    assert_eq!(
+        Milliseconds::<u32>::try_from(clock.try_now().unwrap() - start),
+        Ok(Milliseconds(1_500))
+    );
+}
+
+#[test]
+fn frequency_and_width() {
+    type Rtc<F> = TickClock<32_768, F>;
+    type SysTick<F> = TickClock<16_000_000, F, 24>;
+
+    assert_eq!(Rtc::<fn() -> u32>::SCALING_FACTOR, Fraction::new(1, 32_768));
+    assert_eq!(Rtc::<fn() -> u32>::COUNTER_BITS, 32);
+    assert_eq!(
+        SysTick::<fn() -> u32>::SCALING_FACTOR,
+        Fraction::new(1, 16_000_000)
+    );
+    assert_eq!(SysTick::<fn() -> u32>::COUNTER_BITS, 24);
+
+    // a 24-bit counter wraps at 2²⁴
+    let earlier = Instant::<SysTick<fn() -> u32>>::new(0xFF_FFF0);
+    let later = Instant::<SysTick<fn() -> u32>>::new(0x10);
+    assert!(later > earlier);
+}
+
+#[test]
+fn stateful_read() {
+    // eg. a driver owning its counter
+    let mut counter = 0_u32;
+    let clock = TickClock::<1_000, _>::new(move || {
+        counter += 5;
+        counter
+    });
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(5)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(10)));
+}
+
+#[test]
+fn narrow_counter_wrap() {
+    let counter = Cell::new(0xFFF0_u32);
+    let clock = TickClock::<1_000, _, 16>::new(|| counter.get());
+    let start = clock.try_now().unwrap();
+    let timer = clock.new_timer(Milliseconds(0x20_u32)).start().unwrap();
+
+    // across the 16-bit rollover
+    counter.set(0x0F);
+    let now = clock.try_now().unwrap();
+    assert!(now > start);
+    assert_eq!(
+        Milliseconds::<u32>::try_from(now.checked_duration_since(&start).unwrap()),
+        Ok(Milliseconds(0x1F))
+    );
+    assert_eq!(now.checked_duration_since(&start), Some(now - start));
+    assert_eq!(timer.is_expired(), Ok(false));
+    assert_eq!(timer.remaining(), Ok(Milliseconds(1_u32)));
+
+    counter.set(0x10);
+    assert_eq!(timer.is_expired(), Ok(true));
+    assert_eq!(timer.elapsed(), Ok(Milliseconds(0x20_u32)));
+}
+
+#[test]
+fn timers() {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let clock = TickClock::<1_000, _>::new(|| COUNTER.load(Ordering::Relaxed));
+
+    let timer = clock.new_timer(Milliseconds(10_u32)).start().unwrap();
+    COUNTER.store(9, Ordering::Relaxed);
+    assert_eq!(timer.is_expired(), Ok(false));
+    COUNTER.store(10, Ordering::Relaxed);
+    assert_eq!(timer.is_expired(), Ok(true));
+}
+
+#[test]
+fn debug() {
+    let clock = TickClock::<32_768, _>::new(|| 0);
+    assert_eq!(format!("{:?}", clock), "TickClock { hz: 32768, bits: 32 }");
+    assert_eq!((clock.into_inner())(), 0);
+}
@@ -1,6 +1,6 @@
 use crossbeam_utils::thread;
 use embedded_time::{
-    self as time, duration::*, fixed_point, fraction::Fraction, Clock as _, Instant,
+    self as time, duration::*, fixed_point, fraction::Fraction, CatchUp, Clock as _, Instant,
 };
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -95,6 +95,49 @@ fn periodic_expiration() {
     assert!(timer.period_complete().unwrap());
 }
 
+static DRIFT_TICKS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+struct DriftClock;
+impl time::Clock for DriftClock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(DRIFT_TICKS.load(Ordering::SeqCst)))
+    }
+}
+
+#[test]
+fn periodic_no_drift() {
+    let clock = DriftClock;
+
+    let mut timer = clock
+        .new_timer(10_u32.milliseconds())
+        .into_periodic()
+        .start()
+        .unwrap();
+
+    // polled every 7 ms for 10 s, so each expiration is observed up to 6 ms late
+    let mut periods = 0;
+    for _ in 0..(10_000 / 7) {
+        DRIFT_TICKS.fetch_add(7, Ordering::SeqCst);
+        if timer.period_complete().unwrap() {
+            periods += 1;
+        }
+    }
+    assert_eq!(periods, 999);
+
+    // each period is scheduled from the previous expiration, not from when it was observed
+    DRIFT_TICKS.fetch_add(7, Ordering::SeqCst);
+    assert!(timer.period_complete().unwrap());
+    assert_eq!(timer.remaining(), Ok(7_u32.milliseconds()));
+
+    DRIFT_TICKS.fetch_add(7, Ordering::SeqCst);
+    let timer = timer.wait().unwrap();
+    assert_eq!(timer.remaining(), Ok(10_u32.milliseconds()));
+}
+
 #[test]
 fn read_timer() {
     init_ticks();
@@ -138,3 +181,238 @@ where
             .integer();
     TICKS.store(ticks, Ordering::SeqCst);
 }
+
+static BUILDER_TICKS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+struct BuilderClock;
+impl time::Clock for BuilderClock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(BUILDER_TICKS.load(Ordering::SeqCst)))
+    }
+}
+
+#[test]
+fn builder_tolerance() {
+    let clock = BuilderClock;
+
+    let oneshot = clock
+        .timer_builder()
+        .oneshot(1_u32.seconds())
+        .start()
+        .unwrap();
+    let mut periodic = clock
+        .timer_builder()
+        .periodic(1_000_u32.milliseconds())
+        .tolerance(100_u32.milliseconds())
+        .start()
+        .unwrap();
+
+    BUILDER_TICKS.fetch_add(899, Ordering::SeqCst);
+    assert!(!periodic.period_complete().unwrap());
+
+    // within the tolerance of the expiration
+    BUILDER_TICKS.fetch_add(1, Ordering::SeqCst);
+    assert!(periodic.period_complete().unwrap());
+    assert!(!oneshot.is_expired().unwrap());
+
+    // the next period is still scheduled from the actual expiration
+    BUILDER_TICKS.fetch_add(1_000, Ordering::SeqCst);
+    assert!(periodic.period_complete().unwrap());
+    assert!(oneshot.is_expired().unwrap());
+
+    // a tolerance of (at least) the period would complete every poll
+    for tolerance in [1_000_u32, 5_000].iter() {
+        assert_eq!(
+            clock
+                .timer_builder()
+                .periodic(1_000_u32.milliseconds())
+                .tolerance(Milliseconds(*tolerance))
+                .build()
+                .map(|_| ()),
+            Err(time::TimeError::Overflow)
+        );
+    }
+    assert!(clock
+        .timer_builder()
+        .oneshot(0_u32.milliseconds())
+        .tolerance(0_u32.milliseconds())
+        .build()
+        .is_ok());
+}
+
+static RESTART_TICKS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+struct RestartClock;
+impl time::Clock for RestartClock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(RESTART_TICKS.load(Ordering::SeqCst)))
+    }
+}
+
+#[test]
+fn restart() {
+    let clock = RestartClock;
+
+    let mut oneshot = clock.new_timer(1_u32.seconds()).start().unwrap();
+    let mut periodic = clock
+        .new_timer(1_u32.seconds())
+        .into_periodic()
+        .start()
+        .unwrap();
+
+    RESTART_TICKS.fetch_add(600, Ordering::SeqCst);
+    oneshot.restart().unwrap();
+    periodic.restart().unwrap();
+    assert_eq!(oneshot.elapsed(), Ok(0_u32.seconds()));
+
+    RESTART_TICKS.fetch_add(600, Ordering::SeqCst);
+    assert!(!oneshot.is_expired().unwrap());
+    assert!(!periodic.period_complete().unwrap());
+
+    RESTART_TICKS.fetch_add(400, Ordering::SeqCst);
+    assert!(oneshot.is_expired().unwrap());
+    assert!(periodic.period_complete().unwrap());
+}
+
+static CATCH_UP_TICKS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+struct CatchUpClock;
+impl time::Clock for CatchUpClock {
+    type T = u64;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(CATCH_UP_TICKS.load(Ordering::SeqCst)))
+    }
+}
+
+#[test]
+fn builder_catch_up() {
+    let clock = CatchUpClock;
+
+    let mut burst = clock
+        .timer_builder()
+        .periodic(100_u32.milliseconds())
+        .start()
+        .unwrap();
+    let mut skip = clock
+        .timer_builder()
+        .periodic(100_u32.milliseconds())
+        .catch_up(CatchUp::Skip)
+        .start()
+        .unwrap();
+
+    // 3½ periods late
+    CATCH_UP_TICKS.store(350, Ordering::SeqCst);
+    assert!(burst.period_complete().unwrap());
+    assert!(burst.period_complete().unwrap());
+    assert!(burst.period_complete().unwrap());
+    assert!(!burst.period_complete().unwrap());
+
+    assert!(skip.period_complete().unwrap());
+    assert!(!skip.period_complete().unwrap());
+    assert_eq!(skip.remaining(), Ok(50_u32.milliseconds()));
+
+    // still on the original period boundaries
+    CATCH_UP_TICKS.store(400, Ordering::SeqCst);
+    assert!(burst.period_complete().unwrap());
+    let skip = skip.wait().unwrap();
+    assert_eq!(skip.remaining(), Ok(100_u32.milliseconds()));
+}
+
+#[test]
+fn debug_format() {
+    struct NoDebugClock;
+    impl time::Clock for NoDebugClock {
+        type T = u32;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            Ok(Instant::new(5))
+        }
+    }
+
+    let timer = NoDebugClock.new_timer(2_u32.seconds()).into_periodic();
+    assert_eq!(
+        format!("{:?}", timer),
+        "Timer { type: Periodic, state: Armed, duration: Seconds(2), expiration: Instant(0 ticks × \
+         1/1000 s), slack_ticks: 0, catch_up: Burst }"
+    );
+
+    let timer = timer.start().unwrap();
+    assert_eq!(
+        format!("{:?}", timer),
+        "Timer { type: Periodic, state: Running, duration: Seconds(2), expiration: Instant(2005 \
+         ticks × 1/1000 s), slack_ticks: 0, catch_up: Burst }"
+    );
+}
+
+/// Advances by 1 ms on every reading
+#[derive(Debug, Default)]
+struct SteppingClock(core::cell::Cell<u32>);
+impl time::Clock for SteppingClock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let now = self.0.get();
+        self.0.set(now + 1);
+        Ok(Instant::new(now))
+    }
+}
+
+#[test]
+fn timeout() {
+    use time::clock::TimeoutError;
+
+    let clock = SteppingClock::default();
+    let mut polls = 0;
+    assert_eq!(
+        clock.timeout(10_u32.milliseconds(), || {
+            polls += 1;
+            if polls < 5 {
+                Err(nb::Error::<()>::WouldBlock)
+            } else {
+                Ok(polls)
+            }
+        }),
+        Ok(5)
+    );
+
+    assert_eq!(
+        clock.timeout(10_u32.milliseconds(), || Err(nb::Error::Other("fault"))),
+        Err::<(), _>(TimeoutError::Operation("fault"))
+    );
+
+    let mut polls = 0;
+    assert_eq!(
+        clock.timeout(10_u32.milliseconds(), || {
+            polls += 1;
+            Err::<(), _>(nb::Error::<()>::WouldBlock)
+        }),
+        Err(TimeoutError::TimedOut)
+    );
+    assert_eq!(polls, 10);
+
+    // polled once with a zero timeout
+    assert_eq!(
+        clock.timeout(0_u32.milliseconds(), || Ok::<_, nb::Error<()>>(1)),
+        Ok(1)
+    );
+    // whatever the operation's error type
+    #[derive(Debug, PartialEq)]
+    struct NoDefault;
+    assert_eq!(
+        TimeoutError::<NoDefault>::default(),
+        TimeoutError::Unspecified
+    );
+}
@@ -0,0 +1,51 @@
+use core::convert::TryFrom;
+use embedded_time::{self as time, duration::*, timeslice::Timeslice, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(u32::MAX - 1))
+    }
+}
+
+#[test]
+fn budget() {
+    let mut slice = Timeslice::start(&Clock, Milliseconds(10_u32)).unwrap();
+
+    // across a clock wrap
+    assert!(!slice.should_yield(Instant::new(7)));
+    assert_eq!(
+        Milliseconds::<u32>::try_from(slice.remaining(Instant::new(7)).unwrap()),
+        Ok(Milliseconds(1_u32))
+    );
+    assert!(slice.should_yield(Instant::new(8)));
+    assert_eq!(slice.remaining(Instant::new(8)), None);
+    assert_eq!(slice.overrun(Instant::new(8)), None);
+    assert_eq!(
+        Milliseconds::<u32>::try_from(slice.overrun(Instant::new(11)).unwrap()),
+        Ok(Milliseconds(3_u32))
+    );
+
+    // before the start
+    assert!(!slice.should_yield(Instant::new(u32::MAX - 5)));
+
+    slice.restart(Instant::new(100));
+    assert!(!slice.should_yield(Instant::new(109)));
+    assert_eq!(
+        Milliseconds::<u32>::try_from(slice.elapsed(Instant::new(109))),
+        Ok(Milliseconds(9_u32))
+    );
+}
+
+#[test]
+fn budget_beyond_comparison_window() {
+    assert_eq!(
+        Timeslice::new(Instant::<Clock>::new(0), Hours(1_000_u32)).unwrap_err(),
+        ConversionError::Overflow
+    );
+}
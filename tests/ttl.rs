@@ -0,0 +1,51 @@
+use embedded_time::{self as time, duration::*, ttl::Ttl, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn expiration() {
+    let mut entry = Ttl::new(5_u8, Instant::<Clock>::new(1_000), Seconds(2_u32)).unwrap();
+
+    assert_eq!(entry.get(Instant::new(1_000)), Some(&5));
+    assert_eq!(entry.get(Instant::new(2_999)), Some(&5));
+    assert_eq!(entry.get(Instant::new(3_000)), None);
+    // stored in the future
+    assert_eq!(entry.get(Instant::new(999)), None);
+
+    if let Some(value) = entry.get_mut(Instant::new(1_500)) {
+        *value += 1;
+    }
+    assert_eq!(entry.get(Instant::new(1_500)), Some(&6));
+
+    entry.refresh(7, Instant::new(4_000));
+    assert_eq!(entry.stored_at(), Instant::new(4_000));
+    assert_eq!(entry.get(Instant::new(5_999)), Some(&7));
+    assert!(entry.is_expired(Instant::new(6_000)));
+    assert_eq!(entry.into_inner(), 7);
+}
+
+#[test]
+fn clock_wrap() {
+    let entry = Ttl::new((), Instant::<Clock>::new(u32::MAX - 499), Seconds(1_u32)).unwrap();
+
+    assert_eq!(entry.get(Instant::new(499)), Some(&()));
+    assert_eq!(entry.get(Instant::new(500)), None);
+}
+
+#[test]
+fn ttl_beyond_comparison_window() {
+    assert_eq!(
+        Ttl::new((), Instant::<Clock>::new(0), Hours(1_000_u32)).unwrap_err(),
+        ConversionError::Overflow
+    );
+}
@@ -0,0 +1,278 @@
+#![cfg(feature = "wallclock")]
+
+use embedded_time::{duration::*, wallclock::Timestamp, ConversionError};
+
+#[test]
+fn construction() {
+    assert_eq!(Timestamp::default(), Timestamp::UNIX_EPOCH);
+    assert_eq!(
+        Timestamp::new(5, 1_000_000_000),
+        Err(ConversionError::Overflow)
+    );
+
+    let timestamp = Timestamp::new(5, 250_000_000).unwrap();
+    assert_eq!(timestamp.unix_seconds(), 5);
+    assert_eq!(timestamp.subsec_nanoseconds(), 250_000_000);
+
+    assert_eq!(
+        Timestamp::from_duration(Microseconds(5_250_000_u32)),
+        Ok(timestamp)
+    );
+    assert_eq!(
+        Timestamp::from_duration(Hours(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn durations() {
+    let timestamp = Timestamp::new(5, 250_000_000).unwrap();
+
+    assert_eq!(timestamp.duration_since_epoch(), Ok(Seconds(5_u32)));
+    assert_eq!(
+        timestamp.duration_since_epoch(),
+        Ok(Milliseconds(5_250_u32))
+    );
+    assert_eq!(
+        Timestamp::from_unix_seconds(u64::MAX).duration_since_epoch::<Milliseconds<u32>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        timestamp.checked_add(Milliseconds(750_u32)),
+        Some(Timestamp::from_unix_seconds(6))
+    );
+    assert_eq!(
+        timestamp.checked_sub(Milliseconds(250_u32)),
+        Some(Timestamp::from_unix_seconds(5))
+    );
+    assert_eq!(timestamp.checked_sub(Seconds(6_u32)), None);
+    assert_eq!(
+        Timestamp::from_unix_seconds(u64::MAX).checked_add(Seconds(1_u32)),
+        None
+    );
+
+    assert_eq!(
+        timestamp.checked_duration_since(&Timestamp::from_unix_seconds(2)),
+        Some(Milliseconds(3_250_u32))
+    );
+    assert_eq!(
+        Timestamp::UNIX_EPOCH.checked_duration_since::<Seconds<u32>>(&timestamp),
+        None
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use core::convert::TryFrom;
+
+    let date_time = NaiveDate::from_ymd_opt(2020, 9, 13)
+        .unwrap()
+        .and_hms_milli_opt(12, 26, 40, 500)
+        .unwrap();
+    let timestamp = Timestamp::new(1_600_000_000, 500_000_000).unwrap();
+
+    assert_eq!(Timestamp::try_from(date_time), Ok(timestamp));
+    assert_eq!(NaiveDateTime::try_from(timestamp), Ok(date_time));
+
+    // leap second
+    let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap();
+    assert_eq!(
+        Timestamp::try_from(leap_second),
+        Ok(Timestamp::new(1_483_228_799, 999_999_999).unwrap())
+    );
+
+    let before_epoch = NaiveDate::from_ymd_opt(1969, 12, 31)
+        .unwrap()
+        .and_hms_opt(23, 59, 59)
+        .unwrap();
+    assert_eq!(
+        Timestamp::try_from(before_epoch),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        NaiveDateTime::try_from(Timestamp::from_unix_seconds(u64::MAX)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn calendar() {
+    use embedded_time::wallclock::{days_in_month, is_leap_year, DateTime};
+
+    let date_time = |year, month, day, hour, minute, second| DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    };
+
+    assert_eq!(
+        DateTime::from_timestamp(Timestamp::UNIX_EPOCH),
+        Ok(date_time(1970, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(date_time(1970, 1, 1, 0, 0, 0).weekday(), 4);
+
+    // leap days
+    for &(year, month, day, seconds) in &[
+        (2000, 2, 29, 951_782_400),
+        (2000, 3, 1, 951_868_800),
+        (2100, 3, 1, 4_107_542_400),
+        (2024, 12, 31, 1_735_603_200),
+    ] {
+        let timestamp = Timestamp::from_unix_seconds(seconds + 3_723);
+        assert_eq!(
+            DateTime::from_timestamp(timestamp),
+            Ok(date_time(year, month, day, 1, 2, 3))
+        );
+        assert_eq!(
+            date_time(year, month, day, 1, 2, 3).to_timestamp(),
+            Ok(timestamp)
+        );
+    }
+
+    // every day round-trips
+    let mut previous = Timestamp::UNIX_EPOCH;
+    for day in 1..200_000_u64 {
+        let timestamp = Timestamp::from_unix_seconds(day * 86_400 + 86_399);
+        let broken_down = DateTime::from_timestamp(timestamp).unwrap();
+        assert_eq!(broken_down.to_timestamp(), Ok(timestamp));
+        assert!(timestamp > previous);
+        previous = timestamp;
+    }
+
+    assert_eq!(date_time(2024, 7, 14, 0, 0, 0).weekday(), 7);
+    assert_eq!(date_time(2024, 7, 15, 0, 0, 0).weekday(), 1);
+
+    assert_eq!(
+        date_time(2023, 2, 29, 0, 0, 0).to_timestamp(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        date_time(2023, 13, 1, 0, 0, 0).to_timestamp(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        date_time(2023, 1, 1, 24, 0, 0).to_timestamp(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        date_time(1969, 12, 31, 23, 59, 59).to_timestamp(),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert!(is_leap_year(2000));
+    assert!(is_leap_year(2024));
+    assert!(!is_leap_year(1900));
+    assert!(!is_leap_year(2023));
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 2), 28);
+    assert_eq!(days_in_month(2023, 4), 30);
+    assert_eq!(days_in_month(2023, 0), 0);
+}
+
+#[test]
+fn tai() {
+    use embedded_time::wallclock::{LeapSeconds, TaiTimestamp};
+
+    let utc = Timestamp::new(1_483_228_800, 500_000_000).unwrap();
+    let tai = utc.to_tai(LeapSeconds(37)).unwrap();
+    assert_eq!(
+        tai,
+        TaiTimestamp(Timestamp::new(1_483_228_837, 500_000_000).unwrap())
+    );
+    assert_eq!(tai.to_utc(LeapSeconds(37)), Some(utc));
+    assert_eq!(tai.to_utc(LeapSeconds(36)), utc.checked_add(Seconds(1_u32)));
+
+    assert_eq!(
+        TaiTimestamp(Timestamp::from_unix_seconds(5)).to_utc(LeapSeconds(10)),
+        None
+    );
+    assert_eq!(
+        Timestamp::from_unix_seconds(u64::MAX).to_tai(LeapSeconds(1)),
+        None
+    );
+    assert_eq!(
+        Timestamp::UNIX_EPOCH.to_tai(LeapSeconds::default()),
+        Some(TaiTimestamp::default())
+    );
+
+    assert_eq!(
+        tai.checked_duration_since(&TaiTimestamp(Timestamp::from_unix_seconds(1_483_228_836))),
+        Some(Milliseconds(1_500_u32))
+    );
+}
+
+#[test]
+fn gps_time() {
+    use embedded_time::wallclock::{GpsTime, LeapSeconds, TaiTimestamp};
+
+    let gps_time = |week, tow| GpsTime {
+        week,
+        tow: Milliseconds(tow),
+    };
+
+    // the GPS epoch
+    let epoch = Timestamp::from_unix_seconds(315_964_800);
+    assert_eq!(
+        GpsTime::from_utc(epoch, LeapSeconds(19)),
+        Ok(gps_time(0, 0))
+    );
+    assert_eq!(gps_time(0, 0).to_utc(LeapSeconds(19)), Ok(epoch));
+    assert_eq!(
+        GpsTime::from_utc(Timestamp::from_unix_seconds(315_964_799), LeapSeconds(19)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    let tai = TaiTimestamp(Timestamp::new(315_964_819 + 604_800 * 2 + 1, 999_999).unwrap());
+    assert_eq!(GpsTime::from_tai(tai), Ok(gps_time(2, 1_000)));
+    assert_eq!(
+        gps_time(2, 1_000).to_tai(),
+        Ok(TaiTimestamp(Timestamp::from_unix_seconds(
+            315_964_819 + 604_800 * 2 + 1
+        )))
+    );
+    assert_eq!(
+        gps_time(2, 604_800_000).to_tai(),
+        Err(ConversionError::Overflow)
+    );
+
+    // rollover
+    let pivot = Timestamp::from_unix_seconds(1_600_000_000); // week 2,123
+    assert_eq!(
+        GpsTime::from_truncated_week(75, 10, Milliseconds(0_u32), pivot),
+        Ok(gps_time(2_123, 0))
+    );
+    assert_eq!(
+        GpsTime::from_truncated_week(74, 10, Milliseconds(0_u32), pivot),
+        Ok(gps_time(3_146, 0))
+    );
+    assert_eq!(
+        GpsTime::from_truncated_week(1_023, 10, Milliseconds(0_u32), pivot),
+        Ok(gps_time(3_071, 0))
+    );
+    assert_eq!(
+        GpsTime::from_truncated_week(2_200, 13, Milliseconds(0_u32), pivot),
+        Ok(gps_time(2_200, 0))
+    );
+    assert_eq!(
+        GpsTime::from_truncated_week(5, 10, Milliseconds(0_u32), Timestamp::UNIX_EPOCH),
+        Ok(gps_time(5, 0))
+    );
+    assert_eq!(
+        GpsTime::from_truncated_week(5_000, 32, Milliseconds(0_u32), pivot),
+        Ok(gps_time(5_000, 0))
+    );
+    // the first match after the pivot is beyond `u32` weeks
+    assert_eq!(
+        GpsTime::from_truncated_week(0, 32, Milliseconds(0_u32), pivot),
+        Err(ConversionError::Overflow)
+    );
+}
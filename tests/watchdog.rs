@@ -0,0 +1,143 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time, accuracy::Ppm, duration::*, watchdog::WatchdogScheduler, ConversionError, Instant,
+};
+
+/// Advances by 1 ms per reading
+#[derive(Debug, Default)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let ticks = self.0.get();
+        self.0.set(ticks.wrapping_add(1));
+        Ok(Instant::new(ticks))
+    }
+}
+
+#[test]
+fn feeds_before_timeout() {
+    let feeds = Cell::new(0);
+    let mut watchdog = WatchdogScheduler::<Clock, _>::new(
+        Instant::new(u32::MAX - 10),
+        Microseconds(20_999_u32),
+        Ppm(0),
+        || feeds.set(feeds.get() + 1),
+    )
+    .unwrap();
+
+    // 20.999 ms rounds down to 20 ticks, less the reading resolution
+    assert_eq!(
+        watchdog.interval(),
+        Generic::new(19, Fraction::new(1, 1_000))
+    );
+    assert_eq!(feeds.get(), 1);
+
+    // across a clock wrap
+    assert!(!watchdog.poll(Instant::new(u32::MAX)));
+    assert!(!watchdog.poll(Instant::new(7)));
+    assert!(watchdog.poll(Instant::new(8)));
+    assert_eq!(watchdog.last_fed(), Instant::new(8));
+    assert_eq!(watchdog.next_feed(), Instant::new(27));
+
+    // a late poll still feeds, restarting the interval
+    assert!(watchdog.poll(Instant::new(30)));
+    assert_eq!(watchdog.next_feed(), Instant::new(49));
+
+    watchdog.feed(Instant::new(35));
+    assert_eq!(watchdog.next_feed(), Instant::new(54));
+    assert_eq!(
+        format!("{:?}", watchdog),
+        "WatchdogScheduler { interval: Generic { integer: 19, scaling_factor: Fraction(1/1000) }, last_fed: Instant(35 ticks × 1/1000 s) }"
+    );
+    assert_eq!(feeds.get(), 4);
+
+    let mut feed = watchdog.into_inner();
+    feed();
+    assert_eq!(feeds.get(), 5);
+}
+
+#[test]
+fn tolerance() {
+    let interval = |tolerance| {
+        WatchdogScheduler::<Clock, _>::new(Instant::new(0), Seconds(1_u32), tolerance, || {})
+            .map(|watchdog| watchdog.interval())
+    };
+
+    assert_eq!(
+        interval(Ppm(0)),
+        Ok(Generic::new(999, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        interval(Ppm(1_000)),
+        Ok(Generic::new(998, Fraction::new(1, 1_000)))
+    );
+    // rounded down
+    assert_eq!(
+        interval(Ppm(1_500)),
+        Ok(Generic::new(997, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        interval(Ppm(20).combined(Ppm::from_percent(5))),
+        Ok(Generic::new(948, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        interval(Ppm::from_percent(100)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn start() {
+    let clock = Clock::default();
+    let feeds = Cell::new(0);
+    let mut watchdog = WatchdogScheduler::start(&clock, Milliseconds(5_u32), Ppm(0), || {
+        feeds.set(feeds.get() + 1)
+    })
+    .unwrap();
+
+    while !watchdog.poll(time::Clock::try_now(&clock).unwrap()) {}
+    assert_eq!(watchdog.last_fed(), Instant::new(4));
+    assert_eq!(feeds.get(), 2);
+}
+
+#[test]
+fn errors() {
+    let new = |timeout| {
+        WatchdogScheduler::<Clock, _>::new(Instant::new(0), timeout, Ppm(0), || {}).map(|_| ())
+    };
+
+    // shorter than two ticks
+    assert_eq!(new(Microseconds(1_999_u32)), Err(ConversionError::Overflow));
+    assert_eq!(new(Microseconds(2_000_u32)), Ok(()));
+    // longer than the valid comparison window
+    assert_eq!(
+        WatchdogScheduler::<Clock, _>::new(Instant::new(0), Milliseconds(u32::MAX), Ppm(0), || {})
+            .map(|_| ()),
+        Err(ConversionError::Overflow)
+    );
+
+    #[derive(Debug)]
+    struct WideClock;
+    impl time::Clock for WideClock {
+        type T = u128;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            Ok(Instant::new(0))
+        }
+    }
+    assert_eq!(
+        WatchdogScheduler::<WideClock, _>::new(
+            Instant::new(0),
+            Milliseconds(u128::MAX / 2),
+            Ppm(0),
+            || {}
+        )
+        .map(|_| ()),
+        Err(ConversionError::Overflow)
+    );
+}
@@ -0,0 +1,136 @@
+use embedded_time::{self as time, duration::*, wheel::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn expired(wheel: &mut TimerWheel<'_, Clock, u32>, now: u32) -> Vec<u32> {
+    let mut tokens = Vec::new();
+    while let Some(token) = wheel.pop_expired(Instant::new(now)) {
+        tokens.push(token);
+    }
+    tokens.sort_unstable();
+    tokens
+}
+
+#[test]
+fn expiry() {
+    let mut buckets = [Bucket::new(); 8];
+    let mut entries = [Entry::new(); 32];
+    let mut wheel = TimerWheel::new(
+        &mut buckets,
+        &mut entries,
+        Instant::new(1_000),
+        Milliseconds(10_u32),
+    )
+    .unwrap();
+    assert!(wheel.is_empty());
+    assert_eq!(wheel.capacity(), 32);
+
+    // already passed, within the first revolution, and several revolutions away
+    for (token, expiration) in [990_u32, 1_005, 1_015, 1_019, 1_075, 1_080, 1_300, 1_301]
+        .iter()
+        .enumerate()
+    {
+        wheel
+            .insert(Instant::new(*expiration), token as u32)
+            .unwrap();
+    }
+    assert_eq!(wheel.len(), 8);
+
+    assert_eq!(expired(&mut wheel, 1_000), [0]);
+    assert_eq!(expired(&mut wheel, 1_014), [1]);
+    assert_eq!(expired(&mut wheel, 1_019), [2, 3]);
+    assert_eq!(expired(&mut wheel, 1_080), [4, 5]);
+    assert_eq!(expired(&mut wheel, 1_299), []);
+    assert_eq!(expired(&mut wheel, 1_400), [6, 7]);
+    assert!(wheel.is_empty());
+
+    // idle, then reused beyond the elapsed revolutions
+    assert_eq!(expired(&mut wheel, 900_000), []);
+    wheel.insert(Instant::new(900_015), 8).unwrap();
+    assert_eq!(expired(&mut wheel, 900_014), []);
+    assert_eq!(expired(&mut wheel, 900_015), [8]);
+}
+
+#[test]
+fn across_clock_wrap() {
+    let mut buckets = [Bucket::new(); 4];
+    let mut entries = [Entry::new(); 4];
+    let mut wheel = TimerWheel::new(
+        &mut buckets,
+        &mut entries,
+        Instant::new(u32::MAX - 20),
+        Milliseconds(10_u32),
+    )
+    .unwrap();
+
+    wheel.insert(Instant::new(u32::MAX), 1).unwrap();
+    wheel.insert(Instant::new(30), 2).unwrap();
+
+    assert_eq!(expired(&mut wheel, u32::MAX - 1), []);
+    assert_eq!(expired(&mut wheel, 5), [1]);
+    assert_eq!(expired(&mut wheel, 29), []);
+    assert_eq!(expired(&mut wheel, 30), [2]);
+}
+
+#[test]
+fn cancel() {
+    let mut buckets = [Bucket::new(); 2];
+    let mut entries = [Entry::new(); 3];
+    let mut wheel = TimerWheel::new(
+        &mut buckets,
+        &mut entries,
+        Instant::new(0),
+        Milliseconds(10_u32),
+    )
+    .unwrap();
+
+    let first = wheel.insert(Instant::new(5), 1).unwrap();
+    let second = wheel.insert(Instant::new(6), 2).unwrap();
+    let third = wheel.insert(Instant::new(7), 3).unwrap();
+    assert_eq!(wheel.insert(Instant::new(8), 4), Err(4));
+
+    assert_eq!(wheel.cancel(second), Some(2));
+    assert_eq!(wheel.cancel(second), None);
+    assert_eq!(wheel.len(), 2);
+
+    // the freed entry is reused
+    wheel.insert(Instant::new(8), 4).unwrap();
+    assert_eq!(wheel.cancel(third), Some(3));
+    assert_eq!(wheel.cancel(first), Some(1));
+    assert_eq!(expired(&mut wheel, 10), [4]);
+}
+
+#[test]
+fn invalid_storage() {
+    let mut entries = [Entry::<Clock, u32>::new(); 1];
+    assert_eq!(
+        TimerWheel::new(&mut [], &mut entries, Instant::new(0), Milliseconds(10_u32)).err(),
+        Some(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        TimerWheel::new(
+            &mut [Bucket::new()],
+            &mut entries,
+            Instant::new(0),
+            Microseconds(10_u32)
+        )
+        .err(),
+        Some(ConversionError::DivByZero)
+    );
+
+    let mut buckets = [Bucket::new()];
+    let mut wheel =
+        TimerWheel::<Clock, u32>::new(&mut buckets, &mut [], Instant::new(0), Milliseconds(10_u32))
+            .unwrap();
+    assert_eq!(wheel.insert(Instant::new(0), 1), Err(1));
+}